@@ -0,0 +1,514 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Static archive module.
+//!
+//! Archive is the `!<arch>\n` magic followed by members, each made of a
+//! header and contents padded to even size. Names longer than 15 characters
+//! are stored in the `//` member in GNU archives and referred as `/offset`,
+//! BSD archives store them right after the header and refer as `#1/length`.
+//! Symbol index members (`/`, `/SYM64/`, `__.SYMDEF`) and the long names
+//! table are not returned as members, GNU symbol index is available through
+//! `Archive::symbol_index`.
+//!
+//! Thin archive starts with `!<thin>\n` magic and holds only the symbol
+//! index and long names table, its members are files on disk referred by path
+//! relative to the archive directory.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::mem::size_of;
+use std::path::{Path, PathBuf};
+
+use crate::elf::file::{read, ElfError, ElfFile};
+
+/// Archive magic.
+pub const ARMAG: &[u8; 8] = b"!<arch>\n";
+
+/// Thin archive magic.
+pub const THINMAG: &[u8; 8] = b"!<thin>\n";
+
+/// Archive member header terminator.
+pub const ARFMAG: &[u8; 2] = b"`\n";
+
+/// Archive member header.
+#[repr(C, packed)]
+#[derive(Debug, Clone, Copy)]
+pub struct ar_hdr {
+    /// Member name, terminated by `/` in GNU archives.
+    pub ar_name: [u8; 16],
+    /// Modification time in decimal.
+    pub ar_date: [u8; 12],
+    /// User ID in decimal.
+    pub ar_uid: [u8; 6],
+    /// Group ID in decimal.
+    pub ar_gid: [u8; 6],
+    /// File mode in octal.
+    pub ar_mode: [u8; 8],
+    /// Size of member contents in decimal.
+    pub ar_size: [u8; 10],
+    /// Header terminator, always `ARFMAG`.
+    pub ar_fmag: [u8; 2],
+}
+
+/// Archive error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ArchiveError {
+    /// Data does not start with the archive magic.
+    NotArchive,
+    /// Member header lies outside of the archive.
+    Truncated,
+    /// Member header is not terminated with `ARFMAG`.
+    BadHeader,
+    /// Long name refers outside of the long names table.
+    LongNameIndex(usize),
+    /// Symbol index is too small to hold symbols count.
+    EmptyIndex,
+    /// Symbol index is too small to hold symbols offsets.
+    IndexSize {
+        /// Number of symbols.
+        count: u64,
+        /// Size of symbols count and offsets.
+        width: usize,
+        /// Size of symbol index.
+        size: usize,
+    },
+    /// Member of thin archive has no contents in the archive, its file is
+    /// located by `Member::path`.
+    ThinMember,
+    /// Member contents is not a valid ELF file.
+    Elf(ElfError),
+}
+
+impl fmt::Display for ArchiveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArchiveError::NotArchive       => write!(f, "not an archive"),
+            ArchiveError::Truncated        => write!(f, "failed to read archive header"),
+            ArchiveError::BadHeader        => write!(f, "did not find a valid archive header"),
+            ArchiveError::LongNameIndex(i) => {
+                write!(f, "Found long name index ({}) beyond end of long name table", i)
+            }
+            ArchiveError::EmptyIndex => write!(f, "the archive has an index but no symbols"),
+            ArchiveError::IndexSize { count, width, size } => write!(
+                f,
+                "the archive index is supposed to have {:#x} entries of {} bytes, but the size is only {:#x}",
+                count, width, size
+            ),
+            ArchiveError::ThinMember => write!(f, "thin archive member contents are external"),
+            ArchiveError::Elf(err)   => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for ArchiveError {}
+
+/// Archive result.
+pub type Result<T> = std::result::Result<T, ArchiveError>;
+
+/// Archive member.
+#[derive(Debug, Clone)]
+pub struct Member<'a> {
+    /// Member name.
+    pub name: Cow<'a, str>,
+    /// Offset of the member header in the archive.
+    pub offset: usize,
+    /// Member contents, empty for thin archive members.
+    pub data: &'a [u8],
+    /// Whether member is a thin archive member, whose contents are stored in
+    /// a separate file.
+    pub thin: bool,
+}
+
+impl<'a> Member<'a> {
+    /// Parse member contents as ELF file.
+    ///
+    /// # Returns
+    /// - `ElfFile` - in case of success.
+    /// - `ArchiveError::ThinMember` - if member is a thin archive member, its
+    ///   file should be read from `Member::path` instead.
+    /// - `ArchiveError::Elf` - if member contents is not a valid ELF file.
+    pub fn elf(&self) -> Result<ElfFile<'a>> {
+        if self.thin {
+            return Err(ArchiveError::ThinMember);
+        }
+
+        ElfFile::parse(self.data).map_err(ArchiveError::Elf)
+    }
+
+    /// Get path of thin archive member file.
+    ///
+    /// # Parameters
+    /// - `archive` - given thin archive path.
+    ///
+    /// # Returns
+    /// Member path relative to archive directory, absolute member path as is.
+    pub fn path(&self, archive: &Path) -> PathBuf {
+        let dir = archive.parent().unwrap_or(Path::new(""));
+        dir.join(self.name.as_ref())
+    }
+}
+
+/// Raw archive entry before name resolution.
+struct Entry<'a> {
+    /// Header.
+    hdr: ar_hdr,
+    /// Offset of the header.
+    offset: usize,
+    /// Contents, including BSD long name.
+    data: &'a [u8],
+}
+
+/// Archive symbol index entry.
+#[derive(Debug, Clone, Copy)]
+pub struct IndexSymbol<'a> {
+    /// Symbol name.
+    pub name: &'a [u8],
+    /// Offset of the header of member defining the symbol.
+    pub offset: u64,
+}
+
+/// GNU archive symbol index.
+///
+/// Index is the big-endian symbols count, followed by member offset of every
+/// symbol and then by symbol names terminated by NUL. Count and offsets are
+/// 4 bytes wide in `/` member and 8 bytes wide in `/SYM64/` member.
+#[derive(Debug, Clone, Copy)]
+pub struct SymbolIndex<'a> {
+    /// Symbols offsets.
+    offsets: &'a [u8],
+    /// Symbols names.
+    names: &'a [u8],
+    /// Size of symbols count and offsets.
+    width: usize,
+}
+
+impl<'a> SymbolIndex<'a> {
+    /// Parse symbol index.
+    ///
+    /// # Parameters
+    /// - `data`  - given index member contents.
+    /// - `width` - given size of symbols count and offsets.
+    ///
+    /// # Returns
+    /// - `SymbolIndex` - in case of success.
+    /// - `ArchiveError` - otherwise.
+    fn parse(data: &'a [u8], width: usize) -> Result<SymbolIndex<'a>> {
+        if data.len() < width {
+            return Err(ArchiveError::EmptyIndex);
+        }
+
+        let count = big_endian(&data[..width]);
+        let size  = count
+            .checked_mul(width as u64)
+            .filter(|&size| size <= (data.len() - width) as u64)
+            .ok_or(ArchiveError::IndexSize { count, width, size: data.len() })? as usize;
+
+        let offsets = &data[width..width + size];
+        let names   = &data[width + size..];
+
+        Ok(SymbolIndex { offsets, names, width })
+    }
+
+    /// Get number of symbols.
+    pub fn len(&self) -> usize {
+        self.offsets.len() / self.width
+    }
+
+    /// Check whether index has no symbols.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Check whether index uses 64-bit offsets.
+    pub fn is_64(&self) -> bool {
+        self.width == 8
+    }
+
+    /// Get size of symbol names.
+    pub fn names_size(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Get size of symbol names not referred by any symbol.
+    ///
+    /// Names are padded to even size, or to 8 bytes in 64-bit index.
+    pub fn unused_names_size(&self) -> usize {
+        let used = self.symbols().map(|sym| sym.name.len() + 1).sum::<usize>();
+        let used = match self.is_64() {
+            true  => used.next_multiple_of(8),
+            false => used + (used & 1),
+        };
+
+        self.names.len().saturating_sub(used)
+    }
+
+    /// Get iterator over symbols.
+    ///
+    /// Iteration stops early if names end before offsets.
+    pub fn symbols(&self) -> impl Iterator<Item = IndexSymbol<'a>> + 'a {
+        let mut names = self.names;
+
+        self.offsets.chunks_exact(self.width).map_while(move |offset| {
+            if names.is_empty() {
+                return None;
+            }
+
+            let len  = names.iter().position(|&b| b == 0).unwrap_or(names.len());
+            let name = &names[..len];
+            names    = &names[(len + 1).min(names.len())..];
+
+            Some(IndexSymbol { name, offset: big_endian(offset) })
+        })
+    }
+}
+
+/// Static archive.
+#[derive(Debug, Clone, Copy)]
+pub struct Archive<'a> {
+    /// Archive contents.
+    data: &'a [u8],
+    /// GNU symbol index.
+    index: Option<SymbolIndex<'a>>,
+    /// Long names table.
+    long_names: &'a [u8],
+    /// Offset of the first regular member header.
+    first: usize,
+    /// Whether members are stored outside of the archive.
+    thin: bool,
+}
+
+impl<'a> Archive<'a> {
+    /// Check whether data is an archive.
+    ///
+    /// # Parameters
+    /// - `data` - given file contents.
+    pub fn is_archive(data: &[u8]) -> bool {
+        data.starts_with(ARMAG) || data.starts_with(THINMAG)
+    }
+
+    /// Parse archive.
+    ///
+    /// # Parameters
+    /// - `data` - given file contents.
+    ///
+    /// # Returns
+    /// - `Archive` - in case of success.
+    /// - `ArchiveError` - otherwise.
+    pub fn parse(data: &'a [u8]) -> Result<Archive<'a>> {
+        if !Self::is_archive(data) {
+            return Err(ArchiveError::NotArchive);
+        }
+
+        let mut archive = Archive {
+            data,
+            index:      None,
+            long_names: &[],
+            first:      ARMAG.len(),
+            thin:       data.starts_with(THINMAG),
+        };
+
+        // Symbol index and long names table precede regular members.
+        while let Some(entry) = archive.entry(archive.first)? {
+            let name = &entry.hdr.ar_name;
+
+            if name.starts_with(b"// ") {
+                archive.long_names = entry.data;
+            } else if name.starts_with(b"/ ") {
+                archive.index = Some(SymbolIndex::parse(entry.data, 4)?);
+            } else if name.starts_with(b"/SYM64/ ") {
+                archive.index = Some(SymbolIndex::parse(entry.data, 8)?);
+            } else if !is_bsd_symdef(&entry) {
+                break;
+            }
+
+            archive.first = next_offset(&entry);
+        }
+
+        Ok(archive)
+    }
+
+    /// Check whether archive is thin.
+    pub fn is_thin(&self) -> bool {
+        self.thin
+    }
+
+    /// Get GNU symbol index.
+    pub fn symbol_index(&self) -> Option<&SymbolIndex<'a>> {
+        self.index.as_ref()
+    }
+
+    /// Get iterator over archive members.
+    pub fn members(&self) -> MemberIter<'a> {
+        MemberIter { archive: *self, offset: self.first }
+    }
+
+    /// Get member by offset of its header.
+    ///
+    /// # Parameters
+    /// - `offset` - given member header offset, e.g. from symbol index.
+    ///
+    /// # Returns
+    /// - `Member` - in case of success.
+    /// - `ArchiveError` - otherwise.
+    pub fn member_at(&self, offset: u64) -> Result<Member<'a>> {
+        let offset = usize::try_from(offset).map_err(|_| ArchiveError::Truncated)?;
+        let entry  = self.entry(offset)?.ok_or(ArchiveError::Truncated)?;
+
+        self.member(entry)
+    }
+
+    /// Read entry at given offset.
+    ///
+    /// # Returns
+    /// - Entry - in case of success.
+    /// - `None` - if offset is the end of the archive.
+    /// - `ArchiveError` - if header is invalid.
+    fn entry(&self, offset: usize) -> Result<Option<Entry<'a>>> {
+        if offset >= self.data.len() {
+            return Ok(None);
+        }
+
+        let hdr = read::<ar_hdr>(self.data, offset).map_err(|_| ArchiveError::Truncated)?;
+
+        if hdr.ar_fmag != *ARFMAG {
+            return Err(ArchiveError::BadHeader);
+        }
+
+        // Size of thin archive member is the size of its file.
+        let size  = match self.thin && !is_special(&hdr.ar_name) {
+            true  => 0,
+            false => decimal(&hdr.ar_size),
+        };
+        let start = offset + size_of::<ar_hdr>();
+        let end   = start.saturating_add(size).min(self.data.len());
+
+        Ok(Some(Entry { hdr, offset, data: &self.data[start..end] }))
+    }
+
+    /// Resolve name of entry and strip BSD long name from its contents.
+    ///
+    /// # Parameters
+    /// - `entry` - given archive entry.
+    fn member(&self, entry: Entry<'a>) -> Result<Member<'a>> {
+        let name = &entry.hdr.ar_name;
+
+        if let Some(len) = name.strip_prefix(b"#1/") {
+            let len  = decimal(len).min(entry.data.len());
+            let long = &entry.data[..len];
+            let long = &long[..long.iter().position(|&b| b == 0).unwrap_or(len)];
+
+            return Ok(Member {
+                name:   String::from_utf8_lossy(long),
+                offset: entry.offset,
+                data:   &entry.data[len..],
+                thin:   self.thin,
+            });
+        }
+
+        let name = if name[0] == b'/' {
+            let index = decimal(&name[1..]);
+            let long  = self.long_names.get(index..).ok_or(ArchiveError::LongNameIndex(index))?;
+            let long  = &long[..long.iter().position(|&b| b == b'\n' || b == 0).unwrap_or(long.len())];
+
+            String::from_utf8_lossy(long.strip_suffix(b"/").unwrap_or(long))
+        } else {
+            let short = match name.iter().position(|&b| b == b'/') {
+                Some(end) => &name[..end],
+                None      => name.trim_ascii_end(),
+            };
+
+            Cow::Owned(String::from_utf8_lossy(short).into_owned())
+        };
+
+        Ok(Member { name, offset: entry.offset, data: entry.data, thin: self.thin })
+    }
+}
+
+/// Iterator over archive members.
+///
+/// Iteration stops after the first error.
+#[derive(Debug, Clone)]
+pub struct MemberIter<'a> {
+    /// Archive.
+    archive: Archive<'a>,
+    /// Offset of the next member header.
+    offset: usize,
+}
+
+impl<'a> Iterator for MemberIter<'a> {
+    type Item = Result<Member<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = match self.archive.entry(self.offset) {
+            Ok(entry) => entry?,
+            Err(err)  => {
+                self.offset = usize::MAX;
+                return Some(Err(err));
+            }
+        };
+
+        self.offset = next_offset(&entry);
+
+        if is_bsd_symdef(&entry) {
+            return self.next();
+        }
+
+        let member = self.archive.member(entry);
+
+        if member.is_err() {
+            self.offset = usize::MAX;
+        }
+
+        Some(member)
+    }
+}
+
+/// Get offset of the header following entry, contents are padded to even size.
+fn next_offset(entry: &Entry) -> usize {
+    let end = entry.offset + size_of::<ar_hdr>() + entry.data.len();
+    end + (end & 1)
+}
+
+/// Check whether name is one of GNU symbol index or long names table.
+fn is_special(name: &[u8]) -> bool {
+    name.starts_with(b"/ ") || name.starts_with(b"/SYM64/ ") || name.starts_with(b"// ")
+}
+
+/// Check whether entry is BSD symbol index.
+fn is_bsd_symdef(entry: &Entry) -> bool {
+    let name = &entry.hdr.ar_name;
+
+    if name.starts_with(b"__.SYMDEF") {
+        return true;
+    }
+
+    name.starts_with(b"#1/") && entry.data.starts_with(b"__.SYMDEF")
+}
+
+/// Parse big-endian number of symbol index.
+fn big_endian(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0, |value, &b| (value << 8) | b as u64)
+}
+
+/// Parse leading decimal number the way `strtoul` does, ignoring the rest.
+fn decimal(field: &[u8]) -> usize {
+    field
+        .iter()
+        .skip_while(|b| b.is_ascii_whitespace())
+        .take_while(|b| b.is_ascii_digit())
+        .fold(0usize, |value, &b| value.saturating_mul(10).saturating_add((b - b'0') as usize))
+}
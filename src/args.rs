@@ -0,0 +1,297 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Command line arguments handler module.
+
+use readelf::parser::dump::{DumpKind, DumpRequest};
+use readelf::parser::json::JsonViews;
+
+/// Usage message.
+pub const USAGE: &str = "\
+Usage: readelf <option(s)> elf-file(s)
+ Display information about the contents of ELF format files
+ Options are:
+  -h --file-header       Display the ELF file header
+  -S --section-headers   Display the sections' header
+     --sections          An alias for --section-headers
+  -g --section-groups    Display the section groups
+  -t --section-details   Display the section details
+  -n --notes             Display the core notes (if present)
+  -r --relocs            Display the relocations (if present)
+  -d --dynamic           Display the dynamic section (if present)
+  -V --version-info      Display the version sections (if present)
+     --version-requirements
+                         Display the highest versions required from libraries
+     --max-version=<name>
+                         Fail if a required version is newer than <name>
+  -x --hex-dump=<number|name>
+                         Dump the contents of section <number|name> as bytes
+  -p --string-dump=<number|name>
+                         Dump the contents of section <number|name> as strings
+  -R --relocated-dump=<number|name>
+                         Dump the relocated contents of section <number|name>
+  -z --decompress        Decompress section before dumping it
+     --dyn-syms          Display the dynamic symbol table
+  -I --histogram         Display histogram of bucket list lengths
+  -c --archive-index     Display the symbol/file index in an archive
+  -C --demangle          Decode mangled/processed symbol names
+     --output=<text|json>
+                         Display information as text or as JSON document
+     --elf-output-style=<GNU|JSON>
+                         An alias for --output
+  -H --help              Display this information";
+
+/// Output style.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OutputStyle {
+    /// GNU readelf text.
+    #[default]
+    Text,
+    /// JSON document describing views selected by display options.
+    Json,
+}
+
+/// Command line arguments struct.
+#[derive(Debug, Default)]
+pub struct Args {
+    /// Display the ELF file header.
+    pub file_header: bool,
+    /// Display the sections' headers.
+    pub section_headers: bool,
+    /// Display the sections' headers with flags and compression in full.
+    pub section_details: bool,
+    /// Display the section groups.
+    pub section_groups: bool,
+    /// Display the relocations.
+    pub relocs: bool,
+    /// Display the dynamic section.
+    pub dynamic: bool,
+    /// Display the dynamic symbol table.
+    pub dyn_syms: bool,
+    /// Display the version sections.
+    pub version_info: bool,
+    /// Display the highest versions required from libraries.
+    pub version_requirements: bool,
+    /// Newest allowed versions of needed libraries.
+    pub max_versions: Vec<String>,
+    /// Display the notes.
+    pub notes: bool,
+    /// Display histogram of bucket list lengths.
+    pub histogram: bool,
+    /// Display the symbol index of archives.
+    pub archive_index: bool,
+    /// Sections contents to dump.
+    pub dumps: Vec<DumpRequest>,
+    /// Decompress sections before dumping them.
+    pub decompress: bool,
+    /// Decode mangled symbol names.
+    pub demangle: bool,
+    /// Output style.
+    pub output: OutputStyle,
+    /// Display usage message.
+    pub help: bool,
+    /// Input files paths, `-` stands for standard input.
+    pub files: Vec<String>,
+}
+
+impl Args {
+    /// Check whether any display option was given.
+    pub fn has_display_option(&self) -> bool {
+        self.output == OutputStyle::Json || self.archive_index || self.has_object_option()
+    }
+
+    /// Check whether any option displaying ELF objects was given.
+    pub fn has_object_option(&self) -> bool {
+        self.file_header
+            || self.section_headers
+            || self.section_groups
+            || self.relocs
+            || self.dynamic
+            || self.dyn_syms
+            || self.version_info
+            || self.version_requirements
+            || !self.max_versions.is_empty()
+            || self.notes
+            || self.histogram
+            || !self.dumps.is_empty()
+    }
+
+    /// Get views of ELF objects to include in JSON output.
+    ///
+    /// # Returns
+    /// Views selected by display options, or every view but section dumps if
+    /// no display option was given.
+    pub fn json_views(&self) -> JsonViews {
+        if !self.has_object_option() && !self.archive_index {
+            return JsonViews::all();
+        }
+
+        JsonViews {
+            header:               self.file_header,
+            sections:             self.section_headers,
+            segments:             false,
+            groups:               self.section_groups,
+            symbols:              false,
+            dyn_syms:             self.dyn_syms,
+            relocations:          self.relocs,
+            dynamic:              self.dynamic,
+            versions:             self.version_info,
+            version_requirements: self.version_requirements,
+            histograms:           self.histogram,
+            notes:                self.notes,
+            dumps:                self.dumps.clone(),
+        }
+    }
+}
+
+/// Option with value handler.
+///
+/// # Parameters
+/// - `args`  - given arguments struct to fill.
+/// - `name`  - given option name.
+/// - `value` - given option value.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - with error message otherwise.
+fn handle_value(args: &mut Args, name: &str, value: String) -> Result<(), String> {
+    match name {
+        "x" | "hex-dump"       => args.dumps.push(DumpRequest { kind: DumpKind::Hex, section: value }),
+        "p" | "string-dump"    => args.dumps.push(DumpRequest { kind: DumpKind::String, section: value }),
+        "R" | "relocated-dump" => args.dumps.push(DumpRequest { kind: DumpKind::Relocated, section: value }),
+        "max-version"          => args.max_versions.push(value),
+        "output" | "elf-output-style" => {
+            args.output = match value.to_ascii_lowercase().as_str() {
+                "text" | "gnu" => OutputStyle::Text,
+                "json"         => OutputStyle::Json,
+                _ => return Err(format!("invalid output style '{}'", value)),
+            };
+        }
+        _ => return Err(format!("unrecognized option '{}'", name)),
+    }
+    Ok(())
+}
+
+/// Check whether option takes value.
+fn takes_value(name: &str) -> bool {
+    matches!(
+        name,
+        "x" | "hex-dump" | "p" | "string-dump" | "R" | "relocated-dump" | "max-version" | "output"
+            | "elf-output-style"
+    )
+}
+
+/// Flag option handler.
+///
+/// # Parameters
+/// - `args` - given arguments struct to fill.
+/// - `name` - given option name.
+///
+/// # Returns
+/// - `Ok` - in case of success.
+/// - `Err` - with error message otherwise.
+fn handle_flag(args: &mut Args, name: &str) -> Result<(), String> {
+    match name {
+        "h" | "file-header"  => args.file_header = true,
+        "S" | "section-headers" | "sections" => args.section_headers = true,
+        "g" | "section-groups" => args.section_groups = true,
+        "t" | "section-details" => {
+            args.section_headers = true;
+            args.section_details = true;
+        }
+        "r" | "relocs"       => args.relocs = true,
+        "d" | "dynamic"      => args.dynamic = true,
+        "n" | "notes"        => args.notes = true,
+        "V" | "version-info" => args.version_info = true,
+        "version-requirements" => args.version_requirements = true,
+        "dyn-syms"           => args.dyn_syms = true,
+        "I" | "histogram"    => args.histogram = true,
+        "c" | "archive-index" => args.archive_index = true,
+        "C" | "demangle"     => args.demangle = true,
+        "z" | "decompress"   => args.decompress = true,
+        "H" | "help"         => args.help = true,
+        _ => return Err(format!("unrecognized option '{}'", name)),
+    }
+    Ok(())
+}
+
+/// Parse command line arguments.
+///
+/// Both short (`-hR .text`, `-R.text`) and long (`--relocated-dump=.text`,
+/// `--relocated-dump .text`) options are supported.
+///
+/// # Parameters
+/// - `argv` - given command line arguments without program name.
+///
+/// # Returns
+/// - `Args` - in case of success.
+/// - `Err` - with error message otherwise.
+pub fn parse(argv: impl IntoIterator<Item = String>) -> Result<Args, String> {
+    let mut args = Args::default();
+    let mut argv = argv.into_iter();
+
+    while let Some(arg) = argv.next() {
+        if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None                => (long, None),
+            };
+
+            if takes_value(name) {
+                let value = value
+                    .or_else(|| argv.next())
+                    .ok_or_else(|| format!("option '--{}' requires an argument", name))?;
+                handle_value(&mut args, name, value)?;
+            } else if value.is_some() {
+                return Err(format!("option '--{}' doesn't allow an argument", name));
+            } else {
+                handle_flag(&mut args, name)?;
+            }
+        } else if arg.len() > 1 && arg.starts_with('-') {
+            let shorts = &arg[1..];
+
+            for (i, c) in shorts.char_indices() {
+                let name = c.to_string();
+
+                if takes_value(&name) {
+                    let rest  = &shorts[i + c.len_utf8()..];
+                    let value = match rest {
+                        "" => argv
+                            .next()
+                            .ok_or_else(|| format!("option requires an argument -- '{}'", c))?,
+                        _  => rest.to_string(),
+                    };
+                    handle_value(&mut args, &name, value)?;
+                    break;
+                }
+
+                handle_flag(&mut args, &name)?;
+            }
+        } else {
+            args.files.push(arg);
+        }
+    }
+
+    if args.help {
+        return Ok(args);
+    }
+
+    if args.files.is_empty() {
+        return Err("Nothing to do.".to_string());
+    }
+
+    Ok(args)
+}
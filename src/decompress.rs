@@ -0,0 +1,33 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Section contents decompression module.
+
+pub mod zlib;
+pub mod zstd;
+
+/// Get number of bytes to allocate for decompressed data up front.
+///
+/// Expected size is read from the file and may be arbitrarily large, so the
+/// buffer is allocated for a typical compression ratio and grows as output
+/// is produced.
+///
+/// # Parameters
+/// - `data` - given compressed bytes.
+/// - `size` - given expected size of decompressed data.
+fn initial_capacity(data: &[u8], size: usize) -> usize {
+    size.min(data.len().saturating_mul(4))
+}
@@ -0,0 +1,517 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! zlib (RFC 1950) and DEFLATE (RFC 1951) decompression module.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::decompress::initial_capacity;
+
+/// Maximum number of bits in a Huffman code.
+const MAX_BITS: usize = 15;
+
+/// Order of code length code lengths in dynamic block header.
+const CLEN_ORDER: [usize; 19] = [16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15];
+
+/// Base lengths of length symbols 257..285.
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+    131, 163, 195, 227, 258,
+];
+
+/// Extra bits of length symbols 257..285.
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+/// Base distances of distance symbols 0..29.
+const DIST_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+
+/// Extra bits of distance symbols 0..29.
+const DIST_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Decompress concatenated zlib streams.
+///
+/// # Parameters
+/// - `data` - given compressed bytes.
+/// - `size` - given expected size of decompressed data.
+///
+/// # Returns
+/// - Decompressed bytes - if data is valid and decompresses to exactly `size`
+///   bytes.
+/// - `None` - otherwise.
+pub fn decompress(data: &[u8], size: usize) -> Option<Vec<u8>> {
+    let mut inflater = Inflater {
+        data,
+        pos:     0,
+        bit_buf: 0,
+        bit_cnt: 0,
+        out:     Vec::with_capacity(initial_capacity(data, size)),
+        limit:   size,
+    };
+
+    while inflater.pos < data.len() {
+        inflater.zlib_stream()?;
+    }
+
+    (inflater.out.len() == size).then_some(inflater.out)
+}
+
+/// Canonical Huffman code.
+struct Huffman {
+    /// Number of symbols of each code length.
+    count: [u16; MAX_BITS + 1],
+    /// Symbols ordered by code.
+    symbol: Vec<u16>,
+}
+
+impl Huffman {
+    /// Construct Huffman code from code lengths of symbols.
+    ///
+    /// # Parameters
+    /// - `lengths` - given code lengths, zero for unused symbols.
+    ///
+    /// # Returns
+    /// - Huffman code and whether it is incomplete - in case of success.
+    /// - `None` - if code is over-subscribed.
+    fn new(lengths: &[u8]) -> Option<(Huffman, bool)> {
+        let mut count = [0u16; MAX_BITS + 1];
+
+        for &len in lengths {
+            count[len as usize] += 1;
+        }
+
+        let mut left = 1i32;
+
+        for &n in &count[1..] {
+            left = (left << 1) - n as i32;
+
+            if left < 0 {
+                return None;
+            }
+        }
+
+        let mut offs = [0u16; MAX_BITS + 1];
+
+        for len in 1..MAX_BITS {
+            offs[len + 1] = offs[len] + count[len];
+        }
+
+        let mut symbol = vec![0u16; lengths.len()];
+
+        for (sym, &len) in lengths.iter().enumerate() {
+            if len != 0 {
+                symbol[offs[len as usize] as usize] = sym as u16;
+                offs[len as usize] += 1;
+            }
+        }
+
+        let incomplete = left > 0 && count[0] as usize != lengths.len();
+        Some((Huffman { count, symbol }, incomplete))
+    }
+
+    /// Check whether incomplete code consists of a single one bit code, the
+    /// only incomplete code allowed.
+    fn is_single(&self) -> bool {
+        self.count[1] == 1 && self.count[2..].iter().all(|&n| n == 0)
+    }
+}
+
+/// DEFLATE decoder state.
+struct Inflater<'a> {
+    /// Compressed bytes.
+    data: &'a [u8],
+    /// Position of the next byte to load.
+    pos: usize,
+    /// Loaded bits not consumed yet.
+    bit_buf: u32,
+    /// Number of bits in `bit_buf`.
+    bit_cnt: u32,
+    /// Decompressed bytes.
+    out: Vec<u8>,
+    /// Maximum number of decompressed bytes.
+    limit: usize,
+}
+
+impl Inflater<'_> {
+    /// Read bits, least significant first.
+    ///
+    /// # Parameters
+    /// - `n` - given number of bits, at most 16.
+    fn bits(&mut self, n: u32) -> Option<u32> {
+        while self.bit_cnt < n {
+            let byte = *self.data.get(self.pos)?;
+            self.bit_buf |= (byte as u32) << self.bit_cnt;
+            self.bit_cnt += 8;
+            self.pos += 1;
+        }
+
+        let value = self.bit_buf & ((1 << n) - 1);
+        self.bit_buf >>= n;
+        self.bit_cnt -= n;
+        Some(value)
+    }
+
+    /// Discard bits up to the byte boundary.
+    fn align(&mut self) {
+        self.bit_buf = 0;
+        self.bit_cnt = 0;
+    }
+
+    /// Read byte-aligned bytes.
+    fn bytes(&mut self, n: usize) -> Option<&[u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(n)?)?;
+        self.pos += n;
+        Some(bytes)
+    }
+
+    /// Decode single zlib stream: header, DEFLATE blocks and checksum.
+    fn zlib_stream(&mut self) -> Option<()> {
+        let header = self.bytes(2)?;
+        let (cmf, flg) = (header[0], header[1]);
+
+        // Compression method 8 with window of at most 32K and no dictionary.
+        if cmf & 0x0f != 8 || cmf >> 4 > 7 || flg & 0x20 != 0 {
+            return None;
+        }
+
+        if !(cmf as u16 * 256 + flg as u16).is_multiple_of(31) {
+            return None;
+        }
+
+        let start = self.out.len();
+
+        loop {
+            let last = self.bits(1)?;
+
+            match self.bits(2)? {
+                0 => self.stored()?,
+                1 => self.fixed()?,
+                2 => self.dynamic()?,
+                _ => return None,
+            }
+
+            if last == 1 {
+                break;
+            }
+        }
+
+        self.align();
+        let checksum = u32::from_be_bytes(self.bytes(4)?.try_into().ok()?);
+
+        (adler32(&self.out[start..]) == checksum).then_some(())
+    }
+
+    /// Decode stored block.
+    fn stored(&mut self) -> Option<()> {
+        self.align();
+
+        let header = self.bytes(4)?;
+        let len    = u16::from_le_bytes([header[0], header[1]]);
+        let nlen   = u16::from_le_bytes([header[2], header[3]]);
+
+        if len != !nlen || self.out.len() + len as usize > self.limit {
+            return None;
+        }
+
+        let bytes = self.data.get(self.pos..self.pos + len as usize)?;
+        self.out.extend_from_slice(bytes);
+        self.pos += len as usize;
+        Some(())
+    }
+
+    /// Decode block compressed with fixed Huffman codes.
+    fn fixed(&mut self) -> Option<()> {
+        let mut lengths = [0u8; 288];
+
+        lengths[..144].fill(8);
+        lengths[144..256].fill(9);
+        lengths[256..280].fill(7);
+        lengths[280..].fill(8);
+
+        let (lencode, _)  = Huffman::new(&lengths)?;
+        let (distcode, _) = Huffman::new(&[5; 30])?;
+
+        self.codes(&lencode, &distcode)
+    }
+
+    /// Decode block compressed with dynamic Huffman codes.
+    fn dynamic(&mut self) -> Option<()> {
+        let nlen  = self.bits(5)? as usize + 257;
+        let ndist = self.bits(5)? as usize + 1;
+        let ncode = self.bits(4)? as usize + 4;
+
+        if nlen > 286 || ndist > 30 {
+            return None;
+        }
+
+        let mut lengths = [0u8; 19];
+
+        for &index in &CLEN_ORDER[..ncode] {
+            lengths[index] = self.bits(3)? as u8;
+        }
+
+        let (lencode, incomplete) = Huffman::new(&lengths)?;
+
+        if incomplete {
+            return None;
+        }
+
+        let mut lengths = vec![0u8; nlen + ndist];
+        let mut index   = 0;
+
+        while index < nlen + ndist {
+            let symbol = self.decode(&lencode)?;
+
+            let (len, repeat) = match symbol {
+                0..=15 => (symbol as u8, 1),
+                16     => (*lengths.get(index.checked_sub(1)?)?, 3 + self.bits(2)? as usize),
+                17     => (0, 3 + self.bits(3)? as usize),
+                _      => (0, 11 + self.bits(7)? as usize),
+            };
+
+            if index + repeat > nlen + ndist {
+                return None;
+            }
+
+            lengths[index..index + repeat].fill(len);
+            index += repeat;
+        }
+
+        // End of block code is required.
+        if lengths[256] == 0 {
+            return None;
+        }
+
+        let (lencode, incomplete) = Huffman::new(&lengths[..nlen])?;
+
+        if incomplete && !lencode.is_single() {
+            return None;
+        }
+
+        let (distcode, incomplete) = Huffman::new(&lengths[nlen..])?;
+
+        if incomplete && !distcode.is_single() {
+            return None;
+        }
+
+        self.codes(&lencode, &distcode)
+    }
+
+    /// Decode symbol using Huffman code.
+    fn decode(&mut self, h: &Huffman) -> Option<u16> {
+        let mut code  = 0i32;
+        let mut first = 0i32;
+        let mut index = 0i32;
+
+        for len in 1..=MAX_BITS {
+            code |= self.bits(1)? as i32;
+            let count = h.count[len] as i32;
+
+            if code - count < first {
+                return Some(h.symbol[(index + (code - first)) as usize]);
+            }
+
+            index += count;
+            first += count;
+            first <<= 1;
+            code <<= 1;
+        }
+
+        None
+    }
+
+    /// Decode literals and length/distance pairs until end of block.
+    fn codes(&mut self, lencode: &Huffman, distcode: &Huffman) -> Option<()> {
+        loop {
+            let symbol = self.decode(lencode)? as usize;
+
+            match symbol {
+                0..=255 => {
+                    if self.out.len() >= self.limit {
+                        return None;
+                    }
+
+                    self.out.push(symbol as u8);
+                }
+                256 => return Some(()),
+                _ => {
+                    let symbol = symbol - 257;
+
+                    if symbol >= LENGTH_BASE.len() {
+                        return None;
+                    }
+
+                    let len = LENGTH_BASE[symbol] as usize + self.bits(LENGTH_EXTRA[symbol] as u32)? as usize;
+
+                    let symbol = self.decode(distcode)? as usize;
+
+                    if symbol >= DIST_BASE.len() {
+                        return None;
+                    }
+
+                    let dist = DIST_BASE[symbol] as usize + self.bits(DIST_EXTRA[symbol] as u32)? as usize;
+
+                    if dist > self.out.len() || self.out.len() + len > self.limit {
+                        return None;
+                    }
+
+                    let start = self.out.len() - dist;
+
+                    // Source and destination may overlap, so bytes are copied one by one.
+                    for i in 0..len {
+                        let byte = self.out[start + i];
+                        self.out.push(byte);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Compute Adler-32 checksum.
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for chunk in data.chunks(5552) {
+        for &byte in chunk {
+            a += byte as u32;
+            b += a;
+        }
+
+        a %= 65521;
+        b %= 65521;
+    }
+
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Stored block holding `hello, world`.
+    const STORED: &str = "7801010c00f3ff68656c6c6f2c20776f726c641d540489";
+
+    /// Fixed Huffman block holding `abcabcabcabc hello hello hello`.
+    const FIXED: &str = "78014b4c4a4e8421858cd49c9c7c641200acff0b35";
+
+    /// Dynamic Huffman block holding output of `dynamic_text()`.
+    const DYNAMIC: &str = "\
+        78da9dd25d1642501885e17ba3f886604b3f9a8d384a0e270a65f49666e0bddeebbdda8faf3b67\
+        f1d53e0f67fd58178ddd8630775685af3dc7f6f5b630b9e13ffb7cf95919ee91df1a812601cd01\
+        3429688ea03981e60c9a0b6832f229824024885010b120824144830807110f2220b453c40aaf3e\
+        70f8";
+
+    /// Decode hexadecimal string.
+    fn unhex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Get uncompressed contents of `DYNAMIC` vector.
+    fn dynamic_text() -> Vec<u8> {
+        (0..20)
+            .flat_map(|i| format!("line {}: the quick brown fox jumps over the lazy dog\n", i).into_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn stored_block() {
+        assert_eq!(decompress(&unhex(STORED), 12).as_deref(), Some(&b"hello, world"[..]));
+    }
+
+    #[test]
+    fn fixed_huffman_block() {
+        let expected = b"abcabcabcabc hello hello hello";
+        assert_eq!(decompress(&unhex(FIXED), expected.len()).as_deref(), Some(&expected[..]));
+    }
+
+    #[test]
+    fn dynamic_huffman_block() {
+        let expected = dynamic_text();
+        assert_eq!(decompress(&unhex(DYNAMIC), expected.len()), Some(expected));
+    }
+
+    #[test]
+    fn concatenated_streams() {
+        let data = [unhex(STORED), unhex(FIXED)].concat();
+        let out  = decompress(&data, 42).unwrap();
+
+        assert_eq!(&out[..12], b"hello, world");
+        assert_eq!(&out[12..], b"abcabcabcabc hello hello hello");
+    }
+
+    #[test]
+    fn wrong_size() {
+        assert_eq!(decompress(&unhex(STORED), 11), None);
+        assert_eq!(decompress(&unhex(STORED), 13), None);
+    }
+
+    #[test]
+    fn bad_adler32() {
+        for (vector, size) in [(STORED, 12), (FIXED, 30), (DYNAMIC, dynamic_text().len())] {
+            let mut data = unhex(vector);
+            *data.last_mut().unwrap() ^= 1;
+            assert_eq!(decompress(&data, size), None);
+        }
+    }
+
+    #[test]
+    fn bad_header() {
+        let mut data = unhex(STORED);
+        data[1] ^= 1;
+        assert_eq!(decompress(&data, 12), None);
+
+        // Preset dictionary is not supported.
+        assert_eq!(decompress(&[0x78, 0xbb, 0, 0, 0, 1], 0), None);
+    }
+
+    #[test]
+    fn truncated() {
+        for (vector, size) in [(STORED, 12), (FIXED, 30), (DYNAMIC, dynamic_text().len())] {
+            let data = unhex(vector);
+
+            for end in 1..data.len() {
+                assert_eq!(decompress(&data[..end], size), None);
+            }
+        }
+    }
+
+    #[test]
+    fn corrupted_does_not_panic() {
+        for vector in [STORED, FIXED, DYNAMIC] {
+            let data = unhex(vector);
+
+            for i in 0..data.len() {
+                for bit in 0..8 {
+                    let mut data = data.clone();
+                    data[i] ^= 1 << bit;
+                    let _ = decompress(&data, 1024);
+                }
+            }
+        }
+    }
+}
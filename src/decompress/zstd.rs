@@ -0,0 +1,1116 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Zstandard (RFC 8878) decompression module.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::decompress::initial_capacity;
+
+/// Zstandard frame magic number.
+const ZSTD_MAGIC: u32 = 0xfd2fb528;
+
+/// Skippable frame magic number, lower 4 bits are user defined.
+const SKIPPABLE_MAGIC: u32 = 0x184d2a50;
+
+/// Maximum size of block contents.
+const MAX_BLOCK_SIZE: usize = 128 * 1024;
+
+/// Maximum accuracy log of Huffman weights FSE table.
+const MAX_WEIGHT_LOG: u8 = 6;
+
+/// Maximum number of bits in Huffman code of literals.
+const MAX_HUFFMAN_BITS: u8 = 11;
+
+/// Predefined normalized distribution of literals length codes.
+const LL_DEFAULT: [i16; 36] = [
+    4, 3, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 1, 1, 1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 3, 2, 1, 1, 1, 1, 1,
+    -1, -1, -1, -1,
+];
+
+/// Predefined normalized distribution of match length codes.
+const ML_DEFAULT: [i16; 53] = [
+    1, 4, 3, 2, 2, 2, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1,
+    1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1, -1, -1,
+];
+
+/// Predefined normalized distribution of offset codes.
+const OF_DEFAULT: [i16; 29] = [
+    1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, -1, -1, -1, -1, -1,
+];
+
+/// Baselines and extra bits of literals length codes 16..35.
+const LL_CODES: [(u32, u8); 20] = [
+    (16, 1), (18, 1), (20, 1), (22, 1), (24, 2), (28, 2), (32, 3), (40, 3), (48, 4), (64, 6),
+    (128, 7), (256, 8), (512, 9), (1024, 10), (2048, 11), (4096, 12), (8192, 13), (16384, 14),
+    (32768, 15), (65536, 16),
+];
+
+/// Baselines and extra bits of match length codes 32..52.
+const ML_CODES: [(u32, u8); 21] = [
+    (35, 1), (37, 1), (39, 1), (41, 1), (43, 2), (47, 2), (51, 3), (59, 3), (67, 4), (83, 4),
+    (99, 5), (131, 7), (259, 8), (515, 9), (1027, 10), (2051, 11), (4099, 12), (8195, 13),
+    (16387, 14), (32771, 15), (65539, 16),
+];
+
+/// Decompress concatenated Zstandard frames.
+///
+/// # Parameters
+/// - `data` - given compressed bytes.
+/// - `size` - given expected size of decompressed data.
+///
+/// # Returns
+/// - Decompressed bytes - if data is valid and decompresses to exactly `size`
+///   bytes.
+/// - `None` - otherwise.
+pub fn decompress(data: &[u8], size: usize) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(initial_capacity(data, size));
+    let mut pos = 0;
+
+    while pos < data.len() {
+        let magic = u32::from_le_bytes(data.get(pos..pos + 4)?.try_into().ok()?);
+        pos += 4;
+
+        if magic & 0xfffffff0 == SKIPPABLE_MAGIC {
+            let len = u32::from_le_bytes(data.get(pos..pos + 4)?.try_into().ok()?) as usize;
+            pos = pos.checked_add(4 + len).filter(|&end| end <= data.len())?;
+            continue;
+        }
+
+        if magic != ZSTD_MAGIC {
+            return None;
+        }
+
+        pos += Frame::new(&mut out, size).decode(&data[pos..])?;
+    }
+
+    (out.len() == size).then_some(out)
+}
+
+/// Backward bit stream, read from the last byte towards the first one.
+struct BitReader<'a> {
+    /// Stream bytes.
+    data: &'a [u8],
+    /// Number of bits not consumed yet, negative after reading past the start.
+    pos: isize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Construct bit stream, skipping padding of the last byte.
+    ///
+    /// # Parameters
+    /// - `data` - given stream bytes.
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let last = *data.last()?;
+
+        if last == 0 {
+            return None;
+        }
+
+        let pos = data.len() as isize * 8 - last.leading_zeros() as isize - 1;
+        Some(BitReader { data, pos })
+    }
+
+    /// Get bits at given position, bits before the start are zero.
+    ///
+    /// # Parameters
+    /// - `start` - given position of the lowest bit.
+    /// - `n`     - given number of bits, at most 56.
+    fn bits_at(&self, start: isize, n: u8) -> u64 {
+        if n == 0 {
+            return 0;
+        }
+
+        if start < 0 {
+            let skip = start.unsigned_abs().min(64);
+            return match skip >= n as usize {
+                true  => 0,
+                false => self.bits_at(0, n - skip as u8) << skip,
+            };
+        }
+
+        let byte  = start as usize / 8;
+        let shift = start as usize % 8;
+        let mut buf = [0u8; 8];
+
+        if let Some(bytes) = self.data.get(byte..) {
+            let len = bytes.len().min(8);
+            buf[..len].copy_from_slice(&bytes[..len]);
+        }
+
+        (u64::from_le_bytes(buf) >> shift) & ((1 << n) - 1)
+    }
+
+    /// Read bits.
+    ///
+    /// # Parameters
+    /// - `n` - given number of bits, at most 56.
+    fn read(&mut self, n: u8) -> u64 {
+        self.pos -= n as isize;
+        self.bits_at(self.pos, n)
+    }
+
+    /// Get bits without consuming them.
+    ///
+    /// # Parameters
+    /// - `n` - given number of bits, at most 56.
+    fn peek(&self, n: u8) -> u64 {
+        self.bits_at(self.pos - n as isize, n)
+    }
+}
+
+/// Entry of FSE decoding table.
+#[derive(Debug, Clone, Copy, Default)]
+struct FseEntry {
+    /// Decoded symbol.
+    symbol: u8,
+    /// Number of bits to read for the next state.
+    bits: u8,
+    /// Base of the next state.
+    base: u16,
+}
+
+/// FSE decoding table.
+#[derive(Debug, Clone, Default)]
+struct FseTable {
+    /// Accuracy log.
+    log: u8,
+    /// Table entries.
+    entries: Vec<FseEntry>,
+}
+
+impl FseTable {
+    /// Build decoding table from normalized distribution.
+    ///
+    /// # Parameters
+    /// - `counts` - given normalized counts of symbols, `-1` stands for
+    ///   "less than 1" probability.
+    /// - `log`    - given accuracy log.
+    fn new(counts: &[i16], log: u8) -> Option<Self> {
+        let size     = 1usize << log;
+        let mut high = size - 1;
+        let mut next = vec![0u32; counts.len()];
+        let mut entries = vec![FseEntry::default(); size];
+
+        for (symbol, &count) in counts.iter().enumerate() {
+            if count == -1 {
+                entries[high].symbol = symbol as u8;
+                high = high.wrapping_sub(1);
+                next[symbol] = 1;
+            } else {
+                next[symbol] = count.max(0) as u32;
+            }
+        }
+
+        let step = (size >> 1) + (size >> 3) + 3;
+        let mask = size - 1;
+        let mut pos = 0;
+
+        for (symbol, &count) in counts.iter().enumerate() {
+            for _ in 0..count.max(0) {
+                entries[pos].symbol = symbol as u8;
+                pos = (pos + step) & mask;
+
+                while pos > high {
+                    pos = (pos + step) & mask;
+                }
+            }
+        }
+
+        if pos != 0 {
+            return None;
+        }
+
+        for entry in &mut entries {
+            let state = next[entry.symbol as usize];
+            next[entry.symbol as usize] += 1;
+
+            if state == 0 {
+                return None;
+            }
+
+            entry.bits = log - (31 - state.leading_zeros()) as u8;
+            entry.base = ((state << entry.bits) - size as u32) as u16;
+        }
+
+        Some(FseTable { log, entries })
+    }
+
+    /// Build table that always decodes single symbol.
+    ///
+    /// # Parameters
+    /// - `symbol` - given symbol.
+    fn rle(symbol: u8) -> Self {
+        FseTable {
+            log:     0,
+            entries: vec![FseEntry { symbol, bits: 0, base: 0 }],
+        }
+    }
+
+    /// Read normalized distribution and build decoding table from it.
+    ///
+    /// # Parameters
+    /// - `data`       - given bytes of table description.
+    /// - `max_symbol` - given maximum symbol value.
+    /// - `max_log`    - given maximum accuracy log.
+    ///
+    /// # Returns
+    /// - Table and number of bytes read - in case of success.
+    /// - `None` - otherwise.
+    fn read(data: &[u8], max_symbol: usize, max_log: u8) -> Option<(Self, usize)> {
+        let mut bitpos = 0usize;
+        let bits = |bitpos: usize, n: u32| -> u32 {
+            let mut buf = [0u8; 4];
+            let byte = bitpos / 8;
+
+            if let Some(bytes) = data.get(byte..) {
+                let len = bytes.len().min(4);
+                buf[..len].copy_from_slice(&bytes[..len]);
+            }
+
+            (u32::from_le_bytes(buf) >> (bitpos % 8)) & ((1 << n) - 1)
+        };
+
+        let log = bits(0, 4) as u8 + 5;
+        bitpos += 4;
+
+        if log > max_log {
+            return None;
+        }
+
+        let mut remaining = (1i32 << log) + 1;
+        let mut threshold = 1i32 << log;
+        let mut nbits     = log as u32 + 1;
+        let mut counts    = Vec::new();
+        let mut previous0 = false;
+
+        while remaining > 1 && counts.len() <= max_symbol {
+            if previous0 {
+                loop {
+                    let repeat = bits(bitpos, 2);
+                    bitpos += 2;
+                    counts.extend(core::iter::repeat_n(0, repeat as usize));
+
+                    if repeat != 3 {
+                        break;
+                    }
+                }
+
+                if counts.len() > max_symbol {
+                    return None;
+                }
+            }
+
+            let max = (2 * threshold - 1) - remaining;
+            let low = bits(bitpos, nbits - 1) as i32;
+
+            let mut value = match low < max {
+                true => {
+                    bitpos += nbits as usize - 1;
+                    low
+                }
+                false => {
+                    let value = bits(bitpos, nbits) as i32;
+                    bitpos += nbits as usize;
+
+                    match value >= threshold {
+                        true  => value - max,
+                        false => value,
+                    }
+                }
+            };
+
+            value -= 1;
+            remaining -= value.abs();
+            counts.push(value as i16);
+            previous0 = value == 0;
+
+            if remaining < 1 {
+                return None;
+            }
+
+            while remaining < threshold {
+                nbits -= 1;
+                threshold >>= 1;
+            }
+        }
+
+        let len = bitpos.div_ceil(8);
+
+        if remaining != 1 || counts.len() > max_symbol + 1 || len > data.len() {
+            return None;
+        }
+
+        Some((FseTable::new(&counts, log)?, len))
+    }
+}
+
+/// FSE decoder state.
+struct FseState<'t> {
+    /// Decoding table.
+    table: &'t FseTable,
+    /// Current state.
+    state: usize,
+}
+
+impl<'t> FseState<'t> {
+    /// Initialize state from bit stream.
+    fn new(table: &'t FseTable, bits: &mut BitReader) -> Self {
+        let state = bits.read(table.log) as usize;
+        FseState { table, state }
+    }
+
+    /// Get symbol of current state.
+    fn symbol(&self) -> u8 {
+        self.table.entries[self.state].symbol
+    }
+
+    /// Move to the next state.
+    fn update(&mut self, bits: &mut BitReader) {
+        let entry  = self.table.entries[self.state];
+        self.state = entry.base as usize + bits.read(entry.bits) as usize;
+    }
+}
+
+/// Huffman decoding table of literals.
+#[derive(Debug, Clone, Default)]
+struct HuffmanTable {
+    /// Maximum code length.
+    max_bits: u8,
+    /// Symbols and code lengths indexed by `max_bits` bits of stream.
+    entries: Vec<(u8, u8)>,
+}
+
+impl HuffmanTable {
+    /// Read Huffman tree description.
+    ///
+    /// # Parameters
+    /// - `data` - given bytes starting with tree description.
+    ///
+    /// # Returns
+    /// - Table and number of bytes read - in case of success.
+    /// - `None` - otherwise.
+    fn read(data: &[u8]) -> Option<(Self, usize)> {
+        let header = *data.first()? as usize;
+        let mut weights = Vec::new();
+
+        let len = match header {
+            0..128 => {
+                let stream = data.get(1..1 + header)?;
+                let (table, read) = FseTable::read(stream, 255, MAX_WEIGHT_LOG)?;
+                let mut bits = BitReader::new(&stream[read..])?;
+                let mut s1   = FseState::new(&table, &mut bits);
+                let mut s2   = FseState::new(&table, &mut bits);
+
+                // Two interleaved states share the stream until it is exhausted.
+                loop {
+                    weights.push(s1.symbol());
+                    s1.update(&mut bits);
+
+                    if bits.pos < 0 {
+                        weights.push(s2.symbol());
+                        break;
+                    }
+
+                    weights.push(s2.symbol());
+                    s2.update(&mut bits);
+
+                    if bits.pos < 0 {
+                        weights.push(s1.symbol());
+                        break;
+                    }
+
+                    if weights.len() > 255 {
+                        return None;
+                    }
+                }
+
+                1 + header
+            }
+            _ => {
+                let count = header - 127;
+                let bytes = data.get(1..1 + count.div_ceil(2))?;
+
+                for i in 0..count {
+                    weights.push(match i % 2 {
+                        0 => bytes[i / 2] >> 4,
+                        _ => bytes[i / 2] & 0x0f,
+                    });
+                }
+
+                1 + bytes.len()
+            }
+        };
+
+        Some((HuffmanTable::from_weights(&mut weights)?, len))
+    }
+
+    /// Build decoding table from weights, weight of the last symbol is implied.
+    ///
+    /// # Parameters
+    /// - `weights` - given weights of all symbols but the last one.
+    fn from_weights(weights: &mut Vec<u8>) -> Option<Self> {
+        if weights.len() > 255 {
+            return None;
+        }
+
+        let mut total = 0u32;
+
+        for &weight in weights.iter() {
+            if weight > MAX_HUFFMAN_BITS {
+                return None;
+            }
+
+            if weight > 0 {
+                total += 1 << (weight - 1);
+            }
+        }
+
+        if total == 0 {
+            return None;
+        }
+
+        let max_bits = (32 - total.leading_zeros()) as u8;
+        let rest     = (1u32 << max_bits) - total;
+
+        if max_bits > MAX_HUFFMAN_BITS || !rest.is_power_of_two() {
+            return None;
+        }
+
+        weights.push(rest.trailing_zeros() as u8 + 1);
+
+        let mut entries = Vec::with_capacity(1 << max_bits);
+
+        for weight in 1..=max_bits {
+            for (symbol, _) in weights.iter().enumerate().filter(|&(_, &w)| w == weight) {
+                let len = 1usize << (weight - 1);
+                entries.extend(core::iter::repeat_n((symbol as u8, max_bits + 1 - weight), len));
+            }
+        }
+
+        Some(HuffmanTable { max_bits, entries })
+    }
+
+    /// Decode single Huffman stream.
+    ///
+    /// # Parameters
+    /// - `data`  - given stream bytes.
+    /// - `count` - given number of symbols to decode.
+    /// - `out`   - given buffer to append symbols to.
+    fn decode_stream(&self, data: &[u8], count: usize, out: &mut Vec<u8>) -> Option<()> {
+        let mut bits = BitReader::new(data)?;
+
+        for _ in 0..count {
+            let (symbol, len) = self.entries[bits.peek(self.max_bits) as usize];
+            bits.pos -= len as isize;
+            out.push(symbol);
+        }
+
+        (bits.pos == 0).then_some(())
+    }
+}
+
+/// Frame decoder state.
+struct Frame<'o> {
+    /// Decompressed bytes of all frames.
+    out: &'o mut Vec<u8>,
+    /// Maximum number of decompressed bytes.
+    limit: usize,
+    /// Start of the frame in `out`.
+    start: usize,
+    /// Huffman table of the previous block.
+    huffman: Option<HuffmanTable>,
+    /// FSE tables of the previous block for literals lengths, offsets and
+    /// match lengths.
+    tables: [Option<FseTable>; 3],
+    /// Repeated offsets.
+    offsets: [usize; 3],
+}
+
+impl<'o> Frame<'o> {
+    /// Construct frame decoder.
+    ///
+    /// # Parameters
+    /// - `out`   - given buffer to append decompressed bytes to.
+    /// - `limit` - given maximum size of `out`.
+    fn new(out: &'o mut Vec<u8>, limit: usize) -> Self {
+        let start = out.len();
+
+        Frame {
+            out,
+            limit,
+            start,
+            huffman: None,
+            tables:  [None, None, None],
+            offsets: [1, 4, 8],
+        }
+    }
+
+    /// Decode frame following the magic number.
+    ///
+    /// # Parameters
+    /// - `data` - given bytes starting with frame header.
+    ///
+    /// # Returns
+    /// - Number of bytes read - in case of success.
+    /// - `None` - otherwise.
+    fn decode(mut self, data: &[u8]) -> Option<usize> {
+        let descriptor = *data.first()?;
+        let fcs_flag   = descriptor >> 6;
+        let single     = descriptor & 0x20 != 0;
+        let checksum   = descriptor & 0x04 != 0;
+        let dict_flag  = descriptor & 0x03;
+
+        if descriptor & 0x08 != 0 {
+            return None;
+        }
+
+        let dict_len = [0, 1, 2, 4][dict_flag as usize];
+        let fcs_len  = match fcs_flag {
+            0 => single as usize,
+            1 => 2,
+            2 => 4,
+            _ => 8,
+        };
+
+        let mut pos = 1 + !single as usize;
+
+        // Dictionaries are not supported.
+        if data.get(pos..pos + dict_len)?.iter().any(|&b| b != 0) {
+            return None;
+        }
+
+        pos += dict_len;
+
+        let fcs = data.get(pos..pos + fcs_len)?;
+        let mut content_size = fcs.iter().rev().fold(0u64, |size, &b| (size << 8) | b as u64);
+
+        if fcs_len == 2 {
+            content_size += 256;
+        }
+
+        pos += fcs_len;
+
+        loop {
+            let header = data.get(pos..pos + 3)?;
+            let header = u32::from_le_bytes([header[0], header[1], header[2], 0]);
+            let last   = header & 1 != 0;
+            let size   = (header >> 3) as usize;
+            pos += 3;
+
+            match (header >> 1) & 3 {
+                0 => {
+                    let bytes = data.get(pos..pos + size)?;
+                    self.reserve(size)?;
+                    self.out.extend_from_slice(bytes);
+                    pos += size;
+                }
+                1 => {
+                    let byte = *data.get(pos)?;
+                    self.reserve(size)?;
+                    self.out.extend(core::iter::repeat_n(byte, size));
+                    pos += 1;
+                }
+                2 if size <= MAX_BLOCK_SIZE => {
+                    self.compressed_block(data.get(pos..pos + size)?)?;
+                    pos += size;
+                }
+                _ => return None,
+            }
+
+            if last {
+                break;
+            }
+        }
+
+        let content = &self.out[self.start..];
+
+        if fcs_len != 0 && content.len() as u64 != content_size {
+            return None;
+        }
+
+        if checksum {
+            let expected = u32::from_le_bytes(data.get(pos..pos + 4)?.try_into().ok()?);
+
+            if xxh64(content) as u32 != expected {
+                return None;
+            }
+
+            pos += 4;
+        }
+
+        Some(pos)
+    }
+
+    /// Check that given number of bytes fits into the output.
+    fn reserve(&self, size: usize) -> Option<()> {
+        (self.out.len() + size <= self.limit).then_some(())
+    }
+
+    /// Decode compressed block.
+    ///
+    /// # Parameters
+    /// - `data` - given block contents.
+    fn compressed_block(&mut self, data: &[u8]) -> Option<()> {
+        let (literals, read) = self.literals(data)?;
+        self.sequences(&data[read..], &literals)
+    }
+
+    /// Decode literals section.
+    ///
+    /// # Parameters
+    /// - `data` - given bytes starting with literals section.
+    ///
+    /// # Returns
+    /// - Literals and number of bytes read - in case of success.
+    /// - `None` - otherwise.
+    fn literals(&mut self, data: &[u8]) -> Option<(Vec<u8>, usize)> {
+        let b0     = *data.first()? as usize;
+        let kind   = b0 & 3;
+        let format = (b0 >> 2) & 3;
+
+        if kind < 2 {
+            let (size, header) = match format {
+                0 | 2 => (b0 >> 3, 1),
+                1     => ((b0 >> 4) + ((*data.get(1)? as usize) << 4), 2),
+                _     => ((b0 >> 4) + ((*data.get(1)? as usize) << 4) + ((*data.get(2)? as usize) << 12), 3),
+            };
+
+            return match kind {
+                0 => Some((data.get(header..header + size)?.to_vec(), header + size)),
+                _ => Some((vec![*data.get(header)?; size], header + 1)),
+            };
+        }
+
+        let (header, bits) = match format {
+            0 | 1 => (3, 10),
+            2     => (4, 14),
+            _     => (5, 18),
+        };
+
+        let value = data
+            .get(..header)?
+            .iter()
+            .rev()
+            .fold(0u64, |value, &b| (value << 8) | b as u64);
+
+        let mask       = (1u64 << bits) - 1;
+        let regen_size = ((value >> 4) & mask) as usize;
+        let comp_size  = ((value >> (4 + bits)) & mask) as usize;
+        let mut stream = data.get(header..header + comp_size)?;
+
+        if regen_size > MAX_BLOCK_SIZE {
+            return None;
+        }
+
+        if kind == 2 {
+            let (table, read) = HuffmanTable::read(stream)?;
+            self.huffman = Some(table);
+            stream = &stream[read..];
+        }
+
+        let table = self.huffman.as_ref()?;
+        let mut literals = Vec::with_capacity(regen_size);
+
+        if format == 0 {
+            table.decode_stream(stream, regen_size, &mut literals)?;
+        } else {
+            let jump  = stream.get(..6)?;
+            let sizes = [
+                u16::from_le_bytes([jump[0], jump[1]]) as usize,
+                u16::from_le_bytes([jump[2], jump[3]]) as usize,
+                u16::from_le_bytes([jump[4], jump[5]]) as usize,
+            ];
+
+            let count    = regen_size.div_ceil(4);
+            let mut rest = &stream[6..];
+
+            for (i, &size) in sizes.iter().enumerate() {
+                let part = rest.get(..size)?;
+                table.decode_stream(part, count, &mut literals)?;
+                rest = &rest[size..];
+
+                if i == 2 && regen_size < 3 * count {
+                    return None;
+                }
+            }
+
+            table.decode_stream(rest, regen_size - 3 * count, &mut literals)?;
+        }
+
+        Some((literals, header + comp_size))
+    }
+
+    /// Decode sequences section and execute sequences.
+    ///
+    /// # Parameters
+    /// - `data`     - given bytes of sequences section.
+    /// - `literals` - given decoded literals of the block.
+    fn sequences(&mut self, data: &[u8], literals: &[u8]) -> Option<()> {
+        let b0 = *data.first()? as usize;
+
+        let (count, mut pos) = match b0 {
+            0        => (0, 1),
+            1..128   => (b0, 1),
+            128..255 => (((b0 - 128) << 8) + *data.get(1)? as usize, 2),
+            _        => (*data.get(1)? as usize + ((*data.get(2)? as usize) << 8) + 0x7f00, 3),
+        };
+
+        if count == 0 {
+            self.reserve(literals.len())?;
+            self.out.extend_from_slice(literals);
+            return (pos == data.len()).then_some(());
+        }
+
+        let modes = *data.get(pos)?;
+        pos += 1;
+
+        if modes & 3 != 0 {
+            return None;
+        }
+
+        let kinds: [(u8, &[i16], usize, u8); 3] = [
+            (modes >> 6, &LL_DEFAULT, 35, 9),
+            ((modes >> 4) & 3, &OF_DEFAULT, 31, 8),
+            ((modes >> 2) & 3, &ML_DEFAULT, 52, 9),
+        ];
+
+        for (i, (mode, default, max_symbol, max_log)) in kinds.into_iter().enumerate() {
+            self.tables[i] = match mode {
+                0 => Some(FseTable::new(default, if i == 1 { 5 } else { 6 })?),
+                1 => {
+                    let symbol = *data.get(pos)?;
+                    pos += 1;
+
+                    if symbol as usize > max_symbol {
+                        return None;
+                    }
+
+                    Some(FseTable::rle(symbol))
+                }
+                2 => {
+                    let (table, read) = FseTable::read(&data[pos..], max_symbol, max_log)?;
+                    pos += read;
+                    Some(table)
+                }
+                _ => Some(self.tables[i].take()?),
+            };
+        }
+
+        let [ll_table, of_table, ml_table] = &self.tables;
+        let (ll_table, of_table, ml_table) = (ll_table.as_ref()?, of_table.as_ref()?, ml_table.as_ref()?);
+
+        let mut bits   = BitReader::new(&data[pos..])?;
+        let mut ll     = FseState::new(ll_table, &mut bits);
+        let mut of     = FseState::new(of_table, &mut bits);
+        let mut ml     = FseState::new(ml_table, &mut bits);
+        let mut lit    = 0;
+        let mut output = Vec::new();
+        let mut offsets = self.offsets;
+
+        for i in 0..count {
+            let of_code = of.symbol();
+            let ml_code = ml.symbol() as usize;
+            let ll_code = ll.symbol() as usize;
+
+            if of_code > 31 {
+                return None;
+            }
+
+            let offset_value = (1u64 << of_code) + bits.read(of_code);
+
+            let match_len = match ml_code {
+                0..32 => ml_code as u32 + 3,
+                _     => {
+                    let (base, extra) = ML_CODES[ml_code - 32];
+                    base + bits.read(extra) as u32
+                }
+            } as usize;
+
+            let lit_len = match ll_code {
+                0..16 => ll_code as u32,
+                _     => {
+                    let (base, extra) = LL_CODES[ll_code - 16];
+                    base + bits.read(extra) as u32
+                }
+            } as usize;
+
+            let offset = match offset_value {
+                4.. => {
+                    let offset = offset_value as usize - 3;
+                    offsets = [offset, offsets[0], offsets[1]];
+                    offset
+                }
+                _ => {
+                    let index = offset_value as usize - 1 + (lit_len == 0) as usize;
+
+                    match index {
+                        0 => offsets[0],
+                        1 => {
+                            offsets = [offsets[1], offsets[0], offsets[2]];
+                            offsets[0]
+                        }
+                        2 => {
+                            offsets = [offsets[2], offsets[0], offsets[1]];
+                            offsets[0]
+                        }
+                        _ => {
+                            let offset = offsets[0].checked_sub(1).filter(|&o| o != 0)?;
+                            offsets = [offset, offsets[0], offsets[1]];
+                            offset
+                        }
+                    }
+                }
+            };
+
+            if i + 1 < count {
+                ll.update(&mut bits);
+                ml.update(&mut bits);
+                of.update(&mut bits);
+            }
+
+            output.extend_from_slice(literals.get(lit..lit + lit_len)?);
+            lit += lit_len;
+
+            let frame_len = self.out.len() - self.start + output.len();
+
+            if offset > frame_len || self.out.len() + output.len() + match_len > self.limit {
+                return None;
+            }
+
+            // Match may refer to bytes of the previous blocks of the frame.
+            for _ in 0..match_len {
+                let back = output.len() as isize - offset as isize;
+                let byte = match back {
+                    0.. => output[back as usize],
+                    _   => self.out[(self.out.len() as isize + back) as usize],
+                };
+                output.push(byte);
+            }
+        }
+
+        if bits.pos != 0 {
+            return None;
+        }
+
+        output.extend_from_slice(&literals[lit..]);
+        self.reserve(output.len())?;
+        self.out.extend_from_slice(&output);
+        self.offsets = offsets;
+        Some(())
+    }
+}
+
+/// Compute XXH64 hash with zero seed.
+fn xxh64(data: &[u8]) -> u64 {
+    const P1: u64 = 0x9e3779b185ebca87;
+    const P2: u64 = 0xc2b2ae3d27d4eb4f;
+    const P3: u64 = 0x165667b19e3779f9;
+    const P4: u64 = 0x85ebca77c2b2ae63;
+    const P5: u64 = 0x27d4eb2f165667c5;
+
+    let round = |acc: u64, input: u64| {
+        acc.wrapping_add(input.wrapping_mul(P2)).rotate_left(31).wrapping_mul(P1)
+    };
+    let merge = |acc: u64, val: u64| {
+        (acc ^ round(0, val)).wrapping_mul(P1).wrapping_add(P4)
+    };
+    let word = |bytes: &[u8]| u64::from_le_bytes(bytes[..8].try_into().unwrap());
+
+    let mut chunks = data.chunks_exact(32);
+    let mut hash = match data.len() >= 32 {
+        true => {
+            let mut v = [P1.wrapping_add(P2), P2, 0, 0u64.wrapping_sub(P1)];
+
+            for chunk in &mut chunks {
+                for (i, lane) in v.iter_mut().enumerate() {
+                    *lane = round(*lane, word(&chunk[i * 8..]));
+                }
+            }
+
+            let mut hash = v[0]
+                .rotate_left(1)
+                .wrapping_add(v[1].rotate_left(7))
+                .wrapping_add(v[2].rotate_left(12))
+                .wrapping_add(v[3].rotate_left(18));
+
+            for lane in v {
+                hash = merge(hash, lane);
+            }
+
+            hash
+        }
+        false => P5,
+    };
+
+    hash = hash.wrapping_add(data.len() as u64);
+
+    let mut rest = match data.len() >= 32 {
+        true  => chunks.remainder(),
+        false => data,
+    };
+
+    while rest.len() >= 8 {
+        hash ^= round(0, word(rest));
+        hash = hash.rotate_left(27).wrapping_mul(P1).wrapping_add(P4);
+        rest = &rest[8..];
+    }
+
+    if rest.len() >= 4 {
+        let value = u32::from_le_bytes(rest[..4].try_into().unwrap()) as u64;
+        hash ^= value.wrapping_mul(P1);
+        hash = hash.rotate_left(23).wrapping_mul(P2).wrapping_add(P3);
+        rest = &rest[4..];
+    }
+
+    for &byte in rest {
+        hash ^= (byte as u64).wrapping_mul(P5);
+        hash = hash.rotate_left(11).wrapping_mul(P1);
+    }
+
+    hash ^= hash >> 33;
+    hash = hash.wrapping_mul(P2);
+    hash ^= hash >> 29;
+    hash = hash.wrapping_mul(P3);
+    hash ^ (hash >> 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Frame with raw block holding `hello`.
+    const RAW: &str = "28b52ffd200529000068656c6c6f";
+
+    /// Frame with RLE block holding `x` repeated 10 times.
+    const RLE: &str = "28b52ffd200a53000078";
+
+    /// Skippable frame with 4 bytes of user data.
+    const SKIPPABLE: &str = "502a4d180400000001020304";
+
+    /// Frame with compressed block holding output of `repeat_text()`. Its
+    /// sequences use all three repeat offsets.
+    const COMPRESSED: &str = "\
+        28b52ffd601c01250400e40471727374757677784d616263646566676845696a6b6c6d6e6f70414346\
+        4c43534454484e5145564b50554f5953484a53524d5847454c4f4d53575248414f494a41574a4b5147\
+        515048525a48574534a811e0ef7f07c05698d13110aeabe762e1f9d297f2d8e4269e9760b06082160c\
+        889b23c8a90295c09c3967af820107b20b5405";
+
+    /// Frame with raw block and content checksum.
+    const CHECKSUM: &str = "\
+        28b52ffd2427dd0000a87a73746420636865636b73756d20746573742c20210100ce9b4c8799835a";
+
+    /// Decode hexadecimal string.
+    fn unhex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    /// Get uncompressed contents of `COMPRESSED` vector: words separated by
+    /// pseudo-random letters.
+    fn repeat_text() -> Vec<u8> {
+        let words: [&[u8]; 3] = [b"abcdefgh", b"ijklmnop", b"qrstuvwx"];
+        let mut x: u64 = 7;
+        let mut out    = Vec::new();
+
+        for _ in 0..60 {
+            x = (x * 1103515245 + 12345) & 0x7fffffff;
+            out.extend_from_slice(words[(x >> 8) as usize % 3]);
+            out.push(b'A' + ((x >> 16) % 26) as u8);
+        }
+
+        out
+    }
+
+    #[test]
+    fn raw_block() {
+        assert_eq!(decompress(&unhex(RAW), 5).as_deref(), Some(&b"hello"[..]));
+    }
+
+    #[test]
+    fn rle_block() {
+        assert_eq!(decompress(&unhex(RLE), 10).as_deref(), Some(&b"xxxxxxxxxx"[..]));
+    }
+
+    #[test]
+    fn compressed_block_with_repeat_offsets() {
+        let expected = repeat_text();
+        assert_eq!(decompress(&unhex(COMPRESSED), expected.len()), Some(expected));
+    }
+
+    #[test]
+    fn content_checksum() {
+        let expected = b"zstd checksum test, zstd checksum test!";
+        assert_eq!(decompress(&unhex(CHECKSUM), expected.len()).as_deref(), Some(&expected[..]));
+
+        let mut data = unhex(CHECKSUM);
+        *data.last_mut().unwrap() ^= 1;
+        assert_eq!(decompress(&data, expected.len()), None);
+    }
+
+    #[test]
+    fn multiple_frames() {
+        let data = [unhex(RAW), unhex(SKIPPABLE), unhex(RLE), unhex(COMPRESSED)].concat();
+        let text = repeat_text();
+        let out  = decompress(&data, 15 + text.len()).unwrap();
+
+        assert_eq!(&out[..5], b"hello");
+        assert_eq!(&out[5..15], b"xxxxxxxxxx");
+        assert_eq!(&out[15..], &text[..]);
+    }
+
+    #[test]
+    fn wrong_size() {
+        assert_eq!(decompress(&unhex(RAW), 4), None);
+        assert_eq!(decompress(&unhex(RAW), 6), None);
+    }
+
+    #[test]
+    fn bad_magic() {
+        let mut data = unhex(RAW);
+        data[0] ^= 1;
+        assert_eq!(decompress(&data, 5), None);
+    }
+
+    #[test]
+    fn truncated() {
+        for (vector, size) in [(RAW, 5), (RLE, 10), (COMPRESSED, repeat_text().len()), (CHECKSUM, 39)] {
+            let data = unhex(vector);
+
+            for end in 1..data.len() {
+                assert_eq!(decompress(&data[..end], size), None);
+            }
+        }
+    }
+
+    #[test]
+    fn corrupted_does_not_panic() {
+        for vector in [RAW, RLE, COMPRESSED, CHECKSUM] {
+            let data = unhex(vector);
+
+            for i in 0..data.len() {
+                for bit in 0..8 {
+                    let mut data = data.clone();
+                    data[i] ^= 1 << bit;
+                    let _ = decompress(&data, 1024);
+                }
+            }
+        }
+    }
+}
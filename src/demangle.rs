@@ -0,0 +1,35 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Symbol names demangling module.
+
+pub mod itanium;
+pub mod rust;
+
+/// Demangle symbol name.
+///
+/// Rust demangler is tried first, because legacy Rust names are valid
+/// Itanium C++ names as well.
+///
+/// # Parameters
+/// - `name` - given mangled symbol name.
+///
+/// # Returns
+/// - Demangled name - in case of success.
+/// - `None` - if name is not mangled or malformed.
+pub fn demangle(name: &str) -> Option<String> {
+    rust::demangle(name).or_else(|| itanium::demangle(name))
+}
@@ -0,0 +1,3341 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Itanium C++ ABI demangler module.
+//!
+//! Mangled name is parsed into a tree of components first, substitutions and
+//! template parameters refer to already parsed components. The tree is then
+//! printed in the same way as GNU `c++filt` does: pointers, references and
+//! qualifiers are kept on a modifier stack until the type they apply to is
+//! printed, so that e.g. `PFviE` becomes `void (*)(int)`.
+
+use std::rc::Rc;
+
+/// Maximum nesting of parsed types and expressions.
+const MAX_PARSE_DEPTH: usize = 1024;
+/// Maximum nesting of printed components.
+const MAX_PRINT_DEPTH: usize = 1024;
+
+/// Reference to component of mangled name.
+type NodeRef = Rc<Node>;
+
+/// How literal of builtin type is printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PrintKind {
+    Default,
+    Int,
+    Unsigned,
+    Long,
+    UnsignedLong,
+    LongLong,
+    UnsignedLongLong,
+    Bool,
+    Float,
+    Void,
+}
+
+/// Builtin type description.
+#[derive(Debug)]
+struct BuiltinType {
+    /// Type name.
+    name: &'static str,
+    /// Literal print kind.
+    print: PrintKind,
+}
+
+macro_rules! builtin {
+    ($name:expr, $print:ident) => {
+        Some(BuiltinType { name: $name, print: PrintKind::$print })
+    };
+}
+
+/// Builtin types encoded with single lower case letter.
+static BUILTIN_TYPES: [Option<BuiltinType>; 26] = [
+    builtin!("signed char",        Default),
+    builtin!("bool",               Bool),
+    builtin!("char",               Default),
+    builtin!("double",             Float),
+    builtin!("long double",        Float),
+    builtin!("float",              Float),
+    builtin!("__float128",         Float),
+    builtin!("unsigned char",      Default),
+    builtin!("int",                Int),
+    builtin!("unsigned int",       Unsigned),
+    None,
+    builtin!("long",               Long),
+    builtin!("unsigned long",      UnsignedLong),
+    builtin!("__int128",           Default),
+    builtin!("unsigned __int128",  Default),
+    None,
+    None,
+    None,
+    builtin!("short",              Default),
+    builtin!("unsigned short",     Default),
+    None,
+    builtin!("void",               Void),
+    builtin!("wchar_t",            Default),
+    builtin!("long long",          LongLong),
+    builtin!("unsigned long long", UnsignedLongLong),
+    builtin!("...",                Default),
+];
+
+static DECIMAL32: BuiltinType = BuiltinType { name: "decimal32",         print: PrintKind::Default };
+static DECIMAL64: BuiltinType = BuiltinType { name: "decimal64",         print: PrintKind::Default };
+static DECIMAL128: BuiltinType = BuiltinType { name: "decimal128",       print: PrintKind::Default };
+static HALF: BuiltinType = BuiltinType { name: "half",                   print: PrintKind::Float };
+static CHAR8: BuiltinType = BuiltinType { name: "char8_t",               print: PrintKind::Default };
+static CHAR16: BuiltinType = BuiltinType { name: "char16_t",             print: PrintKind::Default };
+static CHAR32: BuiltinType = BuiltinType { name: "char32_t",             print: PrintKind::Default };
+static NULLPTR: BuiltinType = BuiltinType { name: "decltype(nullptr)",   print: PrintKind::Default };
+static BFLOAT16: BuiltinType = BuiltinType { name: "std::bfloat16_t",    print: PrintKind::Float };
+
+/// Operator description.
+#[derive(Debug)]
+struct OperatorInfo {
+    /// Mangled code.
+    code: &'static str,
+    /// Operator name.
+    name: &'static str,
+    /// Number of operands.
+    args: usize,
+}
+
+macro_rules! operator {
+    ($code:expr, $name:expr, $args:expr) => {
+        OperatorInfo { code: $code, name: $name, args: $args }
+    };
+}
+
+/// Operators sorted by mangled code.
+static OPERATORS: [OperatorInfo; 72] = [
+    operator!("aN", "&=",               2),
+    operator!("aS", "=",                2),
+    operator!("aa", "&&",               2),
+    operator!("ad", "&",                1),
+    operator!("an", "&",                2),
+    operator!("at", "alignof ",         1),
+    operator!("aw", "co_await ",        1),
+    operator!("az", "alignof ",         1),
+    operator!("cc", "const_cast",       2),
+    operator!("cl", "()",               2),
+    operator!("cm", ",",                2),
+    operator!("co", "~",                1),
+    operator!("dV", "/=",               2),
+    operator!("dX", "[...]=",           3),
+    operator!("da", "delete[] ",        1),
+    operator!("dc", "dynamic_cast",     2),
+    operator!("de", "*",                1),
+    operator!("di", "=",                2),
+    operator!("dl", "delete ",          1),
+    operator!("ds", ".*",               2),
+    operator!("dt", ".",                2),
+    operator!("dv", "/",                2),
+    operator!("dx", "]=",               2),
+    operator!("eO", "^=",               2),
+    operator!("eo", "^",                2),
+    operator!("eq", "==",               2),
+    operator!("fL", "...",              3),
+    operator!("fR", "...",              3),
+    operator!("fl", "...",              2),
+    operator!("fr", "...",              2),
+    operator!("ge", ">=",               2),
+    operator!("gs", "::",               1),
+    operator!("gt", ">",                2),
+    operator!("ix", "[]",               2),
+    operator!("lS", "<<=",              2),
+    operator!("le", "<=",               2),
+    operator!("li", "operator\"\" ",    1),
+    operator!("ls", "<<",               2),
+    operator!("lt", "<",                2),
+    operator!("mI", "-=",               2),
+    operator!("mL", "*=",               2),
+    operator!("mi", "-",                2),
+    operator!("ml", "*",                2),
+    operator!("mm", "--",               1),
+    operator!("na", "new[]",            3),
+    operator!("ne", "!=",               2),
+    operator!("ng", "-",                1),
+    operator!("nt", "!",                1),
+    operator!("nw", "new",              3),
+    operator!("nx", "noexcept",         1),
+    operator!("oR", "|=",               2),
+    operator!("oo", "||",               2),
+    operator!("or", "|",                2),
+    operator!("pL", "+=",               2),
+    operator!("pl", "+",                2),
+    operator!("pm", "->*",              2),
+    operator!("pp", "++",               1),
+    operator!("ps", "+",                1),
+    operator!("pt", "->",               2),
+    operator!("qu", "?",                3),
+    operator!("rM", "%=",               2),
+    operator!("rS", ">>=",              2),
+    operator!("rc", "reinterpret_cast", 2),
+    operator!("rm", "%",                2),
+    operator!("rs", ">>",               2),
+    operator!("sP", "sizeof...",        1),
+    operator!("sZ", "sizeof...",        1),
+    operator!("sc", "static_cast",      2),
+    operator!("ss", "<=>",              2),
+    operator!("st", "sizeof ",          1),
+    operator!("sz", "sizeof ",          1),
+    operator!("tw", "throw ",           1),
+];
+
+/// Nullary `throw` operator, it is not part of binary searched table.
+static THROW: OperatorInfo = operator!("tr", "throw", 0);
+
+/// Standard substitution description.
+struct StandardSub {
+    /// Substitution code following `S`.
+    code: u8,
+    /// Expansion used in most places.
+    simple: &'static str,
+    /// Expansion used before constructors and destructors.
+    full: &'static str,
+    /// Name used by following constructor or destructor.
+    last_name: Option<&'static str>,
+}
+
+/// Standard substitutions.
+static STANDARD_SUBS: [StandardSub; 7] = [
+    StandardSub { code: b't', simple: "std", full: "std", last_name: None },
+    StandardSub {
+        code:      b'a',
+        simple:    "std::allocator",
+        full:      "std::allocator",
+        last_name: Some("allocator"),
+    },
+    StandardSub {
+        code:      b'b',
+        simple:    "std::basic_string",
+        full:      "std::basic_string",
+        last_name: Some("basic_string"),
+    },
+    StandardSub {
+        code:      b's',
+        simple:    "std::string",
+        full:      "std::basic_string<char, std::char_traits<char>, std::allocator<char> >",
+        last_name: Some("basic_string"),
+    },
+    StandardSub {
+        code:      b'i',
+        simple:    "std::istream",
+        full:      "std::basic_istream<char, std::char_traits<char> >",
+        last_name: Some("basic_istream"),
+    },
+    StandardSub {
+        code:      b'o',
+        simple:    "std::ostream",
+        full:      "std::basic_ostream<char, std::char_traits<char> >",
+        last_name: Some("basic_ostream"),
+    },
+    StandardSub {
+        code:      b'd',
+        simple:    "std::iostream",
+        full:      "std::basic_iostream<char, std::char_traits<char> >",
+        last_name: Some("basic_iostream"),
+    },
+];
+
+/// Type qualifier.
+#[derive(Debug)]
+enum Qual {
+    Restrict,
+    Volatile,
+    Const,
+    /// Qualifiers of member function, applied to `this`.
+    RestrictThis,
+    VolatileThis,
+    ConstThis,
+    /// Ref-qualifiers of member function.
+    RefThis,
+    RvalueRefThis,
+    TransactionSafe,
+    Noexcept(Option<NodeRef>),
+    ThrowSpec(Option<NodeRef>),
+}
+
+/// Component of mangled name.
+#[derive(Debug)]
+enum Node {
+    Name(String),
+    QualName(NodeRef, NodeRef),
+    LocalName(NodeRef, NodeRef),
+    TypedName(NodeRef, NodeRef),
+    Template(NodeRef, NodeRef),
+    TemplateParam(usize),
+    FunctionParam(usize),
+    Ctor(NodeRef),
+    Dtor(NodeRef),
+    Special(&'static str, NodeRef),
+    ConstructionVtable(NodeRef, NodeRef),
+    RefTemp(NodeRef, NodeRef),
+    Clone(NodeRef, NodeRef),
+    GlobalCtors(NodeRef),
+    GlobalDtors(NodeRef),
+    SubStd(&'static str),
+    Qual(Qual, NodeRef),
+    VendorTypeQual(NodeRef, NodeRef),
+    Pointer(NodeRef),
+    Reference(NodeRef),
+    RvalueReference(NodeRef),
+    Complex(NodeRef),
+    Imaginary(NodeRef),
+    Builtin(&'static BuiltinType),
+    ExtendedBuiltin(i32, Option<char>),
+    VendorType(NodeRef),
+    FunctionType(Option<NodeRef>, NodeRef),
+    ArrayType(Option<NodeRef>, NodeRef),
+    PtrMemType(NodeRef, NodeRef),
+    VectorType(NodeRef, NodeRef),
+    ArgList(Vec<NodeRef>),
+    TemplateArgList(Vec<NodeRef>),
+    InitializerList(Option<NodeRef>, NodeRef),
+    Operator(&'static OperatorInfo),
+    ExtendedOperator(NodeRef),
+    Conversion(NodeRef),
+    Cast(NodeRef),
+    Nullary(NodeRef),
+    Unary(NodeRef, NodeRef, bool),
+    Binary(NodeRef, NodeRef, NodeRef),
+    Trinary(NodeRef, NodeRef, NodeRef, Option<NodeRef>),
+    Literal(NodeRef, bool, NodeRef),
+    Number(i32),
+    Decltype(NodeRef),
+    PackExpansion(NodeRef),
+    Lambda(NodeRef, i32),
+    UnnamedType(i32),
+    TaggedName(NodeRef, NodeRef),
+    DefaultArg(i32, NodeRef),
+    StructuredBinding(Vec<NodeRef>),
+}
+
+impl Node {
+    /// Check whether node is qualifier of member function.
+    fn is_fnqual(&self) -> bool {
+        matches!(
+            self,
+            Node::Qual(
+                Qual::RestrictThis
+                    | Qual::VolatileThis
+                    | Qual::ConstThis
+                    | Qual::RefThis
+                    | Qual::RvalueRefThis
+                    | Qual::TransactionSafe
+                    | Qual::Noexcept(_)
+                    | Qual::ThrowSpec(_),
+                _
+            )
+        )
+    }
+
+    /// Check whether node is plain CV-qualifier.
+    fn is_cv(&self) -> bool {
+        matches!(self, Node::Qual(Qual::Restrict | Qual::Volatile | Qual::Const, _))
+    }
+
+    /// Get type the modifier node applies to.
+    fn inner(&self) -> Option<&NodeRef> {
+        match self {
+            Node::Qual(_, inner)
+            | Node::VendorTypeQual(inner, _)
+            | Node::Pointer(inner)
+            | Node::Reference(inner)
+            | Node::RvalueReference(inner)
+            | Node::Complex(inner)
+            | Node::Imaginary(inner) => Some(inner),
+            _ => None,
+        }
+    }
+
+    /// Get child nodes in print order.
+    fn children(&self) -> Vec<&NodeRef> {
+        match self {
+            Node::QualName(a, b)
+            | Node::LocalName(a, b)
+            | Node::TypedName(a, b)
+            | Node::Template(a, b)
+            | Node::ConstructionVtable(a, b)
+            | Node::RefTemp(a, b)
+            | Node::Clone(a, b)
+            | Node::VendorTypeQual(a, b)
+            | Node::PtrMemType(a, b)
+            | Node::VectorType(a, b)
+            | Node::Unary(a, b, _)
+            | Node::Literal(a, _, b)
+            | Node::TaggedName(a, b) => vec![a, b],
+            Node::Special(_, a)
+            | Node::GlobalCtors(a)
+            | Node::GlobalDtors(a)
+            | Node::Pointer(a)
+            | Node::Reference(a)
+            | Node::RvalueReference(a)
+            | Node::Complex(a)
+            | Node::Imaginary(a)
+            | Node::VendorType(a)
+            | Node::Conversion(a)
+            | Node::Cast(a)
+            | Node::Nullary(a)
+            | Node::Decltype(a)
+            | Node::DefaultArg(_, a) => vec![a],
+            Node::Qual(qual, a) => match qual {
+                Qual::Noexcept(Some(b)) | Qual::ThrowSpec(Some(b)) => vec![a, b],
+                _ => vec![a],
+            },
+            Node::FunctionType(a, b) | Node::ArrayType(a, b) | Node::InitializerList(a, b) => {
+                a.iter().chain(std::iter::once(b)).collect()
+            }
+            Node::Binary(a, b, c) => vec![a, b, c],
+            Node::Trinary(a, b, c, d) => [a, b, c].into_iter().chain(d.iter()).collect(),
+            Node::ArgList(list) | Node::TemplateArgList(list) | Node::StructuredBinding(list) => {
+                list.iter().collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Demangle Itanium C++ ABI symbol name.
+///
+/// # Parameters
+/// - `name` - given mangled symbol name, e.g. `_ZN3foo3barEi`.
+///
+/// # Returns
+/// - Demangled name, e.g. `foo::bar(int)` - in case of success.
+/// - `None` - if name is not mangled or malformed.
+pub fn demangle(name: &str) -> Option<String> {
+    // Unresolved names are tried in current mangling first, then the whole
+    // name is parsed again using the old one.
+    let node = match parse(name, true) {
+        Ok(node)   => node,
+        Err(true)  => parse(name, false).ok()?,
+        Err(false) => return None,
+    };
+
+    let mut printer = Printer::default();
+    printer.print(&node);
+
+    match printer.error {
+        true  => None,
+        false => Some(printer.out),
+    }
+}
+
+/// Parse mangled name.
+///
+/// # Parameters
+/// - `name`           - given mangled symbol name.
+/// - `new_unresolved` - given flag whether to parse unresolved names using
+///   current mangling first.
+///
+/// # Returns
+/// - Parsed name - in case of success.
+/// - `Err(true)` - if the name should be parsed again using old mangling.
+/// - `Err(false)` - otherwise.
+fn parse(name: &str, new_unresolved: bool) -> Result<NodeRef, bool> {
+    let bytes = name.as_bytes();
+
+    if bytes.starts_with(b"_Z") {
+        let mut parser = Parser::new(bytes, new_unresolved);
+
+        match parser.mangled_name(true) {
+            Some(node) if parser.peek() == 0 => Ok(node),
+            _                                => Err(parser.retry_unresolved),
+        }
+    } else if bytes.len() > 10
+        && bytes.starts_with(b"_GLOBAL_")
+        && matches!(bytes[8], b'.' | b'_' | b'$')
+        && matches!(bytes[9], b'D' | b'I')
+        && bytes[10] == b'_'
+    {
+        let mut parser = Parser::new(&bytes[11..], new_unresolved);
+        let keyed  = match parser.peek() == b'_' && parser.peek_next() == b'Z' {
+            true  => {
+                parser.advance(2);
+                parser.encoding(false).ok_or(parser.retry_unresolved)?
+            }
+            false => Rc::new(Node::Name(name[11..].to_string())),
+        };
+
+        match bytes[9] {
+            b'I' => Ok(Rc::new(Node::GlobalCtors(keyed))),
+            _    => Ok(Rc::new(Node::GlobalDtors(keyed))),
+        }
+    } else {
+        Err(false)
+    }
+}
+
+/// Mangled name parser.
+struct Parser<'a> {
+    /// Mangled name bytes.
+    s: &'a [u8],
+    /// Current position.
+    pos: usize,
+    /// Substitution candidates.
+    subs: Vec<NodeRef>,
+    /// Last parsed source name, used by constructors and destructors.
+    last_name: Option<NodeRef>,
+    /// Whether expression is being parsed.
+    is_expression: bool,
+    /// Whether type of conversion operator is being parsed.
+    is_conversion: bool,
+    /// Whether unresolved names are parsed using current mangling.
+    new_unresolved: bool,
+    /// Whether current mangling of unresolved name was tried.
+    retry_unresolved: bool,
+    /// Current nesting of types and expressions.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a [u8], new_unresolved: bool) -> Self {
+        Parser {
+            s,
+            pos:              0,
+            subs:             Vec::new(),
+            last_name:        None,
+            is_expression:    false,
+            is_conversion:    false,
+            new_unresolved,
+            retry_unresolved: false,
+            depth:            0,
+        }
+    }
+
+    fn peek(&self) -> u8 {
+        self.s.get(self.pos).copied().unwrap_or(0)
+    }
+
+    fn peek_next(&self) -> u8 {
+        match self.peek() {
+            0 => 0,
+            _ => self.s.get(self.pos + 1).copied().unwrap_or(0),
+        }
+    }
+
+    fn advance(&mut self, n: usize) {
+        self.pos = (self.pos + n).min(self.s.len());
+    }
+
+    fn next(&mut self) -> u8 {
+        let c = self.peek();
+
+        if c != 0 {
+            self.pos += 1;
+        }
+        c
+    }
+
+    fn check(&mut self, c: u8) -> bool {
+        match self.peek() == c {
+            true  => {
+                self.pos += 1;
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn add_sub(&mut self, node: &NodeRef) {
+        self.subs.push(node.clone());
+    }
+
+    /// `<mangled-name> ::= _Z <encoding> [<clone-suffix>]*`
+    fn mangled_name(&mut self, top_level: bool) -> Option<NodeRef> {
+        if !self.check(b'_') && top_level {
+            return None;
+        }
+
+        if !self.check(b'Z') {
+            return None;
+        }
+
+        let mut node = self.encoding(top_level)?;
+
+        if top_level {
+            while self.peek() == b'.'
+                && matches!(self.peek_next(), b'a'..=b'z' | b'0'..=b'9' | b'_')
+            {
+                node = self.clone_suffix(node);
+            }
+        }
+
+        Some(node)
+    }
+
+    /// Clone suffix, e.g. `.constprop.0` or `.cold`.
+    fn clone_suffix(&mut self, encoding: NodeRef) -> NodeRef {
+        let start = self.pos;
+        let mut end = start;
+
+        if self.s.get(end) == Some(&b'.')
+            && matches!(self.s.get(end + 1), Some(b'a'..=b'z' | b'0'..=b'9' | b'_'))
+        {
+            end += 2;
+            while matches!(self.s.get(end), Some(b'a'..=b'z' | b'0'..=b'9' | b'_')) {
+                end += 1;
+            }
+        }
+
+        while self.s.get(end) == Some(&b'.') && matches!(self.s.get(end + 1), Some(b'0'..=b'9')) {
+            end += 2;
+            while matches!(self.s.get(end), Some(b'0'..=b'9')) {
+                end += 1;
+            }
+        }
+
+        self.pos = end;
+
+        let suffix = Rc::new(Node::Name(self.string(start, end)));
+        Rc::new(Node::Clone(encoding, suffix))
+    }
+
+    fn string(&self, start: usize, end: usize) -> String {
+        String::from_utf8_lossy(&self.s[start..end]).into_owned()
+    }
+
+    /// `<encoding> ::= <name> <bare-function-type> | <name> | <special-name>`
+    fn encoding(&mut self, top_level: bool) -> Option<NodeRef> {
+        let peek = self.peek();
+
+        if peek == b'G' || peek == b'T' {
+            return self.special_name();
+        }
+
+        let name = self.name()?;
+        let peek = self.peek();
+
+        if peek == 0 || peek == b'E' {
+            return Some(name);
+        }
+
+        let mut ftype = self.bare_function_type(has_return_type(&name))?;
+
+        // Return type of function containing local name is not shown.
+        if !top_level
+            && matches!(*name, Node::LocalName(..))
+            && let Node::FunctionType(_, params) = &*ftype
+        {
+            ftype = Rc::new(Node::FunctionType(None, params.clone()));
+        }
+
+        Some(Rc::new(Node::TypedName(name, ftype)))
+    }
+
+    /// `<name> ::= <nested-name> | <unscoped-name> | <unscoped-template-name>
+    /// <template-args> | <local-name>`
+    fn name(&mut self) -> Option<NodeRef> {
+        match self.peek() {
+            b'N' => self.nested_name(),
+            b'Z' => self.local_name(),
+            b'U' => self.unqualified_name(),
+            b'S' => {
+                let (node, subst) = match self.peek_next() {
+                    b't' => {
+                        self.advance(2);
+                        let std  = Rc::new(Node::Name("std".to_string()));
+                        let name = self.unqualified_name()?;
+                        (Rc::new(Node::QualName(std, name)), false)
+                    }
+                    _ => (self.substitution(false)?, true),
+                };
+
+                if self.peek() != b'I' {
+                    return Some(node);
+                }
+
+                if !subst {
+                    self.add_sub(&node);
+                }
+
+                let args = self.template_args()?;
+                Some(Rc::new(Node::Template(node, args)))
+            }
+            _ => {
+                let node = self.unqualified_name()?;
+
+                if self.peek() != b'I' {
+                    return Some(node);
+                }
+
+                self.add_sub(&node);
+
+                let args = self.template_args()?;
+                Some(Rc::new(Node::Template(node, args)))
+            }
+        }
+    }
+
+    /// `<nested-name> ::= N [<CV-qualifiers>] [<ref-qualifier>] <prefix> E`
+    fn nested_name(&mut self) -> Option<NodeRef> {
+        if !self.check(b'N') {
+            return None;
+        }
+
+        let quals  = self.cv_qualifiers(true)?;
+        let rqual  = self.ref_qualifier();
+        let prefix = self.prefix(true)?;
+        let mut node = wrap_qualifiers(quals, prefix);
+
+        if let Some(rqual) = rqual {
+            node = Rc::new(Node::Qual(rqual, node));
+        }
+
+        if !self.check(b'E') {
+            return None;
+        }
+
+        Some(node)
+    }
+
+    /// `<prefix> ::= <prefix> <unqualified-name> | <template-prefix>
+    /// <template-args> | <template-param> | <decltype> | <substitution>`
+    ///
+    /// Components are added to substitution candidates if `subst` is set.
+    fn prefix(&mut self, subst: bool) -> Option<NodeRef> {
+        let mut node: Option<NodeRef> = None;
+
+        loop {
+            let peek = self.peek();
+
+            if peek == b'D' && matches!(self.peek_next(), b'T' | b't') {
+                if node.is_some() {
+                    return None;
+                }
+                node = Some(self.parse_type()?);
+            } else if peek == b'I' {
+                let template = node?;
+                let args     = self.template_args()?;
+                node = Some(Rc::new(Node::Template(template, args)));
+            } else if peek == b'T' {
+                if node.is_some() {
+                    return None;
+                }
+                node = Some(self.template_param()?);
+            } else if peek == b'M' {
+                // Initializer scope of lambda, it is already a candidate.
+                self.advance(1);
+                continue;
+            } else if peek == b'S' {
+                let sub = self.substitution(true)?;
+
+                if node.is_some() {
+                    return None;
+                }
+                node = Some(sub);
+                continue;
+            } else {
+                let name = self.unqualified_name()?;
+
+                node = Some(match node {
+                    Some(scope) => Rc::new(Node::QualName(scope, name)),
+                    None        => name,
+                });
+            }
+
+            if self.peek() == b'E' {
+                return node;
+            }
+
+            if subst && let Some(node) = &node {
+                self.add_sub(node);
+            }
+        }
+    }
+
+    /// `<unqualified-name> ::= <operator-name> | <ctor-dtor-name> |
+    /// <source-name> | <local-source-name> | <unnamed-type-name> [<abi-tags>]`
+    fn unqualified_name(&mut self) -> Option<NodeRef> {
+        let peek = self.peek();
+
+        let mut node = if peek.is_ascii_digit() {
+            self.source_name()?
+        } else if peek.is_ascii_lowercase() {
+            let was_expression = self.is_expression;
+
+            if peek == b'o' && self.peek_next() == b'n' {
+                self.advance(2);
+                // Treat `cv` as naming conversion operator.
+                self.is_expression = false;
+            }
+
+            let op = self.operator_name();
+            self.is_expression = was_expression;
+            let op = op?;
+
+            match &*op {
+                Node::Operator(info) if info.code == "li" => {
+                    let name = self.source_name()?;
+                    Rc::new(Node::Unary(op, name, false))
+                }
+                _ => op,
+            }
+        } else if peek == b'D' && self.peek_next() == b'C' {
+            self.advance(2);
+
+            let mut names = Vec::new();
+            loop {
+                names.push(self.source_name()?);
+
+                if self.peek() == b'E' {
+                    break;
+                }
+            }
+
+            self.advance(1);
+            Rc::new(Node::StructuredBinding(names))
+        } else if peek == b'C' || peek == b'D' {
+            self.ctor_dtor_name()?
+        } else if peek == b'L' {
+            self.advance(1);
+
+            let name = self.source_name()?;
+
+            if !self.discriminator() {
+                return None;
+            }
+            name
+        } else if peek == b'U' {
+            match self.peek_next() {
+                b'l' => self.lambda()?,
+                b't' => self.unnamed_type()?,
+                _    => return None,
+            }
+        } else {
+            return None;
+        };
+
+        if self.peek() == b'B' {
+            let last_name = self.last_name.clone();
+
+            while self.check(b'B') {
+                let tag = self.source_name()?;
+                node = Rc::new(Node::TaggedName(node, tag));
+            }
+
+            self.last_name = last_name;
+        }
+
+        Some(node)
+    }
+
+    /// `<source-name> ::= <length> <identifier>`
+    fn source_name(&mut self) -> Option<NodeRef> {
+        let len = self.number();
+
+        if len <= 0 {
+            return None;
+        }
+
+        let node = self.identifier(len as usize)?;
+        self.last_name = Some(node.clone());
+        Some(node)
+    }
+
+    fn identifier(&mut self, len: usize) -> Option<NodeRef> {
+        let start = self.pos;
+        let end   = start.checked_add(len)?;
+
+        if end > self.s.len() {
+            return None;
+        }
+
+        self.pos = end;
+
+        let name = &self.s[start..end];
+
+        // GCC encoding of anonymous namespace.
+        if len >= 10
+            && name.starts_with(b"_GLOBAL_")
+            && matches!(name[8], b'.' | b'_' | b'$')
+            && name[9] == b'N'
+        {
+            return Some(Rc::new(Node::Name("(anonymous namespace)".to_string())));
+        }
+
+        Some(Rc::new(Node::Name(self.string(start, end))))
+    }
+
+    /// `<number> ::= [n] <non-negative decimal integer>`
+    fn number(&mut self) -> i32 {
+        let negative = self.check(b'n');
+        let mut ret: i32 = 0;
+
+        while self.peek().is_ascii_digit() {
+            let digit = (self.peek() - b'0') as i32;
+
+            if ret > (i32::MAX - digit) / 10 {
+                return -1;
+            }
+
+            ret = ret * 10 + digit;
+            self.advance(1);
+        }
+
+        match negative {
+            true  => -ret,
+            false => ret,
+        }
+    }
+
+    /// Number followed by `_`, empty number means 0.
+    fn compact_number(&mut self) -> i32 {
+        let num = match self.peek() {
+            b'_' => 0,
+            b'n' => return -1,
+            _    => self.number().checked_add(1).unwrap_or(-1),
+        };
+
+        if num < 0 || !self.check(b'_') {
+            return -1;
+        }
+        num
+    }
+
+    /// `<discriminator> ::= _ <digit> | __ <number> _`
+    fn discriminator(&mut self) -> bool {
+        if !self.check(b'_') {
+            return true;
+        }
+
+        let underscores = if self.check(b'_') { 2 } else { 1 };
+        let discrim     = self.number();
+
+        if discrim < 0 {
+            return false;
+        }
+
+        if underscores > 1 && discrim >= 10 {
+            return self.check(b'_');
+        }
+        true
+    }
+
+    /// `<ctor-dtor-name> ::= C <kind> | CI <kind> <type> | D <kind>`
+    fn ctor_dtor_name(&mut self) -> Option<NodeRef> {
+        let last_name = self.last_name.clone()?;
+
+        match self.peek() {
+            b'C' => {
+                let inheriting = self.peek_next() == b'I';
+
+                if inheriting {
+                    self.advance(1);
+                }
+
+                if !matches!(self.peek_next(), b'1'..=b'5') {
+                    return None;
+                }
+
+                self.advance(2);
+
+                if inheriting {
+                    self.parse_type()?;
+                }
+
+                Some(Rc::new(Node::Ctor(last_name)))
+            }
+            b'D' => {
+                if !matches!(self.peek_next(), b'0' | b'1' | b'2' | b'4' | b'5') {
+                    return None;
+                }
+
+                self.advance(2);
+                Some(Rc::new(Node::Dtor(last_name)))
+            }
+            _ => None,
+        }
+    }
+
+    /// `<closure-type-name> ::= Ul <lambda-sig> E [<number>] _`
+    fn lambda(&mut self) -> Option<NodeRef> {
+        if !self.check(b'U') || !self.check(b'l') {
+            return None;
+        }
+
+        let params = self.parmlist()?;
+
+        if !self.check(b'E') {
+            return None;
+        }
+
+        let num = self.compact_number();
+
+        if num < 0 {
+            return None;
+        }
+
+        Some(Rc::new(Node::Lambda(params, num)))
+    }
+
+    /// `<unnamed-type-name> ::= Ut [<number>] _`
+    fn unnamed_type(&mut self) -> Option<NodeRef> {
+        if !self.check(b'U') || !self.check(b't') {
+            return None;
+        }
+
+        let num = self.compact_number();
+
+        if num < 0 {
+            return None;
+        }
+
+        Some(Rc::new(Node::UnnamedType(num)))
+    }
+
+    /// `<operator-name>`, including conversion and vendor extended operators.
+    fn operator_name(&mut self) -> Option<NodeRef> {
+        let c1 = self.next();
+        let c2 = self.next();
+
+        if c1 == b'v' && c2.is_ascii_digit() {
+            let name = self.source_name()?;
+            return Some(Rc::new(Node::ExtendedOperator(name)));
+        }
+
+        if c1 == b'c' && c2 == b'v' {
+            let was_conversion = self.is_conversion;
+            self.is_conversion = !self.is_expression;
+
+            let ty   = self.parse_type();
+            let node = match self.is_conversion {
+                true  => ty.map(|ty| Rc::new(Node::Conversion(ty))),
+                false => ty.map(|ty| Rc::new(Node::Cast(ty))),
+            };
+
+            self.is_conversion = was_conversion;
+            return node;
+        }
+
+        if c1 == b't' && c2 == b'r' {
+            return Some(Rc::new(Node::Operator(&THROW)));
+        }
+
+        let code = [c1, c2];
+        let info = OPERATORS
+            .binary_search_by(|op| op.code.as_bytes().cmp(&code[..]))
+            .ok()
+            .map(|i| &OPERATORS[i])?;
+
+        Some(Rc::new(Node::Operator(info)))
+    }
+
+    /// `<local-name> ::= Z <encoding> E <entity name> [<discriminator>] |
+    /// Z <encoding> E s [<discriminator>]`
+    fn local_name(&mut self) -> Option<NodeRef> {
+        if !self.check(b'Z') {
+            return None;
+        }
+
+        let mut function = self.encoding(false)?;
+
+        if !self.check(b'E') {
+            return None;
+        }
+
+        let name = if self.check(b's') {
+            if !self.discriminator() {
+                return None;
+            }
+            Rc::new(Node::Name("string literal".to_string()))
+        } else {
+            let mut num = -1;
+
+            if self.check(b'd') {
+                num = self.compact_number();
+
+                if num < 0 {
+                    return None;
+                }
+            }
+
+            let mut name = self.name()?;
+
+            if !matches!(*name, Node::Lambda(..) | Node::UnnamedType(_)) && !self.discriminator() {
+                return None;
+            }
+
+            if num >= 0 {
+                name = Rc::new(Node::DefaultArg(num, name));
+            }
+            name
+        };
+
+        // Return type of containing function is not shown.
+        if let Node::TypedName(fname, ftype) = &*function
+            && let Node::FunctionType(Some(_), params) = &**ftype
+        {
+            let ftype = Rc::new(Node::FunctionType(None, params.clone()));
+            function = Rc::new(Node::TypedName(fname.clone(), ftype));
+        }
+
+        Some(Rc::new(Node::LocalName(function, name)))
+    }
+
+    /// `<special-name>`: virtual tables, type information, thunks, guard
+    /// variables and other compiler generated entities.
+    fn special_name(&mut self) -> Option<NodeRef> {
+        let special = |prefix: &'static str, node: NodeRef| Some(Rc::new(Node::Special(prefix, node)));
+
+        if self.check(b'T') {
+            match self.next() {
+                b'V' => special("vtable for ", self.parse_type()?),
+                b'T' => special("VTT for ", self.parse_type()?),
+                b'I' => special("typeinfo for ", self.parse_type()?),
+                b'S' => special("typeinfo name for ", self.parse_type()?),
+                b'h' => {
+                    if !self.call_offset(b'h') {
+                        return None;
+                    }
+                    special("non-virtual thunk to ", self.encoding(false)?)
+                }
+                b'v' => {
+                    if !self.call_offset(b'v') {
+                        return None;
+                    }
+                    special("virtual thunk to ", self.encoding(false)?)
+                }
+                b'c' => {
+                    if !self.call_offset(0) || !self.call_offset(0) {
+                        return None;
+                    }
+                    special("covariant return thunk to ", self.encoding(false)?)
+                }
+                b'C' => {
+                    let derived = self.parse_type()?;
+
+                    if self.number() < 0 || !self.check(b'_') {
+                        return None;
+                    }
+
+                    let base = self.parse_type()?;
+                    Some(Rc::new(Node::ConstructionVtable(base, derived)))
+                }
+                b'F' => special("typeinfo fn for ", self.parse_type()?),
+                b'J' => special("java Class for ", self.parse_type()?),
+                b'H' => special("TLS init function for ", self.name()?),
+                b'W' => special("TLS wrapper function for ", self.name()?),
+                b'A' => special("template parameter object for ", self.template_arg()?),
+                _    => None,
+            }
+        } else if self.check(b'G') {
+            match self.next() {
+                b'V' => special("guard variable for ", self.name()?),
+                b'R' => {
+                    let name = self.name()?;
+                    let num  = Rc::new(Node::Number(self.number()));
+                    Some(Rc::new(Node::RefTemp(name, num)))
+                }
+                b'A' => special("hidden alias for ", self.encoding(false)?),
+                b'T' => match self.next() {
+                    b'n' => special("non-transaction clone for ", self.encoding(false)?),
+                    _    => special("transaction clone for ", self.encoding(false)?),
+                },
+                _ => None,
+            }
+        } else {
+            None
+        }
+    }
+
+    /// `<call-offset> ::= h <nv-offset> _ | v <v-offset> _`
+    fn call_offset(&mut self, c: u8) -> bool {
+        let c = match c {
+            0 => self.next(),
+            c => c,
+        };
+
+        match c {
+            b'h' => {
+                self.number();
+            }
+            b'v' => {
+                self.number();
+
+                if !self.check(b'_') {
+                    return false;
+                }
+                self.number();
+            }
+            _ => return false,
+        }
+
+        self.check(b'_')
+    }
+
+    /// `<substitution> ::= S <seq-id> _ | S_ | St | Sa | Sb | Ss | Si | So | Sd`
+    fn substitution(&mut self, prefix: bool) -> Option<NodeRef> {
+        if !self.check(b'S') {
+            return None;
+        }
+
+        let mut c = self.next();
+
+        if c == b'_' || c.is_ascii_digit() || c.is_ascii_uppercase() {
+            let mut id: usize = 0;
+
+            if c != b'_' {
+                loop {
+                    let digit = match c {
+                        b'0'..=b'9' => c - b'0',
+                        b'A'..=b'Z' => c - b'A' + 10,
+                        _           => return None,
+                    };
+
+                    id = id.checked_mul(36)?.checked_add(digit as usize)?;
+                    c  = self.next();
+
+                    if c == b'_' {
+                        break;
+                    }
+                }
+
+                id += 1;
+            }
+
+            return self.subs.get(id).cloned();
+        }
+
+        let verbose = prefix && matches!(self.peek(), b'C' | b'D');
+        let sub     = STANDARD_SUBS.iter().find(|sub| sub.code == c)?;
+
+        if let Some(last_name) = sub.last_name {
+            self.last_name = Some(Rc::new(Node::SubStd(last_name)));
+        }
+
+        match verbose {
+            true  => Some(Rc::new(Node::SubStd(sub.full))),
+            false => Some(Rc::new(Node::SubStd(sub.simple))),
+        }
+    }
+
+    /// Check whether type qualifier follows.
+    fn next_is_type_qual(&self) -> bool {
+        match self.peek() {
+            b'r' | b'V' | b'K' => true,
+            b'D'               => matches!(self.peek_next(), b'x' | b'o' | b'O' | b'w'),
+            _                  => false,
+        }
+    }
+
+    /// `<CV-qualifiers> ::= [r] [V] [K]` and function qualifiers.
+    ///
+    /// Qualifiers are returned from outermost to innermost one.
+    fn cv_qualifiers(&mut self, member_fn: bool) -> Option<Vec<Qual>> {
+        let mut quals = Vec::new();
+
+        while self.next_is_type_qual() {
+            let qual = match self.next() {
+                b'r' => if member_fn { Qual::RestrictThis } else { Qual::Restrict },
+                b'V' => if member_fn { Qual::VolatileThis } else { Qual::Volatile },
+                b'K' => if member_fn { Qual::ConstThis } else { Qual::Const },
+                _    => match self.next() {
+                    b'x' => Qual::TransactionSafe,
+                    b'o' => Qual::Noexcept(None),
+                    b'O' => {
+                        let expr = self.expression()?;
+
+                        if !self.check(b'E') {
+                            return None;
+                        }
+                        Qual::Noexcept(Some(expr))
+                    }
+                    b'w' => {
+                        let list = self.parmlist()?;
+
+                        if !self.check(b'E') {
+                            return None;
+                        }
+                        Qual::ThrowSpec(Some(list))
+                    }
+                    _ => return None,
+                },
+            };
+
+            quals.push(qual);
+        }
+
+        // Qualifiers before function type apply to `this`.
+        if !member_fn && self.peek() == b'F' {
+            for qual in quals.iter_mut() {
+                *qual = match qual {
+                    Qual::Restrict => Qual::RestrictThis,
+                    Qual::Volatile => Qual::VolatileThis,
+                    Qual::Const    => Qual::ConstThis,
+                    _              => continue,
+                };
+            }
+        }
+
+        Some(quals)
+    }
+
+    /// `<ref-qualifier> ::= R | O`
+    fn ref_qualifier(&mut self) -> Option<Qual> {
+        match self.peek() {
+            b'R' => {
+                self.advance(1);
+                Some(Qual::RefThis)
+            }
+            b'O' => {
+                self.advance(1);
+                Some(Qual::RvalueRefThis)
+            }
+            _ => None,
+        }
+    }
+
+    /// `<type>`
+    fn parse_type(&mut self) -> Option<NodeRef> {
+        if self.depth >= MAX_PARSE_DEPTH {
+            return None;
+        }
+
+        self.depth += 1;
+        let node = self.parse_type_inner();
+        self.depth -= 1;
+        node
+    }
+
+    fn parse_type_inner(&mut self) -> Option<NodeRef> {
+        if self.next_is_type_qual() {
+            let quals = self.cv_qualifiers(false)?;
+            let inner = match self.peek() {
+                // Qualified function type is not a candidate itself.
+                b'F' => self.function_type()?,
+                _    => self.parse_type()?,
+            };
+
+            // Ref-qualifier is printed after CV-qualifiers.
+            let node = match &*inner {
+                Node::Qual(Qual::RefThis, function) => {
+                    Rc::new(Node::Qual(Qual::RefThis, wrap_qualifiers(quals, function.clone())))
+                }
+                Node::Qual(Qual::RvalueRefThis, function) => {
+                    Rc::new(Node::Qual(Qual::RvalueRefThis, wrap_qualifiers(quals, function.clone())))
+                }
+                _ => wrap_qualifiers(quals, inner),
+            };
+
+            self.add_sub(&node);
+            return Some(node);
+        }
+
+        let mut can_subst = true;
+        let peek = self.peek();
+
+        let node = match peek {
+            b'a'..=b'z' if BUILTIN_TYPES[(peek - b'a') as usize].is_some() => {
+                self.advance(1);
+                can_subst = false;
+                Rc::new(Node::Builtin(BUILTIN_TYPES[(peek - b'a') as usize].as_ref()?))
+            }
+            b'u' => {
+                self.advance(1);
+                Rc::new(Node::VendorType(self.source_name()?))
+            }
+            b'F' => self.function_type()?,
+            b'A' => self.array_type()?,
+            b'M' => self.pointer_to_member_type()?,
+            b'T' => {
+                let mut node = self.template_param()?;
+
+                if self.peek() == b'I' {
+                    if !self.is_conversion {
+                        self.add_sub(&node);
+                        let args = self.template_args()?;
+                        node = Rc::new(Node::Template(node, args));
+                    } else {
+                        // Arguments may belong to conversion operator.
+                        let pos       = self.pos;
+                        let subs      = self.subs.len();
+                        let last_name = self.last_name.clone();
+                        let args      = self.template_args()?;
+
+                        if self.peek() == b'I' {
+                            self.add_sub(&node);
+                            node = Rc::new(Node::Template(node, args));
+                        } else {
+                            self.pos       = pos;
+                            self.last_name = last_name;
+                            self.subs.truncate(subs);
+                        }
+                    }
+                }
+                node
+            }
+            b'S' => {
+                let next = self.peek_next();
+
+                if next.is_ascii_digit() || next == b'_' || next.is_ascii_uppercase() {
+                    let sub = self.substitution(false)?;
+
+                    match self.peek() == b'I' {
+                        true  => {
+                            let args = self.template_args()?;
+                            Rc::new(Node::Template(sub, args))
+                        }
+                        false => {
+                            can_subst = false;
+                            sub
+                        }
+                    }
+                } else {
+                    let node = self.name()?;
+
+                    // Complete standard substitution is not a new candidate.
+                    if matches!(*node, Node::SubStd(_)) {
+                        can_subst = false;
+                    }
+                    node
+                }
+            }
+            b'O' => {
+                self.advance(1);
+                Rc::new(Node::RvalueReference(self.parse_type()?))
+            }
+            b'P' => {
+                self.advance(1);
+                Rc::new(Node::Pointer(self.parse_type()?))
+            }
+            b'R' => {
+                self.advance(1);
+                Rc::new(Node::Reference(self.parse_type()?))
+            }
+            b'C' => {
+                self.advance(1);
+                Rc::new(Node::Complex(self.parse_type()?))
+            }
+            b'G' => {
+                self.advance(1);
+                Rc::new(Node::Imaginary(self.parse_type()?))
+            }
+            b'U' => {
+                self.advance(1);
+
+                let mut qual = self.source_name()?;
+
+                if self.peek() == b'I' {
+                    let args = self.template_args()?;
+                    qual = Rc::new(Node::Template(qual, args));
+                }
+
+                let ty = self.parse_type()?;
+                Rc::new(Node::VendorTypeQual(ty, qual))
+            }
+            b'D' => {
+                can_subst = false;
+                self.advance(1);
+                self.builtin_d_type(&mut can_subst)?
+            }
+            b'N' | b'Z' | b'0'..=b'9' => self.name()?,
+            _ => return None,
+        };
+
+        if can_subst {
+            self.add_sub(&node);
+        }
+
+        Some(node)
+    }
+
+    /// Types starting with `D`.
+    fn builtin_d_type(&mut self, can_subst: &mut bool) -> Option<NodeRef> {
+        let builtin = |ty: &'static BuiltinType| Some(Rc::new(Node::Builtin(ty)));
+
+        match self.next() {
+            b'T' | b't' => {
+                *can_subst = true;
+
+                let expr = self.expression()?;
+
+                if self.next() != b'E' {
+                    return None;
+                }
+                Some(Rc::new(Node::Decltype(expr)))
+            }
+            b'p' => {
+                *can_subst = true;
+                Some(Rc::new(Node::PackExpansion(self.parse_type()?)))
+            }
+            b'a' => Some(Rc::new(Node::Name("auto".to_string()))),
+            b'c' => Some(Rc::new(Node::Name("decltype(auto)".to_string()))),
+            b'f' => builtin(&DECIMAL32),
+            b'd' => builtin(&DECIMAL64),
+            b'e' => builtin(&DECIMAL128),
+            b'h' => builtin(&HALF),
+            b'u' => builtin(&CHAR8),
+            b's' => builtin(&CHAR16),
+            b'i' => builtin(&CHAR32),
+            b'n' => builtin(&NULLPTR),
+            b'F' => {
+                let arg = self.number();
+
+                if self.peek() == b'b' {
+                    if arg != 16 {
+                        return None;
+                    }
+
+                    self.advance(1);
+                    return builtin(&BFLOAT16);
+                }
+
+                let suffix = match self.peek() {
+                    b'x' => Some('x'),
+                    b'_' => None,
+                    _    => return None,
+                };
+
+                self.advance(1);
+                Some(Rc::new(Node::ExtendedBuiltin(arg, suffix)))
+            }
+            b'v' => {
+                *can_subst = true;
+
+                let dim = match self.check(b'_') {
+                    true  => self.expression()?,
+                    false => Rc::new(Node::Number(self.number())),
+                };
+
+                if !self.check(b'_') {
+                    return None;
+                }
+
+                let ty = self.parse_type()?;
+                Some(Rc::new(Node::VectorType(dim, ty)))
+            }
+            _ => None,
+        }
+    }
+
+    /// `<function-type> ::= F [Y] <bare-function-type> [<ref-qualifier>] E`
+    fn function_type(&mut self) -> Option<NodeRef> {
+        if !self.check(b'F') {
+            return None;
+        }
+
+        // C linkage is not shown.
+        self.check(b'Y');
+
+        let mut node = self.bare_function_type(true)?;
+
+        if let Some(rqual) = self.ref_qualifier() {
+            node = Rc::new(Node::Qual(rqual, node));
+        }
+
+        if !self.check(b'E') {
+            return None;
+        }
+
+        Some(node)
+    }
+
+    /// `<bare-function-type> ::= [J] <type>+`
+    fn bare_function_type(&mut self, mut has_return_type: bool) -> Option<NodeRef> {
+        if self.check(b'J') {
+            has_return_type = true;
+        }
+
+        let ret = match has_return_type {
+            true  => Some(self.parse_type()?),
+            false => None,
+        };
+
+        let params = self.parmlist()?;
+        Some(Rc::new(Node::FunctionType(ret, params)))
+    }
+
+    /// List of parameter types, single `void` parameter is omitted.
+    fn parmlist(&mut self) -> Option<NodeRef> {
+        let mut params = Vec::new();
+
+        loop {
+            let peek = self.peek();
+
+            if peek == 0 || peek == b'E' || peek == b'.' {
+                break;
+            }
+
+            // Ref-qualifier of function, not a parameter.
+            if (peek == b'R' || peek == b'O') && self.peek_next() == b'E' {
+                break;
+            }
+
+            params.push(self.parse_type()?);
+        }
+
+        if params.is_empty() {
+            return None;
+        }
+
+        if params.len() == 1
+            && let Node::Builtin(ty) = &*params[0]
+            && ty.print == PrintKind::Void
+        {
+            params.clear();
+        }
+
+        Some(Rc::new(Node::ArgList(params)))
+    }
+
+    /// `<array-type> ::= A <number> _ <type> | A [<expression>] _ <type>`
+    fn array_type(&mut self) -> Option<NodeRef> {
+        if !self.check(b'A') {
+            return None;
+        }
+
+        let peek = self.peek();
+
+        let dim = if peek == b'_' {
+            None
+        } else if peek.is_ascii_digit() {
+            let start = self.pos;
+
+            while self.peek().is_ascii_digit() {
+                self.advance(1);
+            }
+            Some(Rc::new(Node::Name(self.string(start, self.pos))))
+        } else {
+            Some(self.expression()?)
+        };
+
+        if !self.check(b'_') {
+            return None;
+        }
+
+        let ty = self.parse_type()?;
+        Some(Rc::new(Node::ArrayType(dim, ty)))
+    }
+
+    /// `<pointer-to-member-type> ::= M <class type> <member type>`
+    fn pointer_to_member_type(&mut self) -> Option<NodeRef> {
+        if !self.check(b'M') {
+            return None;
+        }
+
+        let class  = self.parse_type()?;
+        let member = self.parse_type()?;
+        Some(Rc::new(Node::PtrMemType(class, member)))
+    }
+
+    /// `<template-param> ::= T_ | T <number> _`
+    fn template_param(&mut self) -> Option<NodeRef> {
+        if !self.check(b'T') {
+            return None;
+        }
+
+        let param = self.compact_number();
+
+        if param < 0 {
+            return None;
+        }
+
+        Some(Rc::new(Node::TemplateParam(param as usize)))
+    }
+
+    /// `<template-args> ::= I <template-arg>+ E`
+    fn template_args(&mut self) -> Option<NodeRef> {
+        if self.peek() != b'I' && self.peek() != b'J' {
+            return None;
+        }
+
+        self.advance(1);
+        self.template_args_list()
+    }
+
+    fn template_args_list(&mut self) -> Option<NodeRef> {
+        // Template arguments do not change name of constructors.
+        let last_name = self.last_name.clone();
+
+        if self.check(b'E') {
+            return Some(Rc::new(Node::TemplateArgList(Vec::new())));
+        }
+
+        let mut args = Vec::new();
+
+        loop {
+            args.push(self.template_arg()?);
+
+            if self.check(b'E') {
+                break;
+            }
+        }
+
+        self.last_name = last_name;
+        Some(Rc::new(Node::TemplateArgList(args)))
+    }
+
+    /// `<template-arg> ::= <type> | X <expression> E | <expr-primary> |
+    /// J <template-arg>* E`
+    fn template_arg(&mut self) -> Option<NodeRef> {
+        match self.peek() {
+            b'X' => {
+                self.advance(1);
+
+                let expr = self.expression()?;
+
+                if !self.check(b'E') {
+                    return None;
+                }
+                Some(expr)
+            }
+            b'L'        => self.expr_primary(),
+            b'I' | b'J' => self.template_args(),
+            _           => self.parse_type(),
+        }
+    }
+
+    /// List of expressions up to terminator.
+    fn exprlist(&mut self, terminator: u8) -> Option<NodeRef> {
+        let mut list = Vec::new();
+
+        if self.check(terminator) {
+            return Some(Rc::new(Node::ArgList(list)));
+        }
+
+        loop {
+            list.push(self.expression()?);
+
+            if self.check(terminator) {
+                break;
+            }
+        }
+
+        Some(Rc::new(Node::ArgList(list)))
+    }
+
+    /// `<expression>`
+    fn expression(&mut self) -> Option<NodeRef> {
+        let was_expression = self.is_expression;
+        self.is_expression = true;
+
+        let node = self.expression_1();
+        self.is_expression = was_expression;
+        node
+    }
+
+    fn expression_1(&mut self) -> Option<NodeRef> {
+        if self.depth >= MAX_PARSE_DEPTH {
+            return None;
+        }
+
+        self.depth += 1;
+        let node = self.expression_inner();
+        self.depth -= 1;
+        node
+    }
+
+    fn expression_inner(&mut self) -> Option<NodeRef> {
+        let peek = self.peek();
+        let next = self.peek_next();
+
+        if peek == b'L' {
+            return self.expr_primary();
+        }
+
+        if peek == b'T' {
+            return self.template_param();
+        }
+
+        if peek == b's' && next == b'r' {
+            return self.unresolved_name();
+        }
+
+        if peek == b's' && next == b'p' {
+            self.advance(2);
+            return Some(Rc::new(Node::PackExpansion(self.expression_1()?)));
+        }
+
+        if peek == b'f' && next == b'p' {
+            self.advance(2);
+
+            // Index 0 is `this`, the other parameters are numbered from 1.
+            let index = match self.check(b'T') {
+                true  => 0,
+                false => {
+                    let index = self.compact_number();
+
+                    if index < 0 || index == i32::MAX {
+                        return None;
+                    }
+                    index as usize + 1
+                }
+            };
+            return Some(Rc::new(Node::FunctionParam(index)));
+        }
+
+        if peek.is_ascii_digit() || (peek == b'o' && next == b'n') {
+            if peek == b'o' {
+                self.advance(2);
+            }
+
+            let name = self.unqualified_name()?;
+
+            if self.peek() != b'I' {
+                return Some(name);
+            }
+
+            let args = self.template_args()?;
+            return Some(Rc::new(Node::Template(name, args)));
+        }
+
+        if (peek == b'i' || peek == b't') && next == b'l' {
+            self.advance(2);
+
+            let ty = match peek {
+                b't' => Some(self.parse_type()?),
+                _    => None,
+            };
+
+            if self.peek() == 0 || self.peek_next() == 0 {
+                return None;
+            }
+
+            let list = self.exprlist(b'E')?;
+            return Some(Rc::new(Node::InitializerList(ty, list)));
+        }
+
+        let op = self.operator_name()?;
+
+        let (code, args) = match &*op {
+            Node::Operator(info) => {
+                if info.code == "st" {
+                    let ty = self.parse_type()?;
+                    return Some(Rc::new(Node::Unary(op, ty, false)));
+                }
+                (Some(info.code), info.args)
+            }
+            Node::ExtendedOperator(_) => (None, 1),
+            Node::Cast(_)             => (None, 1),
+            _                         => return None,
+        };
+
+        match args {
+            0 => Some(Rc::new(Node::Nullary(op))),
+            1 => {
+                // `pp_` and `mm_` are prefix forms.
+                let suffix = matches!(code, Some("pp" | "mm")) && !self.check(b'_');
+
+                let operand = if matches!(*op, Node::Cast(_)) && self.check(b'_') {
+                    self.exprlist(b'E')?
+                } else if code == Some("sP") {
+                    self.template_args_list()?
+                } else {
+                    self.expression_1()?
+                };
+
+                Some(Rc::new(Node::Unary(op, operand, suffix)))
+            }
+            2 => {
+                let code = code?;
+
+                let left = if is_new_cast(code) {
+                    self.parse_type()?
+                } else if code.starts_with('f') {
+                    // Fold expression.
+                    self.operator_name()?
+                } else if code == "di" {
+                    self.unqualified_name()?
+                } else {
+                    self.expression_1()?
+                };
+
+                let right = if code == "cl" {
+                    self.exprlist(b'E')?
+                } else if code == "dt" || code == "pt" {
+                    let peek = self.peek();
+                    let next = self.peek_next();
+
+                    if (peek == b'g' && next == b's') || (peek == b's' && next == b'r') {
+                        self.expression_1()?
+                    } else {
+                        let name = self.unqualified_name()?;
+
+                        match self.peek() == b'I' {
+                            true  => {
+                                let args = self.template_args()?;
+                                Rc::new(Node::Template(name, args))
+                            }
+                            false => name,
+                        }
+                    }
+                } else {
+                    self.expression_1()?
+                };
+
+                Some(Rc::new(Node::Binary(op, left, right)))
+            }
+            3 => {
+                let code = code?;
+
+                let (first, second, third) = if code == "qu" || code == "dX" {
+                    let first  = self.expression_1()?;
+                    let second = self.expression_1()?;
+                    let third  = self.expression_1()?;
+                    (first, second, Some(third))
+                } else if code.starts_with('f') {
+                    let first  = self.operator_name()?;
+                    let second = self.expression_1()?;
+                    let third  = self.expression_1()?;
+                    (first, second, Some(third))
+                } else if code == "nw" || code == "na" {
+                    let first  = self.exprlist(b'_')?;
+                    let second = self.parse_type()?;
+
+                    let third = if self.check(b'E') {
+                        None
+                    } else if self.peek() == b'p' && self.peek_next() == b'i' {
+                        self.advance(2);
+                        Some(self.exprlist(b'E')?)
+                    } else if self.peek() == b'i' && self.peek_next() == b'l' {
+                        Some(self.expression_1()?)
+                    } else {
+                        return None;
+                    };
+                    (first, second, third)
+                } else {
+                    return None;
+                };
+
+                Some(Rc::new(Node::Trinary(op, first, second, third)))
+            }
+            _ => None,
+        }
+    }
+
+    /// `<unresolved-name> ::= sr <unresolved-type> <base-unresolved-name> |
+    /// sr <unresolved-qualifier-level>+ E <base-unresolved-name>`
+    fn unresolved_name(&mut self) -> Option<NodeRef> {
+        self.advance(2);
+
+        let peek = self.peek();
+
+        // Old mangling of `A::x` is `sr1A1x`, the current one is `sr1AE1x`.
+        let scope = if self.new_unresolved
+            && (peek.is_ascii_digit() || peek.is_ascii_lowercase() || matches!(peek, b'C' | b'U' | b'L'))
+        {
+            self.retry_unresolved = true;
+
+            let scope = self.prefix(false);
+            self.check(b'E');
+            scope
+        } else {
+            self.parse_type()
+        };
+
+        let name = self.unqualified_name()?;
+
+        let name = match scope {
+            Some(scope) => Rc::new(Node::QualName(scope, name)),
+            None        => name,
+        };
+
+        if self.peek() != b'I' {
+            return Some(name);
+        }
+
+        let args = self.template_args()?;
+        Some(Rc::new(Node::Template(name, args)))
+    }
+
+    /// `<expr-primary> ::= L <type> <value> E | L <mangled-name> E`
+    fn expr_primary(&mut self) -> Option<NodeRef> {
+        if !self.check(b'L') {
+            return None;
+        }
+
+        let node = if self.peek() == b'_' || self.peek() == b'Z' {
+            self.mangled_name(false)?
+        } else {
+            let ty = self.parse_type()?;
+
+            if let Node::Builtin(builtin) = &*ty
+                && std::ptr::eq(*builtin, &NULLPTR)
+                && self.check(b'E')
+            {
+                return Some(ty);
+            }
+
+            let negative = self.check(b'n');
+            let start    = self.pos;
+
+            while self.peek() != b'E' {
+                if self.peek() == 0 {
+                    return None;
+                }
+                self.advance(1);
+            }
+
+            let value = Rc::new(Node::Name(self.string(start, self.pos)));
+            Rc::new(Node::Literal(ty, negative, value))
+        };
+
+        if !self.check(b'E') {
+            return None;
+        }
+
+        Some(node)
+    }
+}
+
+/// Wrap node into qualifiers, the first qualifier becomes outermost.
+fn wrap_qualifiers(quals: Vec<Qual>, inner: NodeRef) -> NodeRef {
+    quals.into_iter().rev().fold(inner, |node, qual| Rc::new(Node::Qual(qual, node)))
+}
+
+/// Check whether encoding of function name includes return type.
+fn has_return_type(node: &Node) -> bool {
+    match node {
+        Node::LocalName(_, name)   => has_return_type(name),
+        Node::Template(name, _)    => !is_ctor_dtor_or_conversion(name),
+        Node::Qual(_, inner) if node.is_fnqual() => has_return_type(inner),
+        _ => false,
+    }
+}
+
+/// Check whether name is constructor, destructor or conversion operator.
+fn is_ctor_dtor_or_conversion(node: &Node) -> bool {
+    match node {
+        Node::QualName(_, name) | Node::LocalName(_, name) => is_ctor_dtor_or_conversion(name),
+        Node::Ctor(_) | Node::Dtor(_) | Node::Conversion(_) => true,
+        _ => false,
+    }
+}
+
+/// Check whether operator code is C++ style cast.
+fn is_new_cast(code: &str) -> bool {
+    matches!(code, "dc" | "sc" | "cc" | "rc")
+}
+
+/// Get code of operator node.
+fn operator_code(node: &Node) -> Option<&'static str> {
+    match node {
+        Node::Operator(info) => Some(info.code),
+        _                    => None,
+    }
+}
+
+/// Pending modifier, printed by type it applies to or after it.
+#[derive(Debug, Clone)]
+struct PrintMod {
+    /// Modifier node.
+    node: NodeRef,
+    /// Whether modifier was printed.
+    printed: bool,
+    /// Templates in scope of modifier.
+    templates: Option<usize>,
+    /// Next (outer) modifier.
+    next: Option<usize>,
+}
+
+/// Template in scope, template parameters refer to its arguments.
+#[derive(Debug)]
+struct PrintTemplate {
+    /// Template node.
+    decl: NodeRef,
+    /// Enclosing template.
+    next: Option<usize>,
+}
+
+/// Demangled name printer.
+#[derive(Debug, Default)]
+struct Printer {
+    /// Output string.
+    out: String,
+    /// Storage of modifiers, linked by indexes.
+    mods: Vec<PrintMod>,
+    /// Current list of modifiers.
+    modifiers: Option<usize>,
+    /// Storage of templates, linked by indexes.
+    templates_list: Vec<PrintTemplate>,
+    /// Current list of templates.
+    templates: Option<usize>,
+    /// Template which conversion operator may refer to.
+    current_template: Option<NodeRef>,
+    /// Index of pack element being printed.
+    pack_index: isize,
+    /// Whether lambda parameters are being printed.
+    lambda_arg: u32,
+    /// Components being printed.
+    stack: Vec<*const Node>,
+    /// Output length after separator of empty list element was removed.
+    removed_at: Option<usize>,
+    /// Templates in scope of referenced template parameters when they were
+    /// printed first.
+    saved_scopes: Vec<(*const Node, Option<usize>)>,
+    /// Whether printing failed.
+    error: bool,
+}
+
+impl Printer {
+    fn last_char(&self) -> u8 {
+        // Removed separator is still seen as the last character, which
+        // GNU demangler does, e.g. `A<B<C>>` is printed when `C` is followed
+        // by empty pack.
+        if self.removed_at == Some(self.out.len()) {
+            return b' ';
+        }
+
+        self.out.as_bytes().last().copied().unwrap_or(0)
+    }
+
+    fn push_mod(&mut self, node: &NodeRef) -> usize {
+        self.mods.push(PrintMod {
+            node:      node.clone(),
+            printed:   false,
+            templates: self.templates,
+            next:      self.modifiers,
+        });
+
+        let index = self.mods.len() - 1;
+        self.modifiers = Some(index);
+        index
+    }
+
+    fn push_template(&mut self, decl: &NodeRef) -> usize {
+        self.templates_list.push(PrintTemplate { decl: decl.clone(), next: self.templates });
+
+        let index = self.templates_list.len() - 1;
+        self.templates = Some(index);
+        index
+    }
+
+    fn pop_template(&mut self, index: usize) {
+        self.templates = self.templates_list[index].next;
+    }
+
+    fn print(&mut self, node: &NodeRef) {
+        if self.error {
+            return;
+        }
+
+        let ptr = Rc::as_ptr(node);
+
+        // Guard against self-referencing substitutions.
+        if self.stack.len() >= MAX_PRINT_DEPTH || self.stack.iter().filter(|p| **p == ptr).count() > 1 {
+            self.error = true;
+            return;
+        }
+
+        self.stack.push(ptr);
+        self.print_inner(node);
+        self.stack.pop();
+    }
+
+    fn print_inner(&mut self, dc: &NodeRef) {
+        match &**dc {
+            Node::Name(name) => self.out.push_str(name),
+            Node::TaggedName(name, tag) => {
+                self.print(name);
+                self.out.push_str("[abi:");
+                self.print(tag);
+                self.out.push(']');
+            }
+            Node::QualName(scope, name) | Node::LocalName(scope, name) => {
+                self.print(scope);
+                self.out.push_str("::");
+
+                let mut name = name;
+
+                if let Node::DefaultArg(num, sub) = &**name {
+                    self.out.push_str(&format!("{{default arg#{}}}::", num + 1));
+                    name = sub;
+                }
+                self.print(name);
+            }
+            Node::TypedName(name, ty) => self.print_typed_name(name, ty),
+            Node::Template(name, args) => {
+                let hold_current = self.current_template.replace(dc.clone());
+                let hold_mods    = self.modifiers.take();
+
+                self.print(name);
+                self.print_template_args(args);
+
+                self.modifiers        = hold_mods;
+                self.current_template = hold_current;
+            }
+            Node::TemplateParam(index) => {
+                if self.lambda_arg > 0 {
+                    self.out.push_str(&format!("auto:{}", index + 1));
+                    return;
+                }
+
+                let Some(arg) = self.template_argument(*index) else {
+                    self.error = true;
+                    return;
+                };
+
+                // Argument may refer to parameter of outer template.
+                let hold = self.templates;
+                self.templates = hold.and_then(|i| self.templates_list[i].next);
+                self.print(&arg);
+                self.templates = hold;
+            }
+            Node::Ctor(name) => self.print(name),
+            Node::Dtor(name) => {
+                self.out.push('~');
+                self.print(name);
+            }
+            Node::Special(prefix, node) => {
+                self.out.push_str(prefix);
+                self.print(node);
+            }
+            Node::ConstructionVtable(base, derived) => {
+                self.out.push_str("construction vtable for ");
+                self.print(base);
+                self.out.push_str("-in-");
+                self.print(derived);
+            }
+            Node::RefTemp(name, num) => {
+                self.out.push_str("reference temporary #");
+                self.print(num);
+                self.out.push_str(" for ");
+                self.print(name);
+            }
+            Node::Clone(encoding, suffix) => {
+                self.print(encoding);
+                self.out.push_str(" [clone ");
+                self.print(suffix);
+                self.out.push(']');
+            }
+            Node::GlobalCtors(node) => {
+                self.out.push_str("global constructors keyed to ");
+                self.print(node);
+            }
+            Node::GlobalDtors(node) => {
+                self.out.push_str("global destructors keyed to ");
+                self.print(node);
+            }
+            Node::SubStd(name) => self.out.push_str(name),
+            Node::Qual(qual, inner) if dc.is_cv() => {
+                // The same qualifier may be pushed several times by arrays or
+                // come from template argument, it is printed once.
+                let mut p = self.modifiers;
+
+                while let Some(i) = p {
+                    let m = &self.mods[i];
+
+                    if !m.printed {
+                        let Node::Qual(pending, _) = &*m.node else {
+                            break;
+                        };
+
+                        if !m.node.is_cv() {
+                            break;
+                        }
+
+                        if std::mem::discriminant(pending) == std::mem::discriminant(qual) {
+                            self.print(inner);
+                            return;
+                        }
+                    }
+                    p = m.next;
+                }
+
+                self.print_modifier(dc, None);
+            }
+            Node::Reference(sub) | Node::RvalueReference(sub) => {
+                let mut sub = sub.clone();
+                let hold    = self.templates;
+
+                // Reference collapsing, `& &&` becomes `&`.
+                if self.lambda_arg == 0
+                    && let Node::TemplateParam(index) = &*sub
+                {
+                    let ptr = Rc::as_ptr(&sub);
+
+                    // Parameter reentered as substitution refers to templates
+                    // it was printed with first.
+                    match self.saved_scopes.iter().find(|(p, _)| *p == ptr) {
+                        Some(&(_, templates)) => {
+                            let stack = &self.stack[..self.stack.len() - 1];
+
+                            if !self.stack.contains(&ptr) && !stack.contains(&Rc::as_ptr(dc)) {
+                                self.templates = templates;
+                            }
+                        }
+                        None => self.saved_scopes.push((ptr, self.templates)),
+                    }
+
+                    let Some(arg) = self.template_argument(*index) else {
+                        self.templates = hold;
+                        self.error = true;
+                        return;
+                    };
+                    sub = arg;
+                }
+
+                let same = std::mem::discriminant(&*sub) == std::mem::discriminant(&**dc);
+
+                if matches!(*sub, Node::Reference(_)) || same {
+                    self.print_modifier(&sub, None);
+                } else if let Node::RvalueReference(inner) = &*sub {
+                    self.print_modifier(dc, Some(inner.clone()));
+                } else {
+                    self.print_modifier(dc, None);
+                }
+
+                self.templates = hold;
+            }
+            Node::Qual(..)
+            | Node::VendorTypeQual(..)
+            | Node::Pointer(_)
+            | Node::Complex(_)
+            | Node::Imaginary(_) => self.print_modifier(dc, None),
+            Node::Builtin(ty) => self.out.push_str(ty.name),
+            Node::ExtendedBuiltin(arg, suffix) => {
+                self.out.push_str(&format!("_Float{}", arg));
+
+                if let Some(suffix) = suffix {
+                    self.out.push(*suffix);
+                }
+            }
+            Node::VendorType(name) => self.print(name),
+            Node::FunctionType(ret, _) => {
+                if let Some(ret) = ret {
+                    // Return type may be pointer to function.
+                    let index = self.push_mod(dc);
+                    self.print(ret);
+                    self.modifiers = self.mods[index].next;
+
+                    if self.mods[index].printed {
+                        return;
+                    }
+
+                    self.out.push(' ');
+                }
+
+                self.print_function_type(dc, self.modifiers);
+            }
+            Node::ArrayType(_, elem) => self.print_array(dc, elem),
+            Node::PtrMemType(_, ty) | Node::VectorType(_, ty) => {
+                let index = self.push_mod(dc);
+                self.print(ty);
+
+                if !self.mods[index].printed {
+                    self.print_mod(dc);
+                }
+
+                self.modifiers = self.mods[index].next;
+            }
+            Node::ArgList(list) | Node::TemplateArgList(list) => self.print_list(list),
+            Node::InitializerList(ty, list) => {
+                if let Some(ty) = ty {
+                    self.print(ty);
+                }
+
+                self.out.push('{');
+                self.print(list);
+                self.out.push('}');
+            }
+            Node::Operator(info) => {
+                self.out.push_str("operator");
+
+                if info.name.as_bytes()[0].is_ascii_lowercase() {
+                    self.out.push(' ');
+                }
+
+                self.out.push_str(info.name.strip_suffix(' ').unwrap_or(info.name));
+            }
+            Node::ExtendedOperator(name) => {
+                self.out.push_str("operator ");
+                self.print(name);
+            }
+            Node::Conversion(ty) => {
+                self.out.push_str("operator ");
+                self.print_conversion(ty);
+            }
+            Node::Nullary(op) => self.print_expr_op(op),
+            Node::Unary(op, operand, suffix) => self.print_unary(op, operand, *suffix),
+            Node::Binary(op, left, right) => self.print_binary(op, left, right),
+            Node::Trinary(op, first, second, third) => self.print_trinary(op, first, second, third),
+            Node::Literal(ty, negative, value) => self.print_literal(ty, *negative, value),
+            Node::Number(num) => self.out.push_str(&num.to_string()),
+            Node::Decltype(expr) => {
+                self.out.push_str("decltype (");
+                self.print(expr);
+                self.out.push(')');
+            }
+            Node::PackExpansion(pattern) => {
+                let Some(pack) = self.find_pack(pattern) else {
+                    // Only function parameter packs are involved.
+                    self.print_subexpr(pattern);
+                    self.out.push_str("...");
+                    return;
+                };
+
+                let len = pack_length(&pack);
+
+                for i in 0..len {
+                    self.pack_index = i as isize;
+                    self.print(pattern);
+
+                    if i + 1 < len {
+                        self.out.push_str(", ");
+                    }
+                }
+            }
+            Node::Lambda(params, num) => {
+                self.out.push_str("{lambda(");
+                self.lambda_arg += 1;
+                self.print(params);
+                self.lambda_arg -= 1;
+                self.out.push_str(&format!(")#{}}}", num + 1));
+            }
+            Node::UnnamedType(num) => self.out.push_str(&format!("{{unnamed type#{}}}", num + 1)),
+            Node::FunctionParam(0) => self.out.push_str("this"),
+            Node::FunctionParam(num) => self.out.push_str(&format!("{{parm#{}}}", num)),
+            Node::StructuredBinding(names) => {
+                self.out.push('[');
+                self.print_list(names);
+                self.out.push(']');
+            }
+            Node::Cast(_) | Node::DefaultArg(..) => self.error = true,
+        }
+    }
+
+    /// Print function name together with its type.
+    fn print_typed_name(&mut self, name: &NodeRef, ty: &NodeRef) {
+        // Name and qualifiers of `this` are passed down to the type to be
+        // printed in the right place.
+        let hold_mods = self.modifiers.take();
+        let mut adpm  = Vec::new();
+        let mut typed = name.clone();
+
+        loop {
+            if adpm.len() >= 4 {
+                self.error = true;
+                return;
+            }
+
+            adpm.push(self.push_mod(&typed));
+
+            match typed.is_fnqual() {
+                true  => typed = typed.inner().cloned().unwrap_or(typed),
+                false => break,
+            }
+        }
+
+        // Class local to function may carry qualifiers applying here.
+        if let Node::LocalName(_, local) = &*typed.clone() {
+            typed = local.clone();
+
+            if let Node::DefaultArg(_, sub) = &*typed.clone() {
+                typed = sub.clone();
+            }
+
+            while typed.is_fnqual() {
+                if adpm.len() >= 4 {
+                    self.error = true;
+                    return;
+                }
+
+                let last = *adpm.last().unwrap_or(&0);
+                let mut copy = self.mods[last].clone();
+                copy.next = Some(last);
+                self.mods.push(copy);
+
+                let index = self.mods.len() - 1;
+                self.modifiers = Some(index);
+
+                self.mods[last].node      = typed.clone();
+                self.mods[last].printed   = false;
+                self.mods[last].templates = self.templates;
+                adpm.push(index);
+
+                typed = typed.inner().cloned().unwrap_or(typed);
+            }
+        }
+
+        // Template arguments are in scope of the function type.
+        let template = match *typed {
+            Node::Template(..) => Some(self.push_template(&typed)),
+            _                  => None,
+        };
+
+        self.print(ty);
+
+        if let Some(template) = template {
+            self.pop_template(template);
+        }
+
+        for &index in adpm.iter().rev() {
+            if !self.mods[index].printed {
+                self.out.push(' ');
+
+                let node = self.mods[index].node.clone();
+                self.print_mod(&node);
+            }
+        }
+
+        self.modifiers = hold_mods;
+    }
+
+    /// Print modifier node after type it applies to.
+    fn print_modifier(&mut self, dc: &NodeRef, inner: Option<NodeRef>) {
+        let index = self.push_mod(dc);
+
+        if let Some(inner) = inner.or_else(|| dc.inner().cloned()) {
+            self.print(&inner);
+        }
+
+        if !self.mods[index].printed {
+            self.print_mod(dc);
+        }
+
+        self.modifiers = self.mods[index].next;
+    }
+
+    /// Print template arguments enclosed in angle brackets.
+    fn print_template_args(&mut self, args: &NodeRef) {
+        if self.last_char() == b'<' {
+            self.out.push(' ');
+        }
+
+        self.out.push('<');
+        self.print(args);
+
+        // Avoid `>>` ambiguity.
+        if self.last_char() == b'>' {
+            self.out.push(' ');
+        }
+
+        self.out.push('>');
+    }
+
+    /// Print comma separated list, trailing elements printing nothing
+    /// (e.g. empty packs) are not separated.
+    fn print_list(&mut self, list: &[NodeRef]) {
+        let Some((first, rest)) = list.split_first() else {
+            return;
+        };
+
+        self.print(first);
+
+        if !rest.is_empty() {
+            self.out.push_str(", ");
+
+            let len = self.out.len();
+            self.print_list(rest);
+
+            if self.out.len() == len {
+                self.out.truncate(len - 2);
+                self.removed_at = Some(self.out.len());
+            }
+        }
+    }
+
+    /// Print modifier itself.
+    fn print_mod(&mut self, node: &NodeRef) {
+        match &**node {
+            Node::Qual(Qual::Restrict | Qual::RestrictThis, _) => self.out.push_str(" restrict"),
+            Node::Qual(Qual::Volatile | Qual::VolatileThis, _) => self.out.push_str(" volatile"),
+            Node::Qual(Qual::Const | Qual::ConstThis, _)       => self.out.push_str(" const"),
+            Node::Qual(Qual::TransactionSafe, _) => self.out.push_str(" transaction_safe"),
+            Node::Qual(Qual::Noexcept(expr), _) => {
+                self.out.push_str(" noexcept");
+
+                if let Some(expr) = expr {
+                    self.out.push('(');
+                    self.print(expr);
+                    self.out.push(')');
+                }
+            }
+            Node::Qual(Qual::ThrowSpec(list), _) => {
+                self.out.push_str(" throw");
+
+                if let Some(list) = list {
+                    self.out.push('(');
+                    self.print(list);
+                    self.out.push(')');
+                }
+            }
+            Node::Qual(Qual::RefThis, _)       => self.out.push_str(" &"),
+            Node::Qual(Qual::RvalueRefThis, _) => self.out.push_str(" &&"),
+            Node::VendorTypeQual(_, qual) => {
+                self.out.push(' ');
+                self.print(qual);
+            }
+            Node::Pointer(_)         => self.out.push('*'),
+            Node::Reference(_)       => self.out.push('&'),
+            Node::RvalueReference(_) => self.out.push_str("&&"),
+            Node::Complex(_)         => self.out.push_str(" _Complex"),
+            Node::Imaginary(_)       => self.out.push_str(" _Imaginary"),
+            Node::PtrMemType(class, _) => {
+                if self.last_char() != b'(' {
+                    self.out.push(' ');
+                }
+
+                self.print(class);
+                self.out.push_str("::*");
+            }
+            Node::TypedName(name, _) => self.print(name),
+            Node::VectorType(dim, _) => {
+                self.out.push_str(" __vector(");
+                self.print(dim);
+                self.out.push(')');
+            }
+            _ => self.print(node),
+        }
+    }
+
+    /// Print list of modifiers, function qualifiers are printed only if
+    /// `suffix` is set.
+    fn print_mod_list(&mut self, mods: Option<usize>, suffix: bool) {
+        let mut p = mods;
+
+        while let Some(i) = p {
+            if self.error {
+                return;
+            }
+
+            let node = self.mods[i].node.clone();
+            let next = self.mods[i].next;
+
+            if self.mods[i].printed || (!suffix && node.is_fnqual()) {
+                p = next;
+                continue;
+            }
+
+            self.mods[i].printed = true;
+
+            let hold = self.templates;
+            self.templates = self.mods[i].templates;
+
+            match &*node {
+                Node::FunctionType(..) => {
+                    self.print_function_type(&node, next);
+                    self.templates = hold;
+                    return;
+                }
+                Node::ArrayType(..) => {
+                    self.print_array_type(&node, next);
+                    self.templates = hold;
+                    return;
+                }
+                Node::LocalName(function, name) => {
+                    let hold_mods = self.modifiers.take();
+                    self.print(function);
+                    self.modifiers = hold_mods;
+
+                    self.out.push_str("::");
+
+                    let mut name = name.clone();
+
+                    if let Node::DefaultArg(num, sub) = &*name.clone() {
+                        self.out.push_str(&format!("{{default arg#{}}}::", num + 1));
+                        name = sub.clone();
+                    }
+
+                    while name.is_fnqual() {
+                        name = name.inner().cloned().unwrap_or(name);
+                    }
+
+                    self.print(&name);
+                    self.templates = hold;
+                    return;
+                }
+                _ => {}
+            }
+
+            self.print_mod(&node);
+            self.templates = hold;
+            p = next;
+        }
+    }
+
+    /// Print function type with pending modifiers placed before parameters,
+    /// e.g. `(*)` or `(Class::*)`.
+    fn print_function_type(&mut self, dc: &NodeRef, mods: Option<usize>) {
+        let mut need_paren = false;
+        let mut need_space = false;
+        let mut p = mods;
+
+        while let Some(i) = p {
+            if self.mods[i].printed {
+                break;
+            }
+
+            match &*self.mods[i].node {
+                Node::Pointer(_) | Node::Reference(_) | Node::RvalueReference(_) => need_paren = true,
+                Node::VendorTypeQual(..)
+                | Node::Complex(_)
+                | Node::Imaginary(_)
+                | Node::PtrMemType(..) => {
+                    need_space = true;
+                    need_paren = true;
+                }
+                node if node.is_cv() => {
+                    need_space = true;
+                    need_paren = true;
+                }
+                _ => {}
+            }
+
+            if need_paren {
+                break;
+            }
+            p = self.mods[i].next;
+        }
+
+        if need_paren {
+            if !need_space && self.last_char() != b'(' && self.last_char() != b'*' {
+                need_space = true;
+            }
+
+            if need_space && self.last_char() != b' ' {
+                self.out.push(' ');
+            }
+
+            self.out.push('(');
+        }
+
+        let hold_mods = self.modifiers.take();
+
+        self.print_mod_list(mods, false);
+
+        if need_paren {
+            self.out.push(')');
+        }
+
+        self.out.push('(');
+
+        if let Node::FunctionType(_, params) = &**dc {
+            self.print(params);
+        }
+
+        self.out.push(')');
+        self.print_mod_list(mods, true);
+
+        self.modifiers = hold_mods;
+    }
+
+    /// Print array type node.
+    fn print_array(&mut self, dc: &NodeRef, elem: &NodeRef) {
+        // Array is passed down as modifier to print multi-dimensional arrays,
+        // qualifiers of the array apply to its elements.
+        let hold_mods = self.modifiers;
+        let first     = self.push_mod(dc);
+        let mut adpm  = vec![first];
+        let mut p     = hold_mods;
+
+        while let Some(i) = p {
+            if !self.mods[i].node.is_cv() {
+                break;
+            }
+
+            if !self.mods[i].printed {
+                if adpm.len() >= 4 {
+                    self.error = true;
+                    return;
+                }
+
+                let mut copy = self.mods[i].clone();
+                copy.next = self.modifiers;
+                self.mods.push(copy);
+
+                let index = self.mods.len() - 1;
+                self.modifiers = Some(index);
+                self.mods[i].printed = true;
+                adpm.push(index);
+            }
+
+            p = self.mods[i].next;
+        }
+
+        self.print(elem);
+        self.modifiers = hold_mods;
+
+        if self.mods[first].printed {
+            return;
+        }
+
+        for &index in adpm[1..].iter().rev() {
+            let node = self.mods[index].node.clone();
+            self.print_mod(&node);
+        }
+
+        self.print_array_type(dc, self.modifiers);
+    }
+
+    /// Print array dimension with pending modifiers, e.g. `(&) [3]`.
+    fn print_array_type(&mut self, dc: &NodeRef, mods: Option<usize>) {
+        let mut need_space = true;
+
+        if mods.is_some() {
+            let mut need_paren = false;
+            let mut p = mods;
+
+            while let Some(i) = p {
+                if !self.mods[i].printed {
+                    match *self.mods[i].node {
+                        Node::ArrayType(..) => need_space = false,
+                        _                   => {
+                            need_paren = true;
+                            need_space = true;
+                        }
+                    }
+                    break;
+                }
+                p = self.mods[i].next;
+            }
+
+            if need_paren {
+                self.out.push_str(" (");
+            }
+
+            self.print_mod_list(mods, false);
+
+            if need_paren {
+                self.out.push(')');
+            }
+        }
+
+        if need_space {
+            self.out.push(' ');
+        }
+
+        self.out.push('[');
+
+        if let Node::ArrayType(Some(dim), _) = &**dc {
+            self.print(dim);
+        }
+
+        self.out.push(']');
+    }
+
+    /// Print type of conversion operator.
+    fn print_conversion(&mut self, ty: &NodeRef) {
+        // Type may refer to parameters of the enclosing template.
+        let template = self.current_template.clone().map(|decl| self.push_template(&decl));
+
+        match &**ty {
+            Node::Template(name, args) => {
+                self.print(name);
+
+                if let Some(template) = template {
+                    self.pop_template(template);
+                }
+
+                self.print_template_args(args);
+            }
+            _ => {
+                self.print(ty);
+
+                if let Some(template) = template {
+                    self.pop_template(template);
+                }
+            }
+        }
+    }
+
+    /// Get template argument by parameter index.
+    fn template_argument(&mut self, index: usize) -> Option<NodeRef> {
+        let arg = self.lookup_template_argument(index as isize)?;
+
+        match &*arg {
+            Node::TemplateArgList(_) => index_template_argument(&arg, self.pack_index),
+            _                        => Some(arg),
+        }
+    }
+
+    fn lookup_template_argument(&mut self, index: isize) -> Option<NodeRef> {
+        let Some(template) = self.templates else {
+            self.error = true;
+            return None;
+        };
+
+        match &*self.templates_list[template].decl {
+            Node::Template(_, args) => index_template_argument(args, index),
+            _                       => None,
+        }
+    }
+
+    /// Find template argument pack referred by pack expansion pattern.
+    fn find_pack(&mut self, node: &NodeRef) -> Option<NodeRef> {
+        match &**node {
+            // Parameters of lambda are `auto`, not template arguments.
+            Node::TemplateParam(_) if self.lambda_arg > 0 => None,
+            Node::TemplateParam(index) => {
+                let arg = self.lookup_template_argument(*index as isize)?;
+
+                match *arg {
+                    Node::TemplateArgList(_) => Some(arg),
+                    _                        => None,
+                }
+            }
+            Node::PackExpansion(_)
+            | Node::Lambda(..)
+            | Node::Name(_)
+            | Node::TaggedName(..)
+            | Node::Operator(_)
+            | Node::Builtin(_)
+            | Node::ExtendedBuiltin(..)
+            | Node::SubStd(_)
+            | Node::FunctionParam(_)
+            | Node::UnnamedType(_)
+            | Node::DefaultArg(..)
+            | Node::Number(_) => None,
+            Node::ExtendedOperator(name) | Node::Ctor(name) | Node::Dtor(name) => self.find_pack(name),
+            _ => node.children().into_iter().find_map(|child| self.find_pack(child)),
+        }
+    }
+
+    fn print_expr_op(&mut self, op: &NodeRef) {
+        match &**op {
+            Node::Operator(info) => self.out.push_str(info.name),
+            _                    => self.print(op),
+        }
+    }
+
+    /// Print subexpression, enclosed in parentheses unless it is simple.
+    fn print_subexpr(&mut self, node: &NodeRef) {
+        let simple = matches!(
+            **node,
+            Node::Name(_) | Node::QualName(..) | Node::InitializerList(..) | Node::FunctionParam(_)
+        );
+
+        if !simple {
+            self.out.push('(');
+        }
+
+        self.print(node);
+
+        if !simple {
+            self.out.push(')');
+        }
+    }
+
+    fn print_unary(&mut self, op: &NodeRef, operand: &NodeRef, suffix: bool) {
+        let code = operator_code(op);
+        let mut operand = operand.clone();
+
+        if code.is_some() {
+            // Address of function is printed without its parameters.
+            if code == Some("ad")
+                && let Node::TypedName(name, ty) = &*operand.clone()
+                && matches!(**name, Node::QualName(..))
+                && matches!(**ty, Node::FunctionType(..))
+            {
+                operand = name.clone();
+            }
+
+            if suffix {
+                self.print_subexpr(&operand);
+                self.print_expr_op(op);
+                return;
+            }
+        }
+
+        // `sizeof...` is printed as length of the pack.
+        if code == Some("sZ") {
+            let len = self.find_pack(&operand).map(|pack| pack_length(&pack)).unwrap_or(0);
+            self.out.push_str(&len.to_string());
+            return;
+        }
+
+        if code == Some("sP") {
+            let len = self.args_length(&operand);
+            self.out.push_str(&len.to_string());
+            return;
+        }
+
+        match &**op {
+            Node::Cast(ty) => {
+                self.out.push('(');
+                self.print(ty);
+                self.out.push(')');
+            }
+            _ => self.print_expr_op(op),
+        }
+
+        match code {
+            // No parentheses after `::`.
+            Some("gs") => self.print(&operand),
+            Some("st") => {
+                self.out.push('(');
+                self.print(&operand);
+                self.out.push(')');
+            }
+            _ => self.print_subexpr(&operand),
+        }
+    }
+
+    fn print_binary(&mut self, op: &NodeRef, left: &NodeRef, right: &NodeRef) {
+        let code = operator_code(op).unwrap_or("");
+
+        if is_new_cast(code) {
+            self.print_expr_op(op);
+            self.out.push('<');
+            self.print(left);
+            self.out.push_str(">(");
+            self.print(right);
+            self.out.push(')');
+            return;
+        }
+
+        if code.starts_with('f') {
+            self.print_fold(code, left, right, None);
+            return;
+        }
+
+        // Greater-than is enclosed in parentheses not to end template
+        // arguments.
+        let greater = matches!(&**op, Node::Operator(info) if info.name == ">");
+
+        if greater {
+            self.out.push('(');
+        }
+
+        // Function call does not print types of its parameters.
+        match &**left {
+            Node::TypedName(name, ty) if code == "cl" => {
+                if !matches!(**ty, Node::FunctionType(..)) {
+                    self.error = true;
+                }
+                self.print_subexpr(name);
+            }
+            _ => self.print_subexpr(left),
+        }
+
+        if code == "ix" {
+            self.out.push('[');
+            self.print(right);
+            self.out.push(']');
+        } else {
+            if code != "cl" {
+                self.print_expr_op(op);
+            }
+            self.print_subexpr(right);
+        }
+
+        if greater {
+            self.out.push(')');
+        }
+    }
+
+    fn print_trinary(&mut self, op: &NodeRef, first: &NodeRef, second: &NodeRef, third: &Option<NodeRef>) {
+        let code = operator_code(op).unwrap_or("");
+
+        if code.starts_with('f') {
+            self.print_fold(code, first, second, third.as_ref());
+            return;
+        }
+
+        if code == "qu" {
+            self.print_subexpr(first);
+            self.print_expr_op(op);
+            self.print_subexpr(second);
+            self.out.push_str(" : ");
+
+            if let Some(third) = third {
+                self.print_subexpr(third);
+            }
+            return;
+        }
+
+        self.out.push_str("new ");
+
+        if matches!(&**first, Node::ArgList(list) if !list.is_empty()) {
+            self.print_subexpr(first);
+            self.out.push(' ');
+        }
+
+        self.print(second);
+
+        if let Some(third) = third {
+            self.print_subexpr(third);
+        }
+    }
+
+    /// Print fold expression, e.g. `(... + args)`.
+    fn print_fold(&mut self, code: &str, op: &NodeRef, op1: &NodeRef, op2: Option<&NodeRef>) {
+        // The whole pack is printed.
+        let pack_index = self.pack_index;
+        self.pack_index = -1;
+
+        match code.as_bytes()[1] {
+            b'l' => {
+                self.out.push_str("(...");
+                self.print_expr_op(op);
+                self.print_subexpr(op1);
+                self.out.push(')');
+            }
+            b'r' => {
+                self.out.push('(');
+                self.print_subexpr(op1);
+                self.print_expr_op(op);
+                self.out.push_str("...)");
+            }
+            _ => {
+                self.out.push('(');
+                self.print_subexpr(op1);
+                self.print_expr_op(op);
+                self.out.push_str("...");
+                self.print_expr_op(op);
+
+                if let Some(op2) = op2 {
+                    self.print_subexpr(op2);
+                }
+                self.out.push(')');
+            }
+        }
+
+        self.pack_index = pack_index;
+    }
+
+    fn print_literal(&mut self, ty: &NodeRef, negative: bool, value: &NodeRef) {
+        let kind = match &**ty {
+            Node::Builtin(builtin) => builtin.print,
+            _                      => PrintKind::Default,
+        };
+
+        let suffix = match kind {
+            PrintKind::Int              => Some(""),
+            PrintKind::Unsigned         => Some("u"),
+            PrintKind::Long             => Some("l"),
+            PrintKind::UnsignedLong     => Some("ul"),
+            PrintKind::LongLong         => Some("ll"),
+            PrintKind::UnsignedLongLong => Some("ull"),
+            _                           => None,
+        };
+
+        if let Some(suffix) = suffix
+            && matches!(**value, Node::Name(_))
+        {
+            if negative {
+                self.out.push('-');
+            }
+
+            self.print(value);
+            self.out.push_str(suffix);
+            return;
+        }
+
+        if kind == PrintKind::Bool
+            && !negative
+            && let Node::Name(value) = &**value
+        {
+            match value.as_str() {
+                "0" => return self.out.push_str("false"),
+                "1" => return self.out.push_str("true"),
+                _   => {}
+            }
+        }
+
+        self.out.push('(');
+        self.print(ty);
+        self.out.push(')');
+
+        if negative {
+            self.out.push('-');
+        }
+
+        if kind == PrintKind::Float {
+            self.out.push('[');
+        }
+
+        self.print(value);
+
+        if kind == PrintKind::Float {
+            self.out.push(']');
+        }
+    }
+
+    /// Count template arguments, expanding packs.
+    fn args_length(&mut self, args: &NodeRef) -> usize {
+        let Node::TemplateArgList(list) = &**args else {
+            return 0;
+        };
+
+        let mut count = 0;
+
+        for arg in list {
+            match &**arg {
+                Node::PackExpansion(pattern) => {
+                    count += self.find_pack(pattern).map(|pack| pack_length(&pack)).unwrap_or(0);
+                }
+                _ => count += 1,
+            }
+        }
+
+        count
+    }
+}
+
+/// Get template argument by index, negative index means the whole list.
+fn index_template_argument(args: &NodeRef, index: isize) -> Option<NodeRef> {
+    if index < 0 {
+        return Some(args.clone());
+    }
+
+    match &**args {
+        Node::TemplateArgList(list) => list.get(index as usize).cloned(),
+        _                           => None,
+    }
+}
+
+/// Get number of elements of argument pack.
+fn pack_length(pack: &NodeRef) -> usize {
+    match &**pack {
+        Node::TemplateArgList(list) => list.len(),
+        _                           => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that each mangled name demangles to the expected one.
+    fn check(vectors: &[(&str, &str)]) {
+        for (mangled, expected) in vectors {
+            assert_eq!(demangle(mangled).as_deref(), Some(*expected), "{}", mangled);
+        }
+    }
+
+    #[test]
+    fn nested_names() {
+        check(&[
+            ("_ZN3foo3barEi",                         "foo::bar(int)"),
+            ("_ZN3foo3bar3bazEv",                     "foo::bar::baz()"),
+            ("_ZNK3foo3barEv",                        "foo::bar() const"),
+            ("_ZSt4cout",                             "std::cout"),
+            ("_ZNSt8ios_base4InitC1Ev",               "std::ios_base::Init::Init()"),
+            ("_ZN9__gnu_cxx13new_allocatorIcED2Ev",   "__gnu_cxx::new_allocator<char>::~new_allocator()"),
+            ("_ZN1N1fB5cxx11Ev",                      "N::f[abi:cxx11]()"),
+        ]);
+    }
+
+    #[test]
+    fn templates() {
+        check(&[
+            ("_Z3maxIiET_S0_S0_",    "int max<int>(int, int)"),
+            ("_Z1fILi3EEvv",         "void f<3>()"),
+            ("_Z1fIJidEEvDpT_",      "void f<int, double>(int, double)"),
+            ("_Z1fIiEDTplfp_fp_ET_", "decltype ({parm#1}+{parm#1}) f<int>(int)"),
+        ]);
+    }
+
+    #[test]
+    fn substitutions() {
+        check(&[
+            (
+                "_ZNSt6vectorIiSaIiEE9push_backERKi",
+                "std::vector<int, std::allocator<int> >::push_back(int const&)",
+            ),
+            (
+                "_ZNSt7__cxx1112basic_stringIcSt11char_traitsIcESaIcEEC1EPKcRKS3_",
+                "std::__cxx11::basic_string<char, std::char_traits<char>, std::allocator<char> >\
+                 ::basic_string(char const*, std::allocator<char> const&)",
+            ),
+            ("_ZN1AplERKS_", "A::operator+(A const&)"),
+        ]);
+    }
+
+    #[test]
+    fn function_types() {
+        check(&[
+            ("_Z1fPFviE",   "f(void (*)(int))"),
+            ("_Z1fM1AFivE", "f(int (A::*)())"),
+            ("_Z1fRA10_i",  "f(int (&) [10])"),
+            ("_Z1fDn",      "f(decltype(nullptr))"),
+        ]);
+    }
+
+    #[test]
+    fn operators() {
+        check(&[
+            ("_ZN1AclEv",   "A::operator()()"),
+            ("_ZNK1AcvbEv", "A::operator bool() const"),
+            ("_ZN1AaSEOS_", "A::operator=(A&&)"),
+            ("_Znwm",       "operator new(unsigned long)"),
+            ("_ZdlPvm",     "operator delete(void*, unsigned long)"),
+        ]);
+    }
+
+    #[test]
+    fn lambdas() {
+        check(&[
+            ("_ZZ4mainENKUlvE_clEv",  "main::{lambda()#1}::operator()() const"),
+            ("_ZZ4mainENKUliE0_clEi", "main::{lambda(int)#2}::operator()(int) const"),
+        ]);
+    }
+
+    #[test]
+    fn clones() {
+        check(&[
+            ("_Z3foov.cold",         "foo() [clone .cold]"),
+            ("_Z3fooi.constprop.0",  "foo(int) [clone .constprop.0]"),
+            ("_Z3fooi.isra.0.cold",  "foo(int) [clone .isra.0] [clone .cold]"),
+        ]);
+    }
+
+    #[test]
+    fn special_names() {
+        check(&[
+            ("_ZTV1A",        "vtable for A"),
+            ("_ZTI1A",        "typeinfo for A"),
+            ("_ZTS1A",        "typeinfo name for A"),
+            ("_ZThn8_N1B1fEv", "non-virtual thunk to B::f()"),
+            ("_ZGVZ4mainE1x", "guard variable for main::x"),
+        ]);
+    }
+
+    #[test]
+    fn malformed() {
+        let names = [
+            "", "main", "foo", "_Z", "_ZN3foo", "_Z3fooIi", "_ZNK", "_Z999x",
+            "_Z1fPFviEE", "_ZSt", "_ZTV", "_Z3maxIiET_S5_",
+        ];
+
+        for name in names {
+            assert_eq!(demangle(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn truncated_and_corrupted_do_not_panic() {
+        let names = [
+            "_ZNSt7__cxx1112basic_stringIcSt11char_traitsIcESaIcEEC1EPKcRKS3_",
+            "_Z1fIiEDTplfp_fp_ET_",
+            "_ZZ4mainENKUliE0_clEi",
+            "_Z1fIJidEEvDpT_",
+            "_ZThn8_N1B1fEv",
+        ];
+
+        for name in names {
+            for end in 0..name.len() {
+                let _ = demangle(&name[..end]);
+            }
+
+            for i in 0..name.len() {
+                for b in [b'S', b'T', b'_', b'E', b'0', b'9', b'I', b'N'] {
+                    let mut bytes = name.as_bytes().to_vec();
+                    bytes[i] = b;
+                    let _ = demangle(core::str::from_utf8(&bytes).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deep_nesting() {
+        assert_eq!(demangle(&format!("_Z1f{}i", "P".repeat(100_000))), None);
+    }
+}
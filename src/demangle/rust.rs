@@ -0,0 +1,1239 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Rust symbol names demangler module.
+//!
+//! Both mangling schemes are supported: legacy one (`_ZN...17h<hash>E`),
+//! which is Itanium-like path with escaped punctuation and trailing hash,
+//! and v0 one (`_R...`). Hashes and crate disambiguators are not shown.
+
+/// Maximum nesting of v0 paths, types and constants.
+const MAX_RECURSION: usize = 1024;
+
+/// Demangle Rust symbol name.
+///
+/// # Parameters
+/// - `name` - given mangled symbol name.
+///
+/// # Returns
+/// - Demangled name, e.g. `core::ptr::drop_in_place` - in case of success.
+/// - `None` - if name is not Rust mangled name or malformed.
+pub fn demangle(name: &str) -> Option<String> {
+    let bytes = name.as_bytes();
+
+    let (sym, legacy) = if let Some(sym) = bytes.strip_prefix(b"_R") {
+        (sym, false)
+    } else if let Some(sym) = bytes.strip_prefix(b"_ZN") {
+        (sym, true)
+    } else {
+        return None;
+    };
+
+    // Paths of v0 symbols always start with upper case characters.
+    if !legacy && !sym.first().is_some_and(|c| c.is_ascii_uppercase()) {
+        return None;
+    }
+
+    let mut len = 0;
+
+    for &c in sym {
+        // Suffix of v0 symbol (e.g. `.llvm.123`) is ignored.
+        if !legacy && c == b'.' {
+            break;
+        }
+
+        len += 1;
+
+        if c == b'_' || c.is_ascii_alphanumeric() {
+            continue;
+        }
+
+        if legacy && matches!(c, b'$' | b'.' | b':' | b'@') {
+            continue;
+        }
+        return None;
+    }
+
+    let mut demangler = Demangler::new(&sym[..len]);
+
+    match legacy {
+        true  => demangler.demangle_legacy()?,
+        false => demangler.demangle_v0(),
+    }
+
+    match demangler.errored {
+        true  => None,
+        false => Some(demangler.out),
+    }
+}
+
+/// Identifier of path segment.
+#[derive(Debug, Default, Clone, Copy)]
+struct Ident<'a> {
+    /// ASCII part.
+    ascii: &'a [u8],
+    /// Punycode encoded part.
+    punycode: &'a [u8],
+}
+
+impl Ident<'_> {
+    fn is_empty(&self) -> bool {
+        self.ascii.is_empty() && self.punycode.is_empty()
+    }
+}
+
+/// Rust symbol demangler state.
+struct Demangler<'a> {
+    /// Symbol without `_R` or `_ZN` prefix.
+    sym: &'a [u8],
+    /// Current position.
+    next: usize,
+    /// Whether legacy scheme is used.
+    legacy: bool,
+    /// Whether symbol is malformed.
+    errored: bool,
+    /// Whether output is suppressed.
+    skipping_printing: bool,
+    /// Number of lifetimes bound by enclosing binders.
+    bound_lifetime_depth: u64,
+    /// Current nesting.
+    recursion: usize,
+    /// Output string.
+    out: String,
+}
+
+impl<'a> Demangler<'a> {
+    fn new(sym: &'a [u8]) -> Self {
+        Demangler {
+            sym,
+            next:                 0,
+            legacy:               false,
+            errored:              false,
+            skipping_printing:    false,
+            bound_lifetime_depth: 0,
+            recursion:            0,
+            out:                  String::new(),
+        }
+    }
+
+    /// Demangle legacy symbol, `None` means the name is not Rust one.
+    fn demangle_legacy(&mut self) -> Option<()> {
+        self.legacy = true;
+
+        let mut ident = Ident::default();
+
+        while self.peek() != b'E' {
+            ident = self.parse_ident();
+
+            if self.errored || ident.ascii.is_empty() {
+                return None;
+            }
+        }
+
+        // Path always ends with `E`, which may be followed by suffix.
+        if self.sym[self.next + 1..].first().is_some_and(|&c| c != b'.') {
+            return None;
+        }
+
+        self.sym = &self.sym[..self.next];
+
+        // The last path segment is always hash.
+        if !is_legacy_hash(ident.ascii) {
+            return None;
+        }
+
+        self.next = 0;
+        self.sym  = &self.sym[..self.sym.len() - 19];
+
+        while self.next < self.sym.len() {
+            if self.next > 0 {
+                self.print("::");
+            }
+
+            let ident = self.parse_ident();
+            self.print_ident(ident);
+        }
+
+        Some(())
+    }
+
+    fn demangle_v0(&mut self) {
+        self.demangle_path(true);
+
+        // Instantiating crate is not shown.
+        if !self.errored && self.next < self.sym.len() {
+            self.skipping_printing = true;
+            self.demangle_path(false);
+        }
+
+        if self.next != self.sym.len() {
+            self.errored = true;
+        }
+    }
+
+    fn peek(&self) -> u8 {
+        self.sym.get(self.next).copied().unwrap_or(0)
+    }
+
+    fn eat(&mut self, c: u8) -> bool {
+        match self.peek() == c {
+            true  => {
+                self.next += 1;
+                true
+            }
+            false => false,
+        }
+    }
+
+    fn next_char(&mut self) -> u8 {
+        let c = self.peek();
+
+        match c {
+            0 => self.errored = true,
+            _ => self.next += 1,
+        }
+        c
+    }
+
+    fn print(&mut self, s: &str) {
+        if !self.errored && !self.skipping_printing {
+            self.out.push_str(s);
+        }
+    }
+
+    fn print_bytes(&mut self, s: &[u8]) {
+        if !self.errored && !self.skipping_printing {
+            self.out.push_str(&String::from_utf8_lossy(s));
+        }
+    }
+
+    /// Enter nested path, type or constant.
+    fn enter(&mut self) -> bool {
+        self.recursion += 1;
+
+        if self.recursion > MAX_RECURSION {
+            self.errored = true;
+        }
+        !self.errored
+    }
+
+    fn leave(&mut self) {
+        self.recursion -= 1;
+    }
+
+    /// `<base-62-number> ::= {<0-9a-zA-Z>} _`
+    fn parse_integer_62(&mut self) -> u64 {
+        if self.eat(b'_') {
+            return 0;
+        }
+
+        let mut x: u64 = 0;
+
+        while !self.eat(b'_') {
+            let c = self.next_char();
+
+            let digit = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'z' => c - b'a' + 10,
+                b'A'..=b'Z' => c - b'A' + 36,
+                _           => {
+                    self.errored = true;
+                    return 0;
+                }
+            };
+
+            x = x.wrapping_mul(62).wrapping_add(digit as u64);
+        }
+
+        x.wrapping_add(1)
+    }
+
+    fn parse_opt_integer_62(&mut self, tag: u8) -> u64 {
+        match self.eat(tag) {
+            true  => self.parse_integer_62().wrapping_add(1),
+            false => 0,
+        }
+    }
+
+    fn parse_disambiguator(&mut self) -> u64 {
+        self.parse_opt_integer_62(b's')
+    }
+
+    /// Parse lower case hex digits terminated by `_`.
+    ///
+    /// # Returns
+    /// - Value and number of digits.
+    fn parse_hex_nibbles(&mut self) -> (u64, usize) {
+        let mut x: u64 = 0;
+        let mut len    = 0;
+
+        while !self.eat(b'_') {
+            len += 1;
+
+            let c = self.next_char();
+
+            let nibble = match c {
+                b'0'..=b'9' => c - b'0',
+                b'a'..=b'f' => c - b'a' + 10,
+                _           => {
+                    self.errored = true;
+                    return (0, len);
+                }
+            };
+
+            x = (x << 4) | nibble as u64;
+        }
+
+        (x, len)
+    }
+
+    /// `<identifier> ::= [u] <decimal-number> [_] <bytes>`
+    fn parse_ident(&mut self) -> Ident<'a> {
+        let mut ident = Ident::default();
+        let punycode  = !self.legacy && self.eat(b'u');
+        let c         = self.next_char();
+
+        if !c.is_ascii_digit() {
+            self.errored = true;
+            return ident;
+        }
+
+        let mut len = (c - b'0') as usize;
+
+        if c != b'0' {
+            while self.peek().is_ascii_digit() {
+                let digit = (self.next_char() - b'0') as usize;
+                len = len.wrapping_mul(10).wrapping_add(digit);
+            }
+        }
+
+        // Optional separator of v0 identifiers starting with digit or `_`.
+        if !self.legacy {
+            self.eat(b'_');
+        }
+
+        let start = self.next;
+
+        match start.checked_add(len) {
+            Some(end) if end <= self.sym.len() => self.next = end,
+            _                                  => {
+                self.errored = true;
+                return ident;
+            }
+        }
+
+        ident.ascii = &self.sym[start..self.next];
+
+        if punycode {
+            // The last `_` separates ASCII and punycode parts.
+            let split = ident.ascii.iter().rposition(|&c| c == b'_');
+            let (ascii, encoded) = match split {
+                Some(i) => (&ident.ascii[..i], &ident.ascii[i + 1..]),
+                None    => (&ident.ascii[..0], ident.ascii),
+            };
+
+            if encoded.is_empty() {
+                self.errored = true;
+                return ident;
+            }
+
+            ident.ascii    = ascii;
+            ident.punycode = encoded;
+        }
+
+        ident
+    }
+
+    fn print_ident(&mut self, ident: Ident) {
+        if self.errored || self.skipping_printing {
+            return;
+        }
+
+        if self.legacy {
+            self.print_legacy_ident(ident.ascii);
+            return;
+        }
+
+        if ident.punycode.is_empty() {
+            self.print_bytes(ident.ascii);
+            return;
+        }
+
+        match decode_punycode(ident.ascii, ident.punycode) {
+            Some(decoded) => self.print(&decoded),
+            None          => self.errored = true,
+        }
+    }
+
+    fn print_legacy_ident(&mut self, mut ascii: &[u8]) {
+        // Underscore is inserted before escape sequence to start identifier.
+        if ascii.starts_with(b"_$") {
+            ascii = &ascii[1..];
+        }
+
+        while !ascii.is_empty() {
+            let len = match ascii[0] {
+                b'$' => match decode_legacy_escape(ascii) {
+                    Some((c, len)) => {
+                        self.out.push(c);
+                        len
+                    }
+                    None => {
+                        // Unexpected escape sequence, print the rest verbatim.
+                        self.print_bytes(ascii);
+                        return;
+                    }
+                },
+                b'.' => match ascii.get(1) {
+                    Some(b'.') => {
+                        self.out.push_str("::");
+                        2
+                    }
+                    _ => {
+                        self.out.push('.');
+                        1
+                    }
+                },
+                _ => {
+                    let len = ascii.iter().position(|&c| c == b'$' || c == b'.').unwrap_or(ascii.len());
+                    self.print_bytes(&ascii[..len]);
+                    len
+                }
+            };
+
+            ascii = &ascii[len..];
+        }
+    }
+
+    fn print_lifetime_from_index(&mut self, lt: u64) {
+        self.print("'");
+
+        if lt == 0 {
+            self.print("_");
+            return;
+        }
+
+        let depth = self.bound_lifetime_depth.wrapping_sub(lt);
+
+        // Letters are used first, then `'_123`.
+        match depth < 26 {
+            true  => self.print(&((b'a' + depth as u8) as char).to_string()),
+            false => self.print(&format!("_{}", depth)),
+        }
+    }
+
+    /// `<binder> ::= G <base-62-number>`
+    fn demangle_binder(&mut self) {
+        if self.errored {
+            return;
+        }
+
+        let bound_lifetimes = self.parse_opt_integer_62(b'G');
+
+        if bound_lifetimes > 0 {
+            self.print("for<");
+
+            for i in 0..bound_lifetimes {
+                if i > 0 {
+                    self.print(", ");
+                }
+
+                self.bound_lifetime_depth = self.bound_lifetime_depth.wrapping_add(1);
+                self.print_lifetime_from_index(1);
+            }
+
+            self.print("> ");
+        }
+    }
+
+    /// Resolve back reference and demangle with given function.
+    fn backref(&mut self, f: impl FnOnce(&mut Self)) {
+        let backref = self.parse_integer_62();
+
+        if !self.skipping_printing {
+            let old_next = self.next;
+            self.next = usize::try_from(backref).unwrap_or(usize::MAX);
+            f(self);
+            self.next = old_next;
+        }
+    }
+
+    /// `<path>`
+    fn demangle_path(&mut self, in_value: bool) {
+        if self.errored {
+            return;
+        }
+
+        if self.enter() {
+            self.demangle_path_inner(in_value);
+        }
+        self.leave();
+    }
+
+    fn demangle_path_inner(&mut self, in_value: bool) {
+        let tag = self.next_char();
+
+        match tag {
+            b'C' => {
+                self.parse_disambiguator();
+
+                let name = self.parse_ident();
+                self.print_ident(name);
+            }
+            b'N' => {
+                let ns = self.next_char();
+
+                if !ns.is_ascii_alphabetic() {
+                    self.errored = true;
+                    return;
+                }
+
+                self.demangle_path(in_value);
+
+                let dis  = self.parse_disambiguator();
+                let name = self.parse_ident();
+
+                if ns.is_ascii_uppercase() {
+                    // Special namespaces, like closures and shims.
+                    self.print("::{");
+
+                    match ns {
+                        b'C' => self.print("closure"),
+                        b'S' => self.print("shim"),
+                        _    => self.print(&(ns as char).to_string()),
+                    }
+
+                    if !name.is_empty() {
+                        self.print(":");
+                        self.print_ident(name);
+                    }
+
+                    self.print(&format!("#{}}}", dis));
+                } else if !name.is_empty() {
+                    self.print("::");
+                    self.print_ident(name);
+                }
+            }
+            b'M' | b'X' | b'Y' => {
+                if tag != b'Y' {
+                    // Path of `impl` itself is not shown.
+                    self.parse_disambiguator();
+
+                    let was_skipping = self.skipping_printing;
+                    self.skipping_printing = true;
+                    self.demangle_path(in_value);
+                    self.skipping_printing = was_skipping;
+                }
+
+                self.print("<");
+                self.demangle_type();
+
+                if tag != b'M' {
+                    self.print(" as ");
+                    self.demangle_path(false);
+                }
+
+                self.print(">");
+            }
+            b'I' => {
+                self.demangle_path(in_value);
+
+                if in_value {
+                    self.print("::");
+                }
+
+                self.print("<");
+                self.demangle_generic_args();
+                self.print(">");
+            }
+            b'B' => self.backref(|d| d.demangle_path(in_value)),
+            _    => self.errored = true,
+        }
+    }
+
+    /// Generic arguments up to terminating `E`.
+    fn demangle_generic_args(&mut self) {
+        let mut i = 0;
+
+        while !self.errored && !self.eat(b'E') {
+            if i > 0 {
+                self.print(", ");
+            }
+
+            self.demangle_generic_arg();
+            i += 1;
+        }
+    }
+
+    /// `<generic-arg> ::= <lifetime> | <type> | K <const>`
+    fn demangle_generic_arg(&mut self) {
+        if self.eat(b'L') {
+            let lt = self.parse_integer_62();
+            self.print_lifetime_from_index(lt);
+        } else if self.eat(b'K') {
+            self.demangle_const();
+        } else {
+            self.demangle_type();
+        }
+    }
+
+    /// `<type>`
+    fn demangle_type(&mut self) {
+        if self.errored {
+            return;
+        }
+
+        if self.enter() {
+            self.demangle_type_inner();
+        }
+        self.leave();
+    }
+
+    fn demangle_type_inner(&mut self) {
+        let tag = self.next_char();
+
+        if let Some(basic) = basic_type(tag) {
+            self.print(basic);
+            return;
+        }
+
+        match tag {
+            b'R' | b'Q' => {
+                self.print("&");
+
+                if self.eat(b'L') {
+                    let lt = self.parse_integer_62();
+
+                    if lt > 0 {
+                        self.print_lifetime_from_index(lt);
+                        self.print(" ");
+                    }
+                }
+
+                if tag != b'R' {
+                    self.print("mut ");
+                }
+
+                self.demangle_type();
+            }
+            b'P' | b'O' => {
+                match tag {
+                    b'P' => self.print("*const "),
+                    _    => self.print("*mut "),
+                }
+
+                self.demangle_type();
+            }
+            b'A' | b'S' => {
+                self.print("[");
+                self.demangle_type();
+
+                if tag == b'A' {
+                    self.print("; ");
+                    self.demangle_const();
+                }
+
+                self.print("]");
+            }
+            b'T' => {
+                self.print("(");
+
+                let mut i = 0;
+
+                while !self.errored && !self.eat(b'E') {
+                    if i > 0 {
+                        self.print(", ");
+                    }
+
+                    self.demangle_type();
+                    i += 1;
+                }
+
+                // One element tuple.
+                if i == 1 {
+                    self.print(",");
+                }
+
+                self.print(")");
+            }
+            b'F' => {
+                let old_depth = self.bound_lifetime_depth;
+                self.demangle_fn_type();
+                self.bound_lifetime_depth = old_depth;
+            }
+            b'D' => {
+                self.print("dyn ");
+
+                let old_depth = self.bound_lifetime_depth;
+                self.demangle_binder();
+
+                let mut i = 0;
+
+                while !self.errored && !self.eat(b'E') {
+                    if i > 0 {
+                        self.print(" + ");
+                    }
+
+                    self.demangle_dyn_trait();
+                    i += 1;
+                }
+
+                self.bound_lifetime_depth = old_depth;
+
+                if !self.eat(b'L') {
+                    self.errored = true;
+                    return;
+                }
+
+                let lt = self.parse_integer_62();
+
+                if lt > 0 {
+                    self.print(" + ");
+                    self.print_lifetime_from_index(lt);
+                }
+            }
+            b'B' => self.backref(|d| d.demangle_type()),
+            _    => {
+                // Named type, tag belongs to path.
+                self.next -= 1;
+                self.demangle_path(false);
+            }
+        }
+    }
+
+    /// `<fn-sig> ::= [<binder>] [U] [K <abi>] {<type>} E <type>`
+    fn demangle_fn_type(&mut self) {
+        self.demangle_binder();
+
+        if self.eat(b'U') {
+            self.print("unsafe ");
+        }
+
+        if self.eat(b'K') {
+            let mut abi: &[u8] = match self.eat(b'C') {
+                true  => b"C",
+                false => {
+                    let ident = self.parse_ident();
+
+                    if ident.ascii.is_empty() || !ident.punycode.is_empty() {
+                        self.errored = true;
+                        return;
+                    }
+                    ident.ascii
+                }
+            };
+
+            self.print("extern \"");
+
+            // Dashes of ABI name are mangled as underscores.
+            let mut i = 0;
+
+            while i < abi.len() {
+                if abi[i] == b'_' {
+                    self.print_bytes(&abi[..i]);
+                    self.print("-");
+                    abi = &abi[i + 1..];
+                    i = 0;
+                }
+                i += 1;
+            }
+
+            self.print_bytes(abi);
+            self.print("\" ");
+        }
+
+        self.print("fn(");
+
+        let mut i = 0;
+
+        while !self.errored && !self.eat(b'E') {
+            if i > 0 {
+                self.print(", ");
+            }
+
+            self.demangle_type();
+            i += 1;
+        }
+
+        self.print(")");
+
+        // Unit return type is not shown.
+        if !self.eat(b'u') {
+            self.print(" -> ");
+            self.demangle_type();
+        }
+    }
+
+    /// Demangle path of trait, leaving generic arguments open for
+    /// associated type bindings.
+    ///
+    /// # Returns
+    /// - `true` - if generic arguments were opened.
+    fn demangle_path_maybe_open_generics(&mut self) -> bool {
+        if self.errored {
+            return false;
+        }
+
+        let mut open = false;
+
+        if self.enter() {
+            if self.eat(b'B') {
+                let backref = self.parse_integer_62();
+
+                if !self.skipping_printing {
+                    let old_next = self.next;
+                    self.next = usize::try_from(backref).unwrap_or(usize::MAX);
+                    open = self.demangle_path_maybe_open_generics();
+                    self.next = old_next;
+                }
+            } else if self.eat(b'I') {
+                self.demangle_path(false);
+                self.print("<");
+                open = true;
+                self.demangle_generic_args_open();
+            } else {
+                self.demangle_path(false);
+            }
+        }
+
+        self.leave();
+        open
+    }
+
+    /// Generic arguments up to terminating `E`, closing bracket is not
+    /// printed.
+    fn demangle_generic_args_open(&mut self) {
+        let mut i = 0;
+
+        while !self.errored && !self.eat(b'E') {
+            if i > 0 {
+                self.print(", ");
+            }
+
+            self.demangle_generic_arg();
+            i += 1;
+        }
+    }
+
+    /// `<dyn-trait> ::= <path> {<dyn-trait-assoc-binding>}`
+    fn demangle_dyn_trait(&mut self) {
+        let mut open = self.demangle_path_maybe_open_generics();
+
+        while self.eat(b'p') {
+            match open {
+                true  => self.print(", "),
+                false => self.print("<"),
+            }
+
+            open = true;
+
+            let name = self.parse_ident();
+            self.print_ident(name);
+            self.print(" = ");
+            self.demangle_type();
+        }
+
+        if open {
+            self.print(">");
+        }
+    }
+
+    /// `<const>`
+    fn demangle_const(&mut self) {
+        if self.errored {
+            return;
+        }
+
+        if self.enter() {
+            self.demangle_const_inner();
+        }
+        self.leave();
+    }
+
+    fn demangle_const_inner(&mut self) {
+        if self.eat(b'B') {
+            self.backref(|d| d.demangle_const());
+            return;
+        }
+
+        match self.next_char() {
+            // Placeholder.
+            b'p' => self.print("_"),
+            b'h' | b't' | b'm' | b'y' | b'o' | b'j' => self.demangle_const_uint(),
+            b'a' | b's' | b'l' | b'x' | b'n' | b'i' => {
+                if self.eat(b'n') {
+                    self.print("-");
+                }
+                self.demangle_const_uint();
+            }
+            b'b' => match self.parse_hex_nibbles() {
+                (0, 1) => self.print("false"),
+                (1, 1) => self.print("true"),
+                _      => self.errored = true,
+            },
+            b'c' => {
+                let (value, len) = self.parse_hex_nibbles();
+
+                if len == 0 || len > 8 {
+                    self.errored = true;
+                    return;
+                }
+
+                self.print_quoted_char(value);
+            }
+            _ => self.errored = true,
+        }
+    }
+
+    fn demangle_const_uint(&mut self) {
+        if self.errored {
+            return;
+        }
+
+        let (value, len) = self.parse_hex_nibbles();
+
+        // Values not fitting 64 bits are printed verbatim.
+        if len > 16 {
+            self.print("0x");
+
+            let start = self.next - len;
+            self.print_bytes(&self.sym[start..self.next]);
+        } else {
+            self.print(&value.to_string());
+        }
+    }
+
+    fn print_quoted_char(&mut self, value: u64) {
+        let Some(c) = u32::try_from(value).ok().and_then(char::from_u32) else {
+            self.errored = true;
+            return;
+        };
+
+        let escaped = match c {
+            '\0'               => "\\0".to_string(),
+            '\t'               => "\\t".to_string(),
+            '\r'               => "\\r".to_string(),
+            '\n'               => "\\n".to_string(),
+            '\\'               => "\\\\".to_string(),
+            '\''               => "\\'".to_string(),
+            ' '..='~'          => c.to_string(),
+            _                  => format!("\\u{{{:x}}}", value),
+        };
+
+        self.print(&format!("'{}'", escaped));
+    }
+}
+
+/// Get name of basic type by its tag.
+fn basic_type(tag: u8) -> Option<&'static str> {
+    match tag {
+        b'b' => Some("bool"),
+        b'c' => Some("char"),
+        b'e' => Some("str"),
+        b'u' => Some("()"),
+        b'a' => Some("i8"),
+        b's' => Some("i16"),
+        b'l' => Some("i32"),
+        b'x' => Some("i64"),
+        b'n' => Some("i128"),
+        b'i' => Some("isize"),
+        b'h' => Some("u8"),
+        b't' => Some("u16"),
+        b'm' => Some("u32"),
+        b'y' => Some("u64"),
+        b'o' => Some("u128"),
+        b'j' => Some("usize"),
+        b'f' => Some("f32"),
+        b'd' => Some("f64"),
+        b'z' => Some("!"),
+        b'p' => Some("_"),
+        b'v' => Some("..."),
+        _    => None,
+    }
+}
+
+/// Check whether identifier is legacy hash `h<16 hex digits>`.
+fn is_legacy_hash(ident: &[u8]) -> bool {
+    if ident.len() != 17 || ident[0] != b'h' {
+        return false;
+    }
+
+    let mut seen: u16 = 0;
+
+    for &c in &ident[1..] {
+        let nibble = match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _           => return false,
+        };
+
+        seen |= 1 << nibble;
+    }
+
+    // Real hashes have enough distinct digits.
+    seen.count_ones() >= 5
+}
+
+/// Decode legacy escape sequence, e.g. `$LT$` or `$u20$`.
+///
+/// # Returns
+/// - Decoded character and length of sequence.
+fn decode_legacy_escape(e: &[u8]) -> Option<(char, usize)> {
+    if e.len() < 3 || e[0] != b'$' {
+        return None;
+    }
+
+    let e = &e[1..];
+
+    let (c, len) = if e[0] == b'C' {
+        (',', 1)
+    } else if e.len() > 2 {
+        match &e[..2] {
+            b"SP" => ('@', 2),
+            b"BP" => ('*', 2),
+            b"RF" => ('&', 2),
+            b"LT" => ('<', 2),
+            b"GT" => ('>', 2),
+            b"LP" => ('(', 2),
+            b"RP" => (')', 2),
+            [b'u', _] if e.len() > 3 => {
+                let hi = hex_nibble(e[1])?;
+                let lo = hex_nibble(e[2])?;
+
+                // Only printable ASCII characters are allowed.
+                if hi > 7 {
+                    return None;
+                }
+
+                let c = (hi << 4) | lo;
+
+                if c < 0x20 {
+                    return None;
+                }
+                (c as char, 3)
+            }
+            _ => return None,
+        }
+    } else {
+        return None;
+    };
+
+    if e.len() <= len || e[len] != b'$' {
+        return None;
+    }
+
+    Some((c, len + 2))
+}
+
+fn hex_nibble(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        _           => None,
+    }
+}
+
+/// Decode punycode identifier (RFC 3492).
+fn decode_punycode(ascii: &[u8], mut punycode: &[u8]) -> Option<String> {
+    const BASE: usize  = 36;
+    const T_MIN: usize = 1;
+    const T_MAX: usize = 26;
+    const SKEW: usize  = 38;
+
+    let mut out: Vec<char> = ascii.iter().map(|&c| c as char).collect();
+    let mut damp = 700;
+    let mut bias = 72;
+    let mut i: usize = 0;
+    let mut n: usize = 0x80;
+
+    while !punycode.is_empty() {
+        let mut delta: usize = 0;
+        let mut w: usize     = 1;
+        let mut k            = 0;
+
+        loop {
+            k += BASE;
+
+            let t = k.saturating_sub(bias).clamp(T_MIN, T_MAX);
+            let (&c, rest) = punycode.split_first()?;
+            punycode = rest;
+
+            let d = match c {
+                b'a'..=b'z' => (c - b'a') as usize,
+                b'0'..=b'9' => (c - b'0') as usize + 26,
+                _           => return None,
+            };
+
+            delta = delta.checked_add(d.checked_mul(w)?)?;
+            w     = w.checked_mul(BASE - t)?;
+
+            if d < t {
+                break;
+            }
+        }
+
+        let len = out.len() + 1;
+        i  = i.checked_add(delta)?;
+        n  = n.checked_add(i / len)?;
+        i %= len;
+
+        out.insert(i, char::from_u32(u32::try_from(n).ok()?)?);
+        i += 1;
+
+        if punycode.is_empty() {
+            break;
+        }
+
+        // Bias adaptation.
+        delta /= damp;
+        damp   = 2;
+        delta += delta / len;
+        k      = 0;
+
+        while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+            delta /= BASE - T_MIN;
+            k     += BASE;
+        }
+
+        bias = k + ((BASE - T_MIN + 1) * delta) / (delta + SKEW);
+    }
+
+    Some(out.into_iter().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Check that each mangled name demangles to the expected one.
+    fn check(vectors: &[(&str, &str)]) {
+        for (mangled, expected) in vectors {
+            assert_eq!(demangle(mangled).as_deref(), Some(*expected), "{}", mangled);
+        }
+    }
+
+    #[test]
+    fn legacy() {
+        check(&[
+            ("_ZN4core3ptr13drop_in_place17h0123456789abcdefE", "core::ptr::drop_in_place"),
+            (
+                "_ZN4core3fmt3num52_$LT$impl$u20$core..fmt..Debug$u20$for$u20$usize$GT$3fmt17h0123456789abcdefE",
+                "core::fmt::num::<impl core::fmt::Debug for usize>::fmt",
+            ),
+            (
+                "_ZN3std2rt10lang_start28_$u7b$$u7b$closure$u7d$$u7d$17h0123456789abcdefE",
+                "std::rt::lang_start::{{closure}}",
+            ),
+            ("_ZN5alloc7raw_vec19RawVec$LT$T$C$A$GT$7reserve17h0123456789abcdefE", "alloc::raw_vec::RawVec<T,A>::reserve"),
+        ]);
+    }
+
+    #[test]
+    fn legacy_without_hash_is_not_rust() {
+        assert_eq!(demangle("_ZN3foo3barE"), None);
+        assert_eq!(demangle("_ZN4core3ptr13drop_in_place17hxyzE"), None);
+    }
+
+    #[test]
+    fn v0_paths() {
+        check(&[
+            ("_RNvCs1234_7mycrate3foo",     "mycrate::foo"),
+            ("_RNvNtCs1234_7mycrate3foo3bar", "mycrate::foo::bar"),
+            ("_RNCNvCs1234_7mycrate4main0B3_", "mycrate::main::{closure#0}"),
+            ("_RNvCs1234_7mycrateu10mnchen_3ya", "mycrate::münchen"),
+            (
+                "_RNvMsr_NtCs3ssYzQotkvD_3std4pathNtB5_7PathBuf3newCs15kBYyAo9fc_7mycrate",
+                "<std::path::PathBuf>::new",
+            ),
+            (
+                "_RNvXs_Cs1234_7mycrateNtB4_3FooNtNtCs5678_4core3fmt7Display3fmt",
+                "<mycrate::Foo as core::fmt::Display>::fmt",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn v0_back_references() {
+        check(&[
+            ("_RINvCs1234_7mycrate3fooNtB2_3BarE", "mycrate::foo::<mycrate::Bar>"),
+            ("_RINvCs1234_7mycrate3fooReEB2_",     "mycrate::foo::<&str>"),
+            ("_RNvXCs1234_7mycrateINtB2_3FooNtB2_3BarEB2_3foo", "<mycrate::Foo<mycrate::Bar> as mycrate>::foo"),
+            (
+                "_RINbNbCskIICzLVDPPb_5alloc5alloc8box_freeDINbNiB4_5boxed5FnBoxuEp6OutputuEL_ECs1iopQbuBiw2_3std",
+                "alloc::alloc::box_free::<dyn alloc::boxed::FnBox<(), Output = ()>>",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn v0_generic_arguments() {
+        check(&[
+            ("_RINvCs1234_7mycrate3fooTlmEE",   "mycrate::foo::<(i32, u32)>"),
+            ("_RINvCs1234_7mycrate3fooAhj4_E",  "mycrate::foo::<[u8; 4]>"),
+            ("_RINvCs1234_7mycrate3fooSbE",     "mycrate::foo::<[bool]>"),
+            ("_RINvCs1234_7mycrate3fooPhE",     "mycrate::foo::<*const u8>"),
+            ("_RINvCs1234_7mycrate3fooQOhE",    "mycrate::foo::<&mut *mut u8>"),
+            ("_RINvCs1234_7mycrate3fooFUKCEuE", "mycrate::foo::<unsafe extern \"C\" fn()>"),
+            ("_RINvCs1234_7mycrate3fooKj7b_E",  "mycrate::foo::<123>"),
+            ("_RINvCs1234_7mycrate3fooKan7b_E", "mycrate::foo::<-123>"),
+            ("_RINvCs1234_7mycrate3fooKb1_E",   "mycrate::foo::<true>"),
+            ("_RINvCs1234_7mycrate3fooKc61_E",  "mycrate::foo::<'a'>"),
+        ]);
+    }
+
+    #[test]
+    fn malformed() {
+        let names = [
+            "", "main", "_R", "_RNv", "_RNvCs1234_7mycrate", "_RINvCs1234_7mycrate3fooB9999_E",
+            "_RNvCs1234_7mycrate7uu11e4zcu",
+        ];
+
+        for name in names {
+            assert_eq!(demangle(name), None, "{}", name);
+        }
+    }
+
+    #[test]
+    fn truncated_and_corrupted_do_not_panic() {
+        let names = [
+            "_RINbNbCskIICzLVDPPb_5alloc5alloc8box_freeDINbNiB4_5boxed5FnBoxuEp6OutputuEL_ECs1iopQbuBiw2_3std",
+            "_RNvXCs1234_7mycrateINtB2_3FooNtB2_3BarEB2_3foo",
+            "_RINvCs1234_7mycrate3fooKan7b_E",
+            "_RNvCs1234_7mycrateu10mnchen_3ya",
+            "_ZN4core3fmt3num52_$LT$impl$u20$core..fmt..Debug$u20$for$u20$usize$GT$3fmt17h0123456789abcdefE",
+        ];
+
+        for name in names {
+            for end in 0..name.len() {
+                let _ = demangle(&name[..end]);
+            }
+
+            for i in 0..name.len() {
+                for b in [b'B', b'_', b'E', b'0', b'9', b'I', b'N', b'u', b'$'] {
+                    let mut bytes = name.as_bytes().to_vec();
+                    bytes[i] = b;
+                    let _ = demangle(core::str::from_utf8(&bytes).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn recursion_limit() {
+        let name = format!("_RINvCs1234_7mycrate3foo{}hE", "R".repeat(MAX_RECURSION * 2));
+        assert_eq!(demangle(&name), None);
+    }
+}
@@ -0,0 +1,306 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Android packed relocations module.
+//!
+//! Sections of type `SHT_ANDROID_REL` and `SHT_ANDROID_RELA` start with the
+//! `APS2` magic followed by a stream of SLEB128 numbers: the relocation count,
+//! the initial `r_offset` and a sequence of relocation groups. Each group
+//! starts with its size and flags, followed by the values shared by the whole
+//! group, followed by the values of each relocation that are not shared.
+
+use alloc::vec::Vec;
+
+use crate::elf::file::{ElfError, Result};
+use crate::elf::reloc::{elf32_r_sym, elf32_r_type, elf64_r_info, Elf64_Rela};
+
+/// Packed relocations magic number.
+pub const APS2_MAGIC: [u8; 4] = *b"APS2";
+
+// Android packed relocation group flags enumeration.
+
+/// All relocations of the group have the same `r_info`, which is stored once
+/// in the group header.
+pub const RELOCATION_GROUPED_BY_INFO_FLAG: u64 = 1;
+/// All relocations of the group have the same `r_offset` delta, which is
+/// stored once in the group header.
+pub const RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG: u64 = 2;
+/// All relocations of the group have the same `r_addend` delta, which is
+/// stored once in the group header.
+pub const RELOCATION_GROUPED_BY_ADDEND_FLAG: u64 = 4;
+/// Relocations of the group have addends. Otherwise the addend is zero.
+pub const RELOCATION_GROUP_HAS_ADDEND_FLAG: u64 = 8;
+
+/// Maximum number of relocation entries allocated before decoding.
+const MAX_PREALLOC: usize = 0x10000;
+
+/// Maximum number of relocation entries in a packed section. Grouped
+/// relocations take no bytes of the stream, so the count is not bound by the
+/// section size; this is far more than any real object holds.
+pub const MAX_PACKED_RELOCS: usize = 0x400000;
+
+/// SLEB128 numbers reader.
+struct Sleb128<'a> {
+    /// Encoded bytes.
+    data: &'a [u8],
+    /// Offset of the next number.
+    pos: usize,
+}
+
+impl Sleb128<'_> {
+    /// Read next number.
+    ///
+    /// # Returns
+    /// - Decoded number - in case of success.
+    /// - `ElfError::Truncated` - if stream ends in the middle of the number.
+    fn next(&mut self) -> Result<i64> {
+        let mut value = 0i64;
+        let mut shift = 0u32;
+
+        loop {
+            let byte = *self.data.get(self.pos).ok_or(ElfError::Truncated)?;
+            self.pos += 1;
+
+            if shift < 64 {
+                value |= ((byte & 0x7f) as i64) << shift;
+            }
+            shift += 7;
+
+            if byte & 0x80 == 0 {
+                if shift < 64 && byte & 0x40 != 0 {
+                    value |= -1i64 << shift;
+                }
+                return Ok(value);
+            }
+        }
+    }
+}
+
+/// Decode Android packed relocations.
+///
+/// # Parameters
+/// - `data`       - given section contents.
+/// - `is_64`      - given file class flag.
+/// - `has_addend` - given flag whether section is `SHT_ANDROID_RELA`.
+///
+/// # Returns
+/// - Relocation entries - in case of success.
+/// - `ElfError` - otherwise.
+pub fn decode_packed_relocs(data: &[u8], is_64: bool, has_addend: bool) -> Result<Vec<Elf64_Rela>> {
+    if !data.starts_with(&APS2_MAGIC) {
+        return Err(ElfError::Malformed("Packed relocation section has no APS2 magic"));
+    }
+
+    let mut stream = Sleb128 { data, pos: APS2_MAGIC.len() };
+    let count      = stream.next()?;
+    let mut offset = stream.next()? as u64;
+
+    // Values wrap around the address size of the file class.
+    let mask = if is_64 { u64::MAX } else { u32::MAX as u64 };
+
+    if count < 0 || count as u64 > MAX_PACKED_RELOCS as u64 {
+        return Err(ElfError::Malformed("Packed relocation count is invalid"));
+    }
+
+    // Only a part of the claimed count is allocated up front, the stream may
+    // end before all of it is decoded.
+    let mut relocs = Vec::with_capacity((count as usize).min(MAX_PREALLOC));
+    let mut info   = 0u64;
+    let mut addend = 0i64;
+
+    while relocs.len() < count as usize {
+        let group_size = stream.next()?;
+        let flags      = stream.next()? as u64;
+
+        if group_size <= 0 || group_size as usize > count as usize - relocs.len() {
+            return Err(ElfError::Malformed("Packed relocation group size is invalid"));
+        }
+
+        let by_info   = flags & RELOCATION_GROUPED_BY_INFO_FLAG != 0;
+        let by_offset = flags & RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG != 0;
+        let by_addend = flags & RELOCATION_GROUPED_BY_ADDEND_FLAG != 0;
+        let group_has_addend = flags & RELOCATION_GROUP_HAS_ADDEND_FLAG != 0;
+
+        if group_has_addend && !has_addend {
+            return Err(ElfError::Malformed("Packed REL relocation group has addends"));
+        }
+
+        let offset_delta = if by_offset { stream.next()? as u64 } else { 0 };
+
+        if by_info {
+            info = stream.next()? as u64;
+        }
+
+        if !group_has_addend {
+            addend = 0;
+        } else if by_addend {
+            addend = addend.wrapping_add(stream.next()?);
+        }
+
+        for _ in 0..group_size {
+            offset = match by_offset {
+                true  => offset.wrapping_add(offset_delta),
+                false => offset.wrapping_add(stream.next()? as u64),
+            } & mask;
+
+            if !by_info {
+                info = stream.next()? as u64;
+            }
+
+            if group_has_addend && !by_addend {
+                addend = addend.wrapping_add(stream.next()?);
+            }
+
+            // Entries of 32-bit files hold info in the Elf32_Rel layout.
+            let r_info = match is_64 {
+                true  => info,
+                false => elf64_r_info(elf32_r_sym(info as u32), elf32_r_type(info as u32) as u32),
+            };
+
+            let r_addend = match is_64 {
+                true  => addend,
+                false => addend as i32 as i64,
+            };
+
+            relocs.push(Elf64_Rela { r_offset: offset, r_info, r_addend });
+        }
+    }
+
+    Ok(relocs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encode numbers as packed relocation section.
+    fn pack(numbers: &[i64]) -> Vec<u8> {
+        let mut data = APS2_MAGIC.to_vec();
+
+        for &number in numbers {
+            let mut value = number;
+
+            loop {
+                let byte = (value & 0x7f) as u8;
+                value >>= 7;
+
+                if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+                    data.push(byte);
+                    break;
+                }
+                data.push(byte | 0x80);
+            }
+        }
+
+        data
+    }
+
+    /// Group flags as stream numbers.
+    const BY_INFO: i64   = RELOCATION_GROUPED_BY_INFO_FLAG as i64;
+    const BY_OFFSET: i64 = RELOCATION_GROUPED_BY_OFFSET_DELTA_FLAG as i64;
+    const BY_ADDEND: i64 = RELOCATION_GROUPED_BY_ADDEND_FLAG as i64;
+    const ADDEND: i64    = RELOCATION_GROUP_HAS_ADDEND_FLAG as i64;
+
+    /// Decode packed relocations into `(r_offset, r_info, r_addend)` tuples.
+    fn decode(data: &[u8], is_64: bool, has_addend: bool) -> Result<Vec<(u64, u64, i64)>> {
+        let relocs = decode_packed_relocs(data, is_64, has_addend)?;
+        Ok(relocs.iter().map(|r| (r.r_offset, r.r_info, r.r_addend)).collect())
+    }
+
+    #[test]
+    fn grouped_by_info_and_offset() {
+        let info = elf64_r_info(0, 8) as i64;
+        let data = pack(&[3, 0x1000, 3, BY_INFO | BY_OFFSET, 8, info]);
+
+        assert_eq!(decode(&data, true, false), Ok(vec![
+            (0x1008, info as u64, 0),
+            (0x1010, info as u64, 0),
+            (0x1018, info as u64, 0),
+        ]));
+    }
+
+    #[test]
+    fn ungrouped_with_addends() {
+        let info = elf64_r_info(5, 1) as i64;
+        let data = pack(&[2, 0, 2, ADDEND, 0x10, info, 4, 0x20, info, -8]);
+
+        assert_eq!(decode(&data, true, true), Ok(vec![
+            (0x10, info as u64, 4),
+            (0x30, info as u64, -4),
+        ]));
+    }
+
+    #[test]
+    fn grouped_addend_and_multiple_groups() {
+        let data = pack(&[
+            3, 0x100,
+            2, BY_INFO | BY_OFFSET | BY_ADDEND | ADDEND, 4, 0x208, 16,
+            1, BY_INFO, 0x301,
+            0x40,
+        ]);
+
+        assert_eq!(decode(&data, false, true), Ok(vec![
+            (0x104, elf64_r_info(2, 8), 16),
+            (0x108, elf64_r_info(2, 8), 16),
+            (0x148, elf64_r_info(3, 1), 0),
+        ]));
+    }
+
+    #[test]
+    fn offsets_wrap_around_32_bits() {
+        let data = pack(&[1, 0xffff_fffc, 1, BY_INFO, 0x08, 8]);
+
+        assert_eq!(decode(&data, false, false), Ok(vec![(4, elf64_r_info(0, 8), 0)]));
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(decode(&pack(&[0, 0]), true, true), Ok(vec![]));
+    }
+
+    #[test]
+    fn bad_magic() {
+        assert!(decode(b"APS1\x00\x00", true, false).is_err());
+    }
+
+    #[test]
+    fn invalid_count() {
+        assert!(decode(&pack(&[-1, 0]), true, false).is_err());
+        assert!(decode(&pack(&[MAX_PACKED_RELOCS as i64 + 1, 0]), true, false).is_err());
+        assert!(decode(&pack(&[1 << 40, 0, 1, BY_INFO | BY_OFFSET, 8, 8]), true, false).is_err());
+    }
+
+    #[test]
+    fn invalid_group_size() {
+        assert!(decode(&pack(&[2, 0, 3, BY_INFO | BY_OFFSET, 8, 8]), true, false).is_err());
+        assert!(decode(&pack(&[2, 0, 0, BY_INFO | BY_OFFSET, 8, 8]), true, false).is_err());
+        assert!(decode(&pack(&[2, 0, -1, BY_INFO | BY_OFFSET, 8, 8]), true, false).is_err());
+    }
+
+    #[test]
+    fn addends_in_rel_section() {
+        assert!(decode(&pack(&[1, 0, 1, ADDEND | BY_INFO, 8, 8, 4]), true, false).is_err());
+    }
+
+    #[test]
+    fn truncated() {
+        let data = pack(&[2, 0, 2, BY_INFO, 8, 8, 8]);
+
+        for len in 0..data.len() {
+            assert!(decode(&data[..len], true, false).is_err());
+        }
+    }
+}
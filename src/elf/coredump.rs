@@ -0,0 +1,541 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Core file notes module.
+//!
+//! Core files describe the crashed process with notes of the `PT_NOTE`
+//! segment. Most of them are Linux kernel structures, whose layout depends on
+//! the size of `long` of the process, which is the address size of the file
+//! class.
+
+use alloc::vec::Vec;
+
+use crate::elf::elfhdr::{EM_386, EM_AARCH64, EM_X86_64};
+use crate::elf::file::{cstr, ElfError, Result};
+
+// Core note type enumeration.
+
+/// The descriptor holds `prstatus` structure: signal, identifiers and general
+/// purpose registers of a thread.
+pub const NT_PRSTATUS: u32 = 1;
+/// The descriptor holds floating point registers.
+pub const NT_FPREGSET: u32 = 2;
+/// The descriptor holds `prpsinfo` structure: process state and command line.
+pub const NT_PRPSINFO: u32 = 3;
+/// The descriptor holds task structure.
+pub const NT_TASKSTRUCT: u32 = 4;
+/// The descriptor holds auxiliary vector.
+pub const NT_AUXV: u32 = 6;
+/// The descriptor holds `pstatus` structure.
+pub const NT_PSTATUS: u32 = 10;
+/// The descriptor holds floating point registers.
+pub const NT_FPREGS: u32 = 12;
+/// The descriptor holds `psinfo` structure.
+pub const NT_PSINFO: u32 = 13;
+/// The descriptor holds `lwpstatus_t` structure.
+pub const NT_LWPSTATUS: u32 = 16;
+/// The descriptor holds `lwpsinfo_t` structure.
+pub const NT_LWPSINFO: u32 = 17;
+/// The descriptor holds `win32_pstatus` structure.
+pub const NT_WIN32PSTATUS: u32 = 18;
+/// The descriptor holds x86 TLS information.
+pub const NT_386_TLS: u32 = 0x200;
+/// The descriptor holds x86 I/O permissions.
+pub const NT_386_IOPERM: u32 = 0x201;
+/// The descriptor holds x86 XSAVE extended state.
+pub const NT_X86_XSTATE: u32 = 0x202;
+/// The descriptor holds x86 CET state.
+pub const NT_X86_CET: u32 = 0x203;
+/// The descriptor holds ARM VFP registers.
+pub const NT_ARM_VFP: u32 = 0x400;
+/// The descriptor holds AArch64 TLS registers.
+pub const NT_ARM_TLS: u32 = 0x401;
+/// The descriptor holds AArch64 hardware breakpoint registers.
+pub const NT_ARM_HW_BREAK: u32 = 0x402;
+/// The descriptor holds AArch64 hardware watchpoint registers.
+pub const NT_ARM_HW_WATCH: u32 = 0x403;
+/// The descriptor holds AArch64 system call number.
+pub const NT_ARM_SYSTEM_CALL: u32 = 0x404;
+/// The descriptor holds AArch64 SVE registers.
+pub const NT_ARM_SVE: u32 = 0x405;
+/// The descriptor holds AArch64 pointer authentication code masks.
+pub const NT_ARM_PAC_MASK: u32 = 0x406;
+/// The descriptor holds AArch64 pointer authentication address keys.
+pub const NT_ARM_PACA_KEYS: u32 = 0x407;
+/// The descriptor holds AArch64 pointer authentication generic key.
+pub const NT_ARM_PACG_KEYS: u32 = 0x408;
+/// The descriptor holds AArch64 tagged address control.
+pub const NT_ARM_TAGGED_ADDR_CTRL: u32 = 0x409;
+/// The descriptor holds AArch64 pointer authentication enabled keys.
+pub const NT_ARM_PAC_ENABLED_KEYS: u32 = 0x40a;
+/// The descriptor holds GDB XML target description.
+pub const NT_GDB_TDESC: u32 = 0xff0;
+/// The descriptor holds `user_xfpregs` structure.
+pub const NT_PRXFPREG: u32 = 0x46e62b7f;
+/// The descriptor holds `siginfo_t` structure of the fatal signal.
+pub const NT_SIGINFO: u32 = 0x53494749;
+/// The descriptor holds table of mapped files.
+pub const NT_FILE: u32 = 0x46494c45;
+
+// Auxiliary vector entry type enumeration.
+
+/// End of vector.
+pub const AT_NULL: u64 = 0;
+/// Entry should be ignored.
+pub const AT_IGNORE: u64 = 1;
+/// File descriptor of program.
+pub const AT_EXECFD: u64 = 2;
+/// Program headers of program.
+pub const AT_PHDR: u64 = 3;
+/// Size of program header entry.
+pub const AT_PHENT: u64 = 4;
+/// Number of program headers.
+pub const AT_PHNUM: u64 = 5;
+/// System page size.
+pub const AT_PAGESZ: u64 = 6;
+/// Base address of interpreter.
+pub const AT_BASE: u64 = 7;
+/// Flags.
+pub const AT_FLAGS: u64 = 8;
+/// Entry point of program.
+pub const AT_ENTRY: u64 = 9;
+/// Program is not ELF.
+pub const AT_NOTELF: u64 = 10;
+/// Real user ID.
+pub const AT_UID: u64 = 11;
+/// Effective user ID.
+pub const AT_EUID: u64 = 12;
+/// Real group ID.
+pub const AT_GID: u64 = 13;
+/// Effective group ID.
+pub const AT_EGID: u64 = 14;
+/// String identifying platform.
+pub const AT_PLATFORM: u64 = 15;
+/// Machine-dependent hardware capabilities.
+pub const AT_HWCAP: u64 = 16;
+/// Frequency of `times()`.
+pub const AT_CLKTCK: u64 = 17;
+/// Secure mode boolean.
+pub const AT_SECURE: u64 = 23;
+/// String identifying real platform.
+pub const AT_BASE_PLATFORM: u64 = 24;
+/// Address of 16 random bytes.
+pub const AT_RANDOM: u64 = 25;
+/// Extension of `AT_HWCAP`.
+pub const AT_HWCAP2: u64 = 26;
+/// Size of `rseq` feature area.
+pub const AT_RSEQ_FEATURE_SIZE: u64 = 27;
+/// Alignment of `rseq` area.
+pub const AT_RSEQ_ALIGN: u64 = 28;
+/// Extension of `AT_HWCAP`.
+pub const AT_HWCAP3: u64 = 29;
+/// Extension of `AT_HWCAP`.
+pub const AT_HWCAP4: u64 = 30;
+/// File name of program.
+pub const AT_EXECFN: u64 = 31;
+/// Entry point of system call page.
+pub const AT_SYSINFO: u64 = 32;
+/// Address of vDSO.
+pub const AT_SYSINFO_EHDR: u64 = 33;
+/// Minimal stack size for signal delivery.
+pub const AT_MINSIGSTKSZ: u64 = 51;
+
+// Signal codes of signals sent by processes.
+
+/// Sent by `kill()`.
+pub const SI_USER: i32 = 0;
+/// Sent by `sigqueue()`.
+pub const SI_QUEUE: i32 = -1;
+/// Sent by `tkill()`.
+pub const SI_TKILL: i32 = -6;
+
+/// x86-64 general purpose registers in `prstatus` order.
+const X86_64_REGS: [&str; 27] = [
+    "r15", "r14", "r13", "r12", "rbp", "rbx", "r11", "r10", "r9", "r8", "rax", "rcx", "rdx",
+    "rsi", "rdi", "orig_rax", "rip", "cs", "eflags", "rsp", "ss", "fs_base", "gs_base", "ds",
+    "es", "fs", "gs",
+];
+
+/// i386 general purpose registers in `prstatus` order.
+const I386_REGS: [&str; 17] = [
+    "ebx", "ecx", "edx", "esi", "edi", "ebp", "eax", "ds", "es", "fs", "gs", "orig_eax", "eip",
+    "cs", "eflags", "esp", "ss",
+];
+
+/// AArch64 general purpose registers in `prstatus` order.
+const AARCH64_REGS: [&str; 34] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "sp", "pc", "pstate",
+];
+
+/// Get names of registers saved in `prstatus` structure.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+///
+/// # Returns
+/// - Register names - if machine is supported.
+/// - `None` - otherwise.
+pub fn register_names(machine: u16) -> Option<&'static [&'static str]> {
+    match machine {
+        EM_X86_64  => Some(&X86_64_REGS),
+        EM_386     => Some(&I386_REGS),
+        EM_AARCH64 => Some(&AARCH64_REGS),
+        _          => None,
+    }
+}
+
+/// Descriptor reader of structures whose layout depends on the size of `long`.
+struct Reader<'a> {
+    /// Note descriptor.
+    desc: &'a [u8],
+    /// Size of `long`.
+    wordsize: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Read little-endian number of given size.
+    fn int(&self, offset: usize, size: usize) -> Result<u64> {
+        let bytes = self.desc.get(offset..offset + size).ok_or(ElfError::Truncated)?;
+        Ok(bytes.iter().rev().fold(0, |value, &b| (value << 8) | b as u64))
+    }
+
+    /// Read `int`.
+    fn i32(&self, offset: usize) -> Result<i32> {
+        Ok(self.int(offset, 4)? as i32)
+    }
+
+    /// Read `long`.
+    fn word(&self, offset: usize) -> Result<u64> {
+        self.int(offset, self.wordsize)
+    }
+
+    /// Read null-padded character array.
+    fn chars(&self, offset: usize, size: usize) -> Result<&'a [u8]> {
+        let bytes = self.desc.get(offset..offset + size).ok_or(ElfError::Truncated)?;
+        let len   = bytes.iter().position(|&b| b == 0).unwrap_or(size);
+        Ok(&bytes[..len])
+    }
+}
+
+/// Time value of `prstatus` structure.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TimeVal {
+    /// Seconds.
+    pub sec: u64,
+    /// Microseconds.
+    pub usec: u64,
+}
+
+/// Thread status from `NT_PRSTATUS` note.
+#[derive(Debug, Default, Clone)]
+pub struct PrStatus {
+    /// Signal number from signal information.
+    pub signo: i32,
+    /// Signal code from signal information.
+    pub code: i32,
+    /// Error number from signal information.
+    pub errno: i32,
+    /// Current signal.
+    pub cursig: u16,
+    /// Set of pending signals.
+    pub sigpend: u64,
+    /// Set of held signals.
+    pub sighold: u64,
+    /// Thread ID.
+    pub pid: i32,
+    /// Parent process ID.
+    pub ppid: i32,
+    /// Process group ID.
+    pub pgrp: i32,
+    /// Session ID.
+    pub sid: i32,
+    /// User time.
+    pub utime: TimeVal,
+    /// System time.
+    pub stime: TimeVal,
+    /// Cumulative user time.
+    pub cutime: TimeVal,
+    /// Cumulative system time.
+    pub cstime: TimeVal,
+    /// General purpose registers.
+    pub regs: Vec<u64>,
+}
+
+impl PrStatus {
+    /// Parse `NT_PRSTATUS` note descriptor.
+    ///
+    /// # Parameters
+    /// - `desc`  - given note descriptor.
+    /// - `is_64` - given file class flag.
+    ///
+    /// # Returns
+    /// - `PrStatus` - in case of success.
+    /// - `ElfError` - if descriptor is too short.
+    pub fn parse(desc: &[u8], is_64: bool) -> Result<PrStatus> {
+        let w = if is_64 { 8 } else { 4 };
+        let r = Reader { desc, wordsize: w };
+
+        let timeval = |offset: usize| -> Result<TimeVal> {
+            Ok(TimeVal { sec: r.word(offset)?, usec: r.word(offset + w)? })
+        };
+
+        // Registers are followed by the `pr_fpvalid` integer padded to `long`.
+        let regs_offset = 32 + 10 * w;
+        let regs_count  = desc.len().saturating_sub(regs_offset + w) / w;
+
+        Ok(PrStatus {
+            signo:   r.i32(0)?,
+            code:    r.i32(4)?,
+            errno:   r.i32(8)?,
+            cursig:  r.int(12, 2)? as u16,
+            sigpend: r.word(16)?,
+            sighold: r.word(16 + w)?,
+            pid:     r.i32(16 + 2 * w)?,
+            ppid:    r.i32(20 + 2 * w)?,
+            pgrp:    r.i32(24 + 2 * w)?,
+            sid:     r.i32(28 + 2 * w)?,
+            utime:   timeval(32 + 2 * w)?,
+            stime:   timeval(32 + 4 * w)?,
+            cutime:  timeval(32 + 6 * w)?,
+            cstime:  timeval(32 + 8 * w)?,
+            regs:    (0..regs_count)
+                .map(|i| r.word(regs_offset + i * w))
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+/// Process information from `NT_PRPSINFO` note.
+#[derive(Debug, Default, Clone)]
+pub struct PrPsInfo<'a> {
+    /// Numeric process state.
+    pub state: u8,
+    /// Character process state, such as `R` or `S`.
+    pub sname: u8,
+    /// Zombie flag.
+    pub zomb: u8,
+    /// Nice value.
+    pub nice: i8,
+    /// Process flags.
+    pub flag: u64,
+    /// User ID.
+    pub uid: u32,
+    /// Group ID.
+    pub gid: u32,
+    /// Process ID.
+    pub pid: i32,
+    /// Parent process ID.
+    pub ppid: i32,
+    /// Process group ID.
+    pub pgrp: i32,
+    /// Session ID.
+    pub sid: i32,
+    /// Executable file name, truncated to 16 characters.
+    pub fname: &'a [u8],
+    /// Initial part of command line, truncated to 80 characters.
+    pub psargs: &'a [u8],
+}
+
+impl<'a> PrPsInfo<'a> {
+    /// Parse `NT_PRPSINFO` note descriptor.
+    ///
+    /// User and group IDs are 16-bit in 32-bit files.
+    ///
+    /// # Parameters
+    /// - `desc`  - given note descriptor.
+    /// - `is_64` - given file class flag.
+    ///
+    /// # Returns
+    /// - `PrPsInfo` - in case of success.
+    /// - `ElfError` - if descriptor is too short.
+    pub fn parse(desc: &'a [u8], is_64: bool) -> Result<PrPsInfo<'a>> {
+        let w   = if is_64 { 8 } else { 4 };
+        let u   = w / 2;
+        let r   = Reader { desc, wordsize: w };
+        let ids = 2 * w + 2 * u;
+
+        Ok(PrPsInfo {
+            state:  r.int(0, 1)? as u8,
+            sname:  r.int(1, 1)? as u8,
+            zomb:   r.int(2, 1)? as u8,
+            nice:   r.int(3, 1)? as i8,
+            flag:   r.word(w)?,
+            uid:    r.int(2 * w, u)? as u32,
+            gid:    r.int(2 * w + u, u)? as u32,
+            pid:    r.i32(ids)?,
+            ppid:   r.i32(ids + 4)?,
+            pgrp:   r.i32(ids + 8)?,
+            sid:    r.i32(ids + 12)?,
+            fname:  r.chars(ids + 16, 16)?,
+            psargs: r.chars(ids + 32, 80)?,
+        })
+    }
+}
+
+/// Signal information from `NT_SIGINFO` note.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SigInfo {
+    /// Signal number.
+    pub signo: i32,
+    /// Error number.
+    pub errno: i32,
+    /// Signal code.
+    pub code: i32,
+    /// Faulting address, meaningful for `SIGILL`, `SIGFPE`, `SIGSEGV` and
+    /// `SIGBUS` sent by the kernel.
+    pub addr: u64,
+    /// Sender process ID, meaningful for signals sent by processes.
+    pub pid: i32,
+    /// Sender user ID, meaningful for signals sent by processes.
+    pub uid: u32,
+}
+
+impl SigInfo {
+    /// Parse `NT_SIGINFO` note descriptor.
+    ///
+    /// # Parameters
+    /// - `desc`  - given note descriptor.
+    /// - `is_64` - given file class flag.
+    ///
+    /// # Returns
+    /// - `SigInfo` - in case of success.
+    /// - `ElfError` - if descriptor is too short.
+    pub fn parse(desc: &[u8], is_64: bool) -> Result<SigInfo> {
+        let w = if is_64 { 8 } else { 4 };
+        let r = Reader { desc, wordsize: w };
+
+        // The union of signal-specific fields is aligned to pointer size.
+        let fields = if is_64 { 16 } else { 12 };
+
+        Ok(SigInfo {
+            signo: r.i32(0)?,
+            errno: r.i32(4)?,
+            code:  r.i32(8)?,
+            addr:  r.word(fields)?,
+            pid:   r.i32(fields)?,
+            uid:   r.i32(fields + 4)? as u32,
+        })
+    }
+
+    /// Check whether signal was sent by a process.
+    pub fn is_user(&self) -> bool {
+        matches!(self.code, SI_USER | SI_QUEUE | SI_TKILL)
+    }
+}
+
+/// Parse `NT_AUXV` note descriptor.
+///
+/// # Parameters
+/// - `desc`  - given note descriptor.
+/// - `is_64` - given file class flag.
+///
+/// # Returns
+/// Auxiliary vector entries type and value up to `AT_NULL` entry.
+pub fn parse_auxv(desc: &[u8], is_64: bool) -> Vec<(u64, u64)> {
+    let w = if is_64 { 8 } else { 4 };
+    let r = Reader { desc, wordsize: w };
+
+    (0..desc.len() / (2 * w))
+        .map_while(|i| {
+            let entry = (r.word(2 * w * i).ok()?, r.word(2 * w * i + w).ok()?);
+            (entry.0 != AT_NULL).then_some(entry)
+        })
+        .collect()
+}
+
+/// File mapping from `NT_FILE` note.
+#[derive(Debug, Clone, Copy)]
+pub struct MappedFile<'a> {
+    /// Start address of mapping.
+    pub start: u64,
+    /// End address of mapping.
+    pub end: u64,
+    /// Offset of mapping in the file, in pages.
+    pub page_offset: u64,
+    /// File path.
+    pub name: &'a str,
+}
+
+/// Table of mapped files from `NT_FILE` note.
+#[derive(Debug, Clone)]
+pub struct FileNote<'a> {
+    /// Size of page used by `page_offset` values.
+    pub page_size: u64,
+    /// Mapped files.
+    pub files: Vec<MappedFile<'a>>,
+}
+
+impl<'a> FileNote<'a> {
+    /// Parse `NT_FILE` note descriptor.
+    ///
+    /// The descriptor holds number of entries, page size, array of entries
+    /// made of start, end and page offset, followed by null-terminated file
+    /// names of each entry.
+    ///
+    /// # Parameters
+    /// - `desc`  - given note descriptor.
+    /// - `is_64` - given file class flag.
+    ///
+    /// # Returns
+    /// - `FileNote` - in case of success.
+    /// - `ElfError` - if descriptor is malformed.
+    pub fn parse(desc: &'a [u8], is_64: bool) -> Result<FileNote<'a>> {
+        let w = if is_64 { 8 } else { 4 };
+        let r = Reader { desc, wordsize: w };
+
+        if desc.len() < 2 * w {
+            return Err(ElfError::Malformed("Malformed note - too short for header"));
+        }
+
+        if desc.last() != Some(&0) {
+            return Err(ElfError::Malformed("Malformed note - does not end with \\0"));
+        }
+
+        let count     = r.word(0)?;
+        let page_size = r.word(w)?;
+
+        let names_offset = count
+            .checked_mul(3 * w as u64)
+            .and_then(|size| size.checked_add(2 * w as u64))
+            .filter(|&offset| offset <= desc.len() as u64)
+            .ok_or(ElfError::Malformed("Malformed note - too short for supplied file count"))?
+            as usize;
+
+        let mut files = Vec::with_capacity(count as usize);
+        let mut name  = names_offset;
+
+        for i in 0..count as usize {
+            if name == desc.len() {
+                return Err(ElfError::Malformed("Malformed note - filenames end too early"));
+            }
+
+            let entry = 2 * w + 3 * w * i;
+            let path  = cstr(desc, name)?;
+            name += path.len() + 1;
+
+            files.push(MappedFile {
+                start:       r.word(entry)?,
+                end:         r.word(entry + w)?,
+                page_offset: r.word(entry + 2 * w)?,
+                name:        path,
+            });
+        }
+
+        Ok(FileNote { page_size, files })
+    }
+}
@@ -16,7 +16,11 @@
 
 //! ELF dynamic section module.
 
-use crate::elf::{Elf32_Addr, Elf32_Sword, Elf32_Word};
+use core::fmt;
+
+use crate::elf::{
+    Elf32_Addr, Elf32_Sword, Elf32_Word, Elf64_Addr, Elf64_Sxword, Elf64_Xword,
+};
 
 /// ELF dynamic section struct.
 ///
@@ -24,14 +28,20 @@ use crate::elf::{Elf32_Addr, Elf32_Sword, Elf32_Word};
 /// will have an element of type PT_DYNAMIC. This ‘‘segment’’ contains the
 /// .dynamic section. A special symbol, _DYNAMIC, labels the section, which
 /// contains an array of the following structures.
+#[derive(Clone, Copy)]
 #[repr(C, packed)]
-struct Elf32_Dyn {
+pub struct Elf32_Dyn {
+    /// Entry type, one of `DT_*` values. It controls the interpretation of
+    /// `d_un`.
     pub d_tag: Elf32_Sword,
+    /// Entry value.
     pub d_un: Dyn_Union,
 }
 
+/// ELF dynamic section entry value union.
+#[derive(Clone, Copy)]
 #[repr(C, packed)]
-union Dyn_Union {
+pub union Dyn_Union {
     /// These Elf32_Word objects represent integer values with various
     /// interpretations.
     pub d_val: Elf32_Word,
@@ -45,6 +55,51 @@ union Dyn_Union {
     pub d_ptr: Elf32_Addr,
 }
 
+/// ELF dynamic section struct for the 64-bit class of object files.
+#[derive(Clone, Copy)]
+#[repr(C, packed)]
+pub struct Elf64_Dyn {
+    /// Entry type, one of `DT_*` values.
+    pub d_tag: Elf64_Sxword,
+    /// Entry value.
+    pub d_un: Dyn64_Union,
+}
+
+/// ELF dynamic section entry value union for the 64-bit class of object
+/// files.
+#[derive(Clone, Copy)]
+#[repr(C, packed)]
+pub union Dyn64_Union {
+    /// Integer value.
+    pub d_val: Elf64_Xword,
+    /// Program virtual address.
+    pub d_ptr: Elf64_Addr,
+}
+
+impl Elf64_Dyn {
+    /// Get entry value.
+    ///
+    /// Both union members are plain integers of the same size, so the value
+    /// is the same whichever member was written.
+    pub fn val(&self) -> Elf64_Xword {
+        unsafe { self.d_un.d_val }
+    }
+
+    /// Get entry tag.
+    pub fn tag(&self) -> DynamicTag {
+        DynamicTag::from(self.d_tag)
+    }
+}
+
+impl From<Elf32_Dyn> for Elf64_Dyn {
+    fn from(dyn32: Elf32_Dyn) -> Self {
+        Elf64_Dyn {
+            d_tag: dyn32.d_tag as Elf64_Sxword,
+            d_un:  Dyn64_Union { d_val: unsafe { dyn32.d_un.d_val } as Elf64_Xword },
+        }
+    }
+}
+
 // ELF dynamic tags types enumeration.
 
 /// An entry with a `DT_NULL` tag marks the end of the `_DYNAMIC` array.
@@ -138,7 +193,432 @@ pub const DT_TEXTREL: u32 = 22;
 /// initialization, if lazy binding is enabled. If this entry is present, the
 /// related entries of types `DT_PLTRELSZ` and `DT_PLTREL` must also be present.
 pub const DT_JMPREL: u32 = 23;
+/// If present in a shared object or executable, this entry instructs the
+/// dynamic linker to process all relocations for the object containing this
+/// entry before transferring control to the program.
+pub const DT_BIND_NOW: u32 = 24;
+/// This element holds the address of the array of pointers to initialization
+/// functions.
+pub const DT_INIT_ARRAY: u32 = 25;
+/// This element holds the address of the array of pointers to termination
+/// functions.
+pub const DT_FINI_ARRAY: u32 = 26;
+/// This element holds the size in bytes of the array of initialization
+/// functions pointed to by the `DT_INIT_ARRAY` entry.
+pub const DT_INIT_ARRAYSZ: u32 = 27;
+/// This element holds the size in bytes of the array of termination functions
+/// pointed to by the `DT_FINI_ARRAY` entry.
+pub const DT_FINI_ARRAYSZ: u32 = 28;
+/// This element holds the string table offset of a null-terminated library
+/// search path string. Unlike `DT_RPATH`, it is searched after the
+/// `LD_LIBRARY_PATH` environment variable.
+pub const DT_RUNPATH: u32 = 29;
+/// This element holds flag values specific to the object being loaded, see
+/// `DF_*` values.
+pub const DT_FLAGS: u32 = 30;
+/// This element holds the address of the array of pointers to
+/// pre-initialization functions. It is processed only in an executable file.
+pub const DT_PREINIT_ARRAY: u32 = 32;
+/// This element holds the size in bytes of the array of pre-initialization
+/// functions pointed to by the `DT_PREINIT_ARRAY` entry.
+pub const DT_PREINIT_ARRAYSZ: u32 = 33;
+/// This element holds the address of the `SHT_SYMTAB_SHNDX` section
+/// associated with the dynamic symbol table.
+pub const DT_SYMTAB_SHNDX: u32 = 34;
+/// This element holds the total size, in bytes, of the `DT_RELR` relocation
+/// table.
+pub const DT_RELRSZ: u32 = 35;
+/// This element holds the address of a packed relative relocation table, in
+/// the `Elf32_Relr` or `Elf64_Relr` format. If this element is present, the
+/// dynamic structure must also have `DT_RELRSZ` and `DT_RELRENT` elements.
+pub const DT_RELR: u32 = 36;
+/// This element holds the size, in bytes, of the `DT_RELR` relocation entry.
+pub const DT_RELRENT: u32 = 37;
+/// Values in this inclusive range are reserved for operating system-specific
+/// semantics.
+pub const DT_LOOS: u32 = 0x6000000d;
+pub const DT_HIOS: u32 = 0x6ffff000;
+
+// GNU and Solaris extensions, values holding integers.
+
+/// GNU flag values, see `DF_GNU_1_*` values.
+pub const DT_GNU_FLAGS_1: u32 = 0x6ffffdf4;
+/// Prelinking timestamp.
+pub const DT_GNU_PRELINKED: u32 = 0x6ffffdf5;
+/// Size of the conflict section.
+pub const DT_GNU_CONFLICTSZ: u32 = 0x6ffffdf6;
+/// Size of the library list.
+pub const DT_GNU_LIBLISTSZ: u32 = 0x6ffffdf7;
+/// Checksum of the object.
+pub const DT_CHECKSUM: u32 = 0x6ffffdf8;
+/// Size of the procedure linkage table padding.
+pub const DT_PLTPADSZ: u32 = 0x6ffffdf9;
+/// Size of the move table entry.
+pub const DT_MOVEENT: u32 = 0x6ffffdfa;
+/// Total size of the move table.
+pub const DT_MOVESZ: u32 = 0x6ffffdfb;
+/// Feature selection flags, see `DTF_1_*` values.
+pub const DT_FEATURE_1: u32 = 0x6ffffdfc;
+/// Flags for the `DT_*` entry following this one, see `DF_P1_*` values.
+pub const DT_POSFLAG_1: u32 = 0x6ffffdfd;
+/// Size of the syminfo table.
+pub const DT_SYMINSZ: u32 = 0x6ffffdfe;
+/// Size of the syminfo table entry.
+pub const DT_SYMINENT: u32 = 0x6ffffdff;
+
+// GNU and Solaris extensions, values holding addresses.
+
+/// Address of the GNU-style symbol hash table.
+pub const DT_GNU_HASH: u32 = 0x6ffffef5;
+/// Address of the TLS descriptor resolver procedure linkage table entry.
+pub const DT_TLSDESC_PLT: u32 = 0x6ffffef6;
+/// Address of the TLS descriptor global offset table entry.
+pub const DT_TLSDESC_GOT: u32 = 0x6ffffef7;
+/// Address of the prelink conflict section.
+pub const DT_GNU_CONFLICT: u32 = 0x6ffffef8;
+/// Address of the prelink library list.
+pub const DT_GNU_LIBLIST: u32 = 0x6ffffef9;
+/// String table offset of the configuration file name.
+pub const DT_CONFIG: u32 = 0x6ffffefa;
+/// String table offset of the dependency audit library name.
+pub const DT_DEPAUDIT: u32 = 0x6ffffefb;
+/// String table offset of the audit library name.
+pub const DT_AUDIT: u32 = 0x6ffffefc;
+/// Address of the procedure linkage table padding.
+pub const DT_PLTPAD: u32 = 0x6ffffefd;
+/// Address of the move table.
+pub const DT_MOVETAB: u32 = 0x6ffffefe;
+/// Address of the syminfo table.
+pub const DT_SYMINFO: u32 = 0x6ffffeff;
+
+// Symbol versioning and relocation count entries.
+
+/// Address of the `.gnu.version` section.
+pub const DT_VERSYM: u32 = 0x6ffffff0;
+/// Number of `R_*_RELATIVE` relocations at the start of the `DT_RELA` table.
+pub const DT_RELACOUNT: u32 = 0x6ffffff9;
+/// Number of `R_*_RELATIVE` relocations at the start of the `DT_REL` table.
+pub const DT_RELCOUNT: u32 = 0x6ffffffa;
+/// State flag values, see `DF_1_*` values.
+pub const DT_FLAGS_1: u32 = 0x6ffffffb;
+/// Address of the version definition table.
+pub const DT_VERDEF: u32 = 0x6ffffffc;
+/// Number of version definitions.
+pub const DT_VERDEFNUM: u32 = 0x6ffffffd;
+/// Address of the table with needed versions.
+pub const DT_VERNEED: u32 = 0x6ffffffe;
+/// Number of needed versions.
+pub const DT_VERNEEDNUM: u32 = 0x6fffffff;
+
 /// Values in this inclusive range are reserved for processor-specific
 /// semantics.
 pub const DT_LOPROC: u32 = 0x70000000;
 pub const DT_HIPROC: u32 = 0x7fffffff;
+
+// Processor-specific entries.
+
+/// AArch64: procedure linkage table uses branch target identification.
+pub const DT_AARCH64_BTI_PLT: u32 = 0x70000001;
+/// AArch64: procedure linkage table uses pointer authentication.
+pub const DT_AARCH64_PAC_PLT: u32 = 0x70000003;
+/// AArch64: object has symbols with variant procedure call standard.
+pub const DT_AARCH64_VARIANT_PCS: u32 = 0x70000005;
+/// RISC-V: object has symbols with variant calling convention.
+pub const DT_RISCV_VARIANT_CC: u32 = 0x70000001;
+/// MIPS: runtime linker interface version.
+pub const DT_MIPS_RLD_VERSION: u32 = 0x70000001;
+/// MIPS: flags.
+pub const DT_MIPS_FLAGS: u32 = 0x70000005;
+/// MIPS: base address of the segment.
+pub const DT_MIPS_BASE_ADDRESS: u32 = 0x70000006;
+/// MIPS: number of local global offset table entries.
+pub const DT_MIPS_LOCAL_GOTNO: u32 = 0x7000000a;
+/// MIPS: number of dynamic symbol table entries.
+pub const DT_MIPS_SYMTABNO: u32 = 0x70000011;
+/// MIPS: index of the first external dynamic symbol not referenced locally.
+pub const DT_MIPS_UNREFEXTNO: u32 = 0x70000012;
+/// MIPS: index of the first dynamic symbol in the global offset table.
+pub const DT_MIPS_GOTSYM: u32 = 0x70000013;
+/// MIPS: address of the runtime linker map.
+pub const DT_MIPS_RLD_MAP: u32 = 0x70000016;
+/// MIPS: offset of the runtime linker map relative to this entry.
+pub const DT_MIPS_RLD_MAP_REL: u32 = 0x70000035;
+/// PowerPC: address of the global offset table.
+pub const DT_PPC_GOT: u32 = 0x70000000;
+/// PowerPC: optimization flags.
+pub const DT_PPC_OPT: u32 = 0x70000001;
+/// PowerPC64: address of the global linkage stubs.
+pub const DT_PPC64_GLINK: u32 = 0x70000000;
+/// PowerPC64: address of the `.opd` section.
+pub const DT_PPC64_OPD: u32 = 0x70000001;
+/// PowerPC64: size of the `.opd` section.
+pub const DT_PPC64_OPDSZ: u32 = 0x70000002;
+/// PowerPC64: optimization flags.
+pub const DT_PPC64_OPT: u32 = 0x70000003;
+
+/// String table offset of the name of the shared object for which this one
+/// acts as an auxiliary filter.
+pub const DT_AUXILIARY: u32 = 0x7ffffffd;
+/// String table offset of the name of an object that is used, Solaris only.
+pub const DT_USED: u32 = 0x7ffffffe;
+/// String table offset of the name of the shared object for which this one
+/// acts as a standard filter.
+pub const DT_FILTER: u32 = 0x7fffffff;
+
+elf_enum! {
+    /// Dynamic entry tag, `d_tag` field of dynamic entry.
+    ///
+    /// Processor-specific tags depend on the machine, so they are always
+    /// `Unknown`.
+    pub enum DynamicTag: i64 {
+        /// End of dynamic array.
+        Null = DT_NULL => "NULL",
+        /// Name of needed library.
+        Needed = DT_NEEDED => "NEEDED",
+        /// Size of PLT relocations.
+        Pltrelsz = DT_PLTRELSZ => "PLTRELSZ",
+        /// Address of PLT or GOT.
+        Pltgot = DT_PLTGOT => "PLTGOT",
+        /// Address of symbol hash table.
+        Hash = DT_HASH => "HASH",
+        /// Address of string table.
+        Strtab = DT_STRTAB => "STRTAB",
+        /// Address of symbol table.
+        Symtab = DT_SYMTAB => "SYMTAB",
+        /// Address of relocations with addends.
+        Rela = DT_RELA => "RELA",
+        /// Size of relocations with addends.
+        Relasz = DT_RELASZ => "RELASZ",
+        /// Size of relocation with addend entry.
+        Relaent = DT_RELAENT => "RELAENT",
+        /// Size of string table.
+        Strsz = DT_STRSZ => "STRSZ",
+        /// Size of symbol table entry.
+        Syment = DT_SYMENT => "SYMENT",
+        /// Address of initialization function.
+        Init = DT_INIT => "INIT",
+        /// Address of termination function.
+        Fini = DT_FINI => "FINI",
+        /// Name of shared object.
+        Soname = DT_SONAME => "SONAME",
+        /// Library search path, deprecated.
+        Rpath = DT_RPATH => "RPATH",
+        /// Symbol resolution starts from the object.
+        Symbolic = DT_SYMBOLIC => "SYMBOLIC",
+        /// Address of relocations.
+        Rel = DT_REL => "REL",
+        /// Size of relocations.
+        Relsz = DT_RELSZ => "RELSZ",
+        /// Size of relocation entry.
+        Relent = DT_RELENT => "RELENT",
+        /// Type of PLT relocations.
+        Pltrel = DT_PLTREL => "PLTREL",
+        /// Used for debugging.
+        Debug = DT_DEBUG => "DEBUG",
+        /// Relocations may modify non-writable segment.
+        Textrel = DT_TEXTREL => "TEXTREL",
+        /// Address of PLT relocations.
+        Jmprel = DT_JMPREL => "JMPREL",
+        /// Process relocations before transferring control.
+        BindNow = DT_BIND_NOW => "BIND_NOW",
+        /// Address of initialization functions array.
+        InitArray = DT_INIT_ARRAY => "INIT_ARRAY",
+        /// Address of termination functions array.
+        FiniArray = DT_FINI_ARRAY => "FINI_ARRAY",
+        /// Size of initialization functions array.
+        InitArraysz = DT_INIT_ARRAYSZ => "INIT_ARRAYSZ",
+        /// Size of termination functions array.
+        FiniArraysz = DT_FINI_ARRAYSZ => "FINI_ARRAYSZ",
+        /// Library search path.
+        Runpath = DT_RUNPATH => "RUNPATH",
+        /// Flags of the object.
+        Flags = DT_FLAGS => "FLAGS",
+        /// Address of pre-initialization functions array.
+        PreinitArray = DT_PREINIT_ARRAY => "PREINIT_ARRAY",
+        /// Size of pre-initialization functions array.
+        PreinitArraysz = DT_PREINIT_ARRAYSZ => "PREINIT_ARRAYSZ",
+        /// Address of extended section indexes of symbol table.
+        SymtabShndx = DT_SYMTAB_SHNDX => "SYMTAB_SHNDX",
+        /// Size of relative relocations.
+        Relrsz = DT_RELRSZ => "RELRSZ",
+        /// Address of relative relocations.
+        Relr = DT_RELR => "RELR",
+        /// Size of relative relocation entry.
+        Relrent = DT_RELRENT => "RELRENT",
+        /// GNU flags.
+        GnuFlags1 = DT_GNU_FLAGS_1 => "GNU_FLAGS_1",
+        /// Prelinking timestamp.
+        GnuPrelinked = DT_GNU_PRELINKED => "GNU_PRELINKED",
+        /// Size of prelink conflicts.
+        GnuConflictsz = DT_GNU_CONFLICTSZ => "GNU_CONFLICTSZ",
+        /// Size of prelink library list.
+        GnuLiblistsz = DT_GNU_LIBLISTSZ => "GNU_LIBLISTSZ",
+        /// Checksum of the object.
+        Checksum = DT_CHECKSUM => "CHECKSUM",
+        /// Size of PLT padding.
+        Pltpadsz = DT_PLTPADSZ => "PLTPADSZ",
+        /// Size of move table entry.
+        Moveent = DT_MOVEENT => "MOVEENT",
+        /// Size of move table.
+        Movesz = DT_MOVESZ => "MOVESZ",
+        /// Feature flags.
+        Feature1 = DT_FEATURE_1 => "FEATURE",
+        /// Flags of the next entry.
+        Posflag1 = DT_POSFLAG_1 => "POSFLAG_1",
+        /// Size of symbol information table.
+        Syminsz = DT_SYMINSZ => "SYMINSZ",
+        /// Size of symbol information entry.
+        Syminent = DT_SYMINENT => "SYMINENT",
+        /// Address of GNU-style symbol hash table.
+        GnuHash = DT_GNU_HASH => "GNU_HASH",
+        /// Address of TLS descriptor PLT entry.
+        TlsdescPlt = DT_TLSDESC_PLT => "TLSDESC_PLT",
+        /// Address of TLS descriptor GOT entry.
+        TlsdescGot = DT_TLSDESC_GOT => "TLSDESC_GOT",
+        /// Address of prelink conflicts.
+        GnuConflict = DT_GNU_CONFLICT => "GNU_CONFLICT",
+        /// Address of prelink library list.
+        GnuLiblist = DT_GNU_LIBLIST => "GNU_LIBLIST",
+        /// Configuration file.
+        Config = DT_CONFIG => "CONFIG",
+        /// Dependency auditing.
+        Depaudit = DT_DEPAUDIT => "DEPAUDIT",
+        /// Object auditing.
+        Audit = DT_AUDIT => "AUDIT",
+        /// Address of PLT padding.
+        Pltpad = DT_PLTPAD => "PLTPAD",
+        /// Address of move table.
+        Movetab = DT_MOVETAB => "MOVETAB",
+        /// Address of symbol information table.
+        Syminfo = DT_SYMINFO => "SYMINFO",
+        /// Address of symbol versions.
+        Versym = DT_VERSYM => "VERSYM",
+        /// Number of relative relocations with addends.
+        Relacount = DT_RELACOUNT => "RELACOUNT",
+        /// Number of relative relocations.
+        Relcount = DT_RELCOUNT => "RELCOUNT",
+        /// State flags.
+        Flags1 = DT_FLAGS_1 => "FLAGS_1",
+        /// Address of version definitions.
+        Verdef = DT_VERDEF => "VERDEF",
+        /// Number of version definitions.
+        Verdefnum = DT_VERDEFNUM => "VERDEFNUM",
+        /// Address of versions needed.
+        Verneed = DT_VERNEED => "VERNEED",
+        /// Number of versions needed.
+        Verneednum = DT_VERNEEDNUM => "VERNEEDNUM",
+        /// Shared object to load before the object.
+        Auxiliary = DT_AUXILIARY => "AUXILIARY",
+        /// Same as `DT_NEEDED`.
+        Used = DT_USED => "USED",
+        /// Shared object to get symbols from.
+        Filter = DT_FILTER => "FILTER",
+    }
+}
+
+impl fmt::Display for DynamicTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let DynamicTag::Unknown(raw) = *self else {
+            return f.write_str(self.name().unwrap_or_default());
+        };
+
+        match u32::try_from(raw) {
+            Ok(DT_LOPROC..=DT_HIPROC) => write!(f, "Processor Specific: {:x}", raw),
+            Ok(DT_LOOS..=DT_HIOS)     => write!(f, "Operating System specific: {:x}", raw),
+            _                         => write!(f, "<unknown>: {:x}", raw),
+        }
+    }
+}
+
+// DT_FLAGS values enumeration.
+
+/// The object may reference the `$ORIGIN` substitution string.
+pub const DF_ORIGIN: u64 = 0x1;
+/// Symbol resolution starts from the object itself, as with `DT_SYMBOLIC`.
+pub const DF_SYMBOLIC: u64 = 0x2;
+/// Relocations may modify a non-writable segment, as with `DT_TEXTREL`.
+pub const DF_TEXTREL: u64 = 0x4;
+/// All relocations are processed before transferring control to the program,
+/// as with `DT_BIND_NOW`.
+pub const DF_BIND_NOW: u64 = 0x8;
+/// The object uses the static thread-local storage model.
+pub const DF_STATIC_TLS: u64 = 0x10;
+
+// DT_FLAGS_1 values enumeration.
+
+/// Perform complete relocation processing.
+pub const DF_1_NOW: u64 = 0x1;
+/// Make symbols of the object available for all later loaded objects.
+pub const DF_1_GLOBAL: u64 = 0x2;
+/// Resolve symbols only within the object's group.
+pub const DF_1_GROUP: u64 = 0x4;
+/// The object can not be unloaded.
+pub const DF_1_NODELETE: u64 = 0x8;
+/// Load all filtees of the object immediately.
+pub const DF_1_LOADFLTR: u64 = 0x10;
+/// Initialize the object before any other.
+pub const DF_1_INITFIRST: u64 = 0x20;
+/// The object can not be loaded with `dlopen`.
+pub const DF_1_NOOPEN: u64 = 0x40;
+/// The object requires `$ORIGIN` processing.
+pub const DF_1_ORIGIN: u64 = 0x80;
+/// Direct binding is enabled.
+pub const DF_1_DIRECT: u64 = 0x100;
+/// Reserved for internal use of the runtime linker.
+pub const DF_1_TRANS: u64 = 0x200;
+/// Symbols of the object interpose all other objects except the executable.
+pub const DF_1_INTERPOSE: u64 = 0x400;
+/// Ignore the default library search path.
+pub const DF_1_NODEFLIB: u64 = 0x800;
+/// The object can not be dumped with `dldump`.
+pub const DF_1_NODUMP: u64 = 0x1000;
+/// The object is a configuration alternative.
+pub const DF_1_CONFALT: u64 = 0x2000;
+/// The filtee terminates the filter search.
+pub const DF_1_ENDFILTEE: u64 = 0x4000;
+/// Displacement relocations were done.
+pub const DF_1_DISPRELDNE: u64 = 0x8000;
+/// Displacement relocations are pending.
+pub const DF_1_DISPRELPND: u64 = 0x10000;
+/// The object has no-direct bindings.
+pub const DF_1_NODIRECT: u64 = 0x20000;
+/// Ignore multiple definitions of the same symbol.
+pub const DF_1_IGNMULDEF: u64 = 0x40000;
+/// The object has no kernel symbols to export.
+pub const DF_1_NOKSYMS: u64 = 0x80000;
+/// The object has no ELF header mapped into memory.
+pub const DF_1_NOHDR: u64 = 0x100000;
+/// The object was modified after it was built.
+pub const DF_1_EDITED: u64 = 0x200000;
+/// The object has no relocations to process.
+pub const DF_1_NORELOC: u64 = 0x400000;
+/// The object has individual symbol interposers.
+pub const DF_1_SYMINTPOSE: u64 = 0x800000;
+/// Global auditing is required.
+pub const DF_1_GLOBAUDIT: u64 = 0x1000000;
+/// The object defines singleton symbols.
+pub const DF_1_SINGLETON: u64 = 0x2000000;
+/// The object is a stub containing only symbol definitions.
+pub const DF_1_STUB: u64 = 0x4000000;
+/// The object is a position-independent executable.
+pub const DF_1_PIE: u64 = 0x8000000;
+/// The object is a kernel module.
+pub const DF_1_KMOD: u64 = 0x10000000;
+/// The object is a weak standard filter.
+pub const DF_1_WEAKFILTER: u64 = 0x20000000;
+/// Common symbols of the object are not treated as tentative definitions.
+pub const DF_1_NOCOMMON: u64 = 0x40000000;
+
+// DT_POSFLAG_1 values enumeration.
+
+/// Lazy loading of the following object.
+pub const DF_P1_LAZY: u64 = 0x1;
+/// The following object is loaded with its own group.
+pub const DF_P1_GROUPPERM: u64 = 0x2;
+
+// DT_FEATURE_1 values enumeration.
+
+/// Partial initialization is supported.
+pub const DTF_1_PARINIT: u64 = 0x1;
+/// Configuration file is expected.
+pub const DTF_1_CONFEXP: u64 = 0x2;
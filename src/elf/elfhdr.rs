@@ -16,7 +16,12 @@
 
 //! ELF header module.
 
-use crate::elf::{Elf32_Addr, Elf32_Half, Elf32_Off, Elf32_Word};
+use core::fmt;
+
+use crate::elf::{
+    Elf32_Addr, Elf32_Half, Elf32_Off, Elf32_Word, Elf64_Addr, Elf64_Half,
+    Elf64_Off, Elf64_Word,
+};
 
 /// ELF header struct.
 #[derive(Debug, Clone, Copy)]
@@ -85,6 +90,77 @@ pub struct Elf32_Ehdr {
     pub e_shstrndx: Elf32_Half,
 }
 
+/// ELF header struct for the 64-bit class of object files.
+///
+/// It holds the same members as `Elf32_Ehdr`, with addresses and offsets
+/// widened to 64 bits. Headers of 32-bit files are converted to this layout,
+/// so the rest of the parser deals with a single representation.
+#[derive(Debug, Clone, Copy)]
+#[repr(C, packed)]
+pub struct Elf64_Ehdr {
+    /// File identification, see `Elf32_Ehdr::e_ident`.
+    pub e_ident: [u8; EI_NIDENT],
+    /// Object file type.
+    pub e_type: Elf64_Half,
+    /// Required architecture.
+    pub e_machine: Elf64_Half,
+    /// Object file version.
+    pub e_version: Elf64_Word,
+    /// Entry point virtual address.
+    pub e_entry: Elf64_Addr,
+    /// Program header table file offset.
+    pub e_phoff: Elf64_Off,
+    /// Section header table file offset.
+    pub e_shoff: Elf64_Off,
+    /// Processor-specific flags.
+    pub e_flags: Elf64_Word,
+    /// ELF header size in bytes.
+    pub e_ehsize: Elf64_Half,
+    /// Program header table entry size.
+    pub e_phentsize: Elf64_Half,
+    /// Program header table entry count.
+    pub e_phnum: Elf64_Half,
+    /// Section header table entry size.
+    pub e_shentsize: Elf64_Half,
+    /// Section header table entry count.
+    pub e_shnum: Elf64_Half,
+    /// Section header string table index.
+    pub e_shstrndx: Elf64_Half,
+}
+
+impl From<Elf32_Ehdr> for Elf64_Ehdr {
+    fn from(hdr: Elf32_Ehdr) -> Self {
+        Elf64_Ehdr {
+            e_ident:     hdr.e_ident,
+            e_type:      hdr.e_type,
+            e_machine:   hdr.e_machine,
+            e_version:   hdr.e_version,
+            e_entry:     hdr.e_entry as Elf64_Addr,
+            e_phoff:     hdr.e_phoff as Elf64_Off,
+            e_shoff:     hdr.e_shoff as Elf64_Off,
+            e_flags:     hdr.e_flags,
+            e_ehsize:    hdr.e_ehsize,
+            e_phentsize: hdr.e_phentsize,
+            e_phnum:     hdr.e_phnum,
+            e_shentsize: hdr.e_shentsize,
+            e_shnum:     hdr.e_shnum,
+            e_shstrndx:  hdr.e_shstrndx,
+        }
+    }
+}
+
+impl Elf64_Ehdr {
+    /// Get object file type.
+    pub fn file_type(&self) -> FileType {
+        FileType::from(self.e_type)
+    }
+
+    /// Get required architecture.
+    pub fn machine(&self) -> Machine {
+        Machine::from(self.e_machine)
+    }
+}
+
 // ELF object file type enumeration.
 
 /// No file type.
@@ -97,6 +173,10 @@ pub const ET_EXEC: u16 = 2;
 pub const ET_DYN: u16 = 3;
 /// Core file.
 pub const ET_CORE: u16 = 4;
+/// Operating system-specific.
+pub const ET_LOOS: u16 = 0xfe00;
+/// Operating system-specific.
+pub const ET_HIOS: u16 = 0xfeff;
 /// Processor-specific.
 pub const ET_LOPROC: u16 = 0xff00;
 /// Processor-specific.
@@ -116,10 +196,120 @@ pub const EM_386: u16 = 3;
 pub const EM_68K: u16 = 4;
 /// Motorola 88000.
 pub const EM_88K: u16 = 5;
+/// Intel MCU.
+pub const EM_IAMCU: u16 = 6;
 /// Intel 80860.
 pub const EM_860: u16 = 7;
 /// MIPS RS3000.
 pub const EM_MIPS: u16 = 8;
+/// SPARC v9 64-bit, old value.
+pub const EM_OLD_SPARCV9: u16 = 11;
+/// SPARC 32-bit with v8+ extensions.
+pub const EM_SPARC32PLUS: u16 = 18;
+/// PowerPC.
+pub const EM_PPC: u16 = 20;
+/// PowerPC 64-bit.
+pub const EM_PPC64: u16 = 21;
+/// ARM 32-bit architecture.
+pub const EM_ARM: u16 = 40;
+/// SPARC v9 64-bit.
+pub const EM_SPARCV9: u16 = 43;
+/// Intel IA-64.
+pub const EM_IA_64: u16 = 50;
+/// AMD x86-64 architecture.
+pub const EM_X86_64: u16 = 62;
+/// Intel L10M.
+pub const EM_L1OM: u16 = 180;
+/// Intel K10M.
+pub const EM_K1OM: u16 = 181;
+/// ARM 64-bit architecture.
+pub const EM_AARCH64: u16 = 183;
+/// RISC-V.
+pub const EM_RISCV: u16 = 243;
+
+elf_enum! {
+    /// Object file type, `e_type` field of ELF header.
+    pub enum FileType: u16 {
+        /// No file type.
+        None = ET_NONE => "NONE (None)",
+        /// Relocatable file.
+        Rel = ET_REL => "REL (Relocatable file)",
+        /// Executable file.
+        Exec = ET_EXEC => "EXEC (Executable file)",
+        /// Shared object file.
+        Dyn = ET_DYN => "DYN (Shared object file)",
+        /// Core file.
+        Core = ET_CORE => "CORE (Core file)",
+    }
+}
+
+impl fmt::Display for FileType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FileType::Unknown(raw @ ET_LOPROC..=ET_HIPROC) => write!(f, "Processor Specific: ({:x})", raw),
+            FileType::Unknown(raw @ ET_LOOS..=ET_HIOS)     => write!(f, "OS Specific: ({:x})", raw),
+            FileType::Unknown(raw)                         => write!(f, "<unknown>: {:x}", raw),
+            known => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
+
+elf_enum! {
+    /// Required architecture, `e_machine` field of ELF header.
+    pub enum Machine: u16 {
+        /// No machine.
+        None = EM_NONE => "None",
+        /// AT&T WE 32100.
+        M32 = EM_M32 => "WE32100",
+        /// SPARC.
+        Sparc = EM_SPARC => "Sparc",
+        /// Intel 80386.
+        I386 = EM_386 => "Intel 80386",
+        /// Motorola 68000.
+        M68k = EM_68K => "MC68000",
+        /// Motorola 88000.
+        M88k = EM_88K => "MC88000",
+        /// Intel MCU.
+        IaMcu = EM_IAMCU => "Intel MCU",
+        /// Intel 80860.
+        I860 = EM_860 => "Intel 80860",
+        /// MIPS RS3000.
+        Mips = EM_MIPS => "MIPS R3000",
+        /// SPARC v9 64-bit, old value.
+        OldSparcV9 = EM_OLD_SPARCV9 => "Sparc v9 (old)",
+        /// SPARC 32-bit with v8+ extensions.
+        Sparc32Plus = EM_SPARC32PLUS => "Sparc v8+",
+        /// PowerPC.
+        Ppc = EM_PPC => "PowerPC",
+        /// PowerPC 64-bit.
+        Ppc64 = EM_PPC64 => "PowerPC64",
+        /// ARM 32-bit architecture.
+        Arm = EM_ARM => "ARM",
+        /// SPARC v9 64-bit.
+        SparcV9 = EM_SPARCV9 => "Sparc v9",
+        /// Intel IA-64.
+        Ia64 = EM_IA_64 => "Intel IA-64",
+        /// AMD x86-64 architecture.
+        X86_64 = EM_X86_64 => "Advanced Micro Devices X86-64",
+        /// Intel L10M.
+        L1om = EM_L1OM => "Intel L1OM",
+        /// Intel K10M.
+        K1om = EM_K1OM => "Intel K1OM",
+        /// ARM 64-bit architecture.
+        Aarch64 = EM_AARCH64 => "AArch64",
+        /// RISC-V.
+        RiscV = EM_RISCV => "RISC-V",
+    }
+}
+
+impl fmt::Display for Machine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Machine::Unknown(raw) => write!(f, "<unknown>: {:#x}", raw),
+            known                 => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
 
 /// File identification.
 pub const EI_MAG0: usize = 0;
@@ -157,6 +347,17 @@ pub const EV_NONE: u8 = 0;
 /// Current version.
 pub const EV_CURRENT: u8 = 1;
 
+/// Operating system/ABI identification.
+pub const EI_OSABI: usize = 7;
+/// UNIX System V ABI.
+pub const ELFOSABI_NONE: u8 = 0;
+/// Object uses GNU ELF extensions.
+pub const ELFOSABI_GNU: u8 = 3;
+/// Sun Solaris.
+pub const ELFOSABI_SOLARIS: u8 = 6;
+/// FreeBSD.
+pub const ELFOSABI_FREEBSD: u8 = 9;
+
 /// Start of padding bytes.
 pub const EI_PAD: usize = 7;
 /// Size of e_ident[].
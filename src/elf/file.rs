@@ -0,0 +1,1366 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! ELF file module.
+//!
+//! Reads ELF images of both classes. Structures of 32-bit files are converted
+//! to their 64-bit counterparts, so callers deal with a single representation.
+//!
+//! File contents are borrowed, e.g. from a memory mapping, or read on demand
+//! from a `Source`, and tables are exposed as views decoding entries on
+//! access, so only the parts of the file which are actually displayed are
+//! read.
+//!
+//! Section contents and strings are decompressed if the section is
+//! compressed. Table views borrow sections as they are stored in the file,
+//! since sections holding them are not compressed in practice.
+
+use alloc::borrow::Cow;
+use alloc::vec::Vec;
+use core::fmt;
+use core::mem::size_of;
+
+use crate::decompress::{zlib, zstd};
+use crate::elf::android::decode_packed_relocs;
+use crate::elf::dynsec::{
+    DynamicTag, Elf32_Dyn, Elf64_Dyn, DT_NEEDED, DT_NULL,
+};
+use crate::elf::elfhdr::*;
+use crate::elf::group::SectionGroup;
+use crate::elf::hash::{GnuHash, SysvHash};
+use crate::elf::note::NoteIter;
+use crate::elf::progtbl::{Elf32_Phdr, Elf64_Phdr, PN_XNUM, PT_DYNAMIC, PT_LOAD, PT_NOTE};
+use crate::elf::reloc::{Elf32_Rel, Elf32_Rela, Elf64_Rel, Elf64_Rela, RelrIter};
+use crate::elf::sechdr::*;
+use crate::elf::source::{Contents, Source};
+use crate::elf::strtab::{StringIter, StringTable};
+use crate::elf::symtbl::{elf32_st_bind, Elf32_Sym, Elf64_Sym, STB_LOCAL};
+use crate::elf::table::Table;
+use crate::elf::version::{
+    Elf64_Verdaux, Elf64_Verdef, Elf64_Vernaux, Elf64_Verneed, NeededVersion, SymbolVersion,
+    VerdefIter, VernauxIter, VerneedIter, VersionEntry, VersionNeed, VERSYM_HIDDEN,
+    VERSYM_VERSION, VER_FLG_BASE, VER_NDX_GLOBAL,
+};
+
+/// ELF parsing error enumeration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ElfError {
+    /// The file does not start with the ELF magic number.
+    BadMagic,
+    /// The file class is neither `ELFCLASS32` nor `ELFCLASS64`.
+    BadClass(u8),
+    /// The data encoding is not supported.
+    BadEncoding(u8),
+    /// A structure or table lies outside of the file.
+    Truncated,
+    /// A section index does not refer to an existing section.
+    BadSectionIndex(usize),
+    /// Some structure holds inconsistent values.
+    Malformed(&'static str),
+    /// Compressed section uses unknown compression algorithm.
+    UnsupportedCompression(u32),
+    /// Compressed section data can not be decompressed.
+    BadCompressedData,
+    /// File contents can not be read from the source.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+
+impl fmt::Display for ElfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElfError::BadMagic => {
+                write!(f, "Not an ELF file - it has the wrong magic bytes at the start")
+            }
+            ElfError::BadClass(c)        => write!(f, "Unsupported ELF class: {}", c),
+            ElfError::BadEncoding(d)     => write!(f, "Unsupported ELF data encoding: {}", d),
+            ElfError::Truncated          => write!(f, "File is truncated"),
+            ElfError::BadSectionIndex(i) => write!(f, "Section index {} is out of range", i),
+            ElfError::Malformed(s)       => write!(f, "{}", s),
+            ElfError::UnsupportedCompression(t) => write!(f, "Unsupported compression type: {}", t),
+            ElfError::BadCompressedData  => write!(f, "Unable to decompress section data"),
+            #[cfg(feature = "std")]
+            ElfError::Io(kind)           => write!(f, "Unable to read file: {}", kind),
+        }
+    }
+}
+
+impl core::error::Error for ElfError {}
+
+/// ELF parsing result.
+pub type Result<T> = core::result::Result<T, ElfError>;
+
+/// Read structure from bytes.
+///
+/// # Parameters
+/// - `data`   - given bytes.
+/// - `offset` - given offset of the structure.
+///
+/// # Returns
+/// - Structure copy - in case of success.
+/// - `ElfError::Truncated` - if structure does not fit into `data`.
+pub fn read<T: Copy>(data: &[u8], offset: usize) -> Result<T> {
+    let end = offset.checked_add(size_of::<T>()).ok_or(ElfError::Truncated)?;
+
+    if end > data.len() {
+        return Err(ElfError::Truncated);
+    }
+
+    // Structures are packed, so any byte offset is properly aligned.
+    Ok(unsafe { core::ptr::read_unaligned(data.as_ptr().add(offset) as *const T) })
+}
+
+/// Read structure from file contents.
+///
+/// # Parameters
+/// - `contents` - given file contents.
+/// - `offset`   - given file offset of the structure.
+///
+/// # Returns
+/// - Structure copy - in case of success.
+/// - `ElfError` - if structure does not fit into the file or can not be read.
+fn read_at<T: Copy>(contents: Contents, offset: u64) -> Result<T> {
+    read::<T>(contents.bytes(offset, size_of::<T>() as u64)?, 0)
+}
+
+/// Get bytes range of the file.
+///
+/// # Parameters
+/// - `data`   - given bytes.
+/// - `offset` - given range offset.
+/// - `size`   - given range size.
+///
+/// # Returns
+/// - Bytes slice - in case of success.
+/// - `ElfError::Truncated` - if range does not fit into `data`.
+pub fn slice(data: &[u8], offset: u64, size: u64) -> Result<&[u8]> {
+    let start = usize::try_from(offset).map_err(|_| ElfError::Truncated)?;
+    let size  = usize::try_from(size).map_err(|_| ElfError::Truncated)?;
+    let end   = start.checked_add(size).ok_or(ElfError::Truncated)?;
+
+    data.get(start..end).ok_or(ElfError::Truncated)
+}
+
+/// Get null-terminated string from string table bytes.
+///
+/// # Parameters
+/// - `table`  - given string table bytes.
+/// - `offset` - given string offset.
+///
+/// # Returns
+/// - String - in case of success.
+/// - `ElfError` - if offset is out of table or string is not valid UTF-8.
+pub fn cstr(table: &[u8], offset: usize) -> Result<&str> {
+    let bytes = table.get(offset..).ok_or(ElfError::Truncated)?;
+    let len   = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+
+    core::str::from_utf8(&bytes[..len])
+        .map_err(|_| ElfError::Malformed("String is not valid UTF-8"))
+}
+
+/// ELF file struct.
+pub struct ElfFile<'a> {
+    /// File contents.
+    contents: Contents<'a>,
+    /// File class, either `ELFCLASS32` or `ELFCLASS64`.
+    class: u8,
+    /// File header.
+    hdr: Elf64_Ehdr,
+    /// Section header table.
+    shdrs: Vec<Elf64_Shdr>,
+    /// Program header table.
+    phdrs: Vec<Elf64_Phdr>,
+}
+
+impl<'a> ElfFile<'a> {
+    /// Parse ELF file.
+    ///
+    /// # Parameters
+    /// - `data` - given file contents.
+    ///
+    /// # Returns
+    /// - `ElfFile` - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn parse(data: &'a [u8]) -> Result<ElfFile<'a>> {
+        Self::from_contents(Contents::Bytes(data))
+    }
+
+    /// Parse ELF file read from source.
+    ///
+    /// Only the file header and section and program header tables are read
+    /// here, everything else is read when requested.
+    ///
+    /// # Parameters
+    /// - `source` - given file contents source, e.g. `StreamSource`.
+    ///
+    /// # Returns
+    /// - `ElfFile` - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn from_source(source: &'a dyn Source) -> Result<ElfFile<'a>> {
+        Self::from_contents(Contents::Source(source))
+    }
+
+    /// Parse ELF file contents.
+    fn from_contents(contents: Contents<'a>) -> Result<ElfFile<'a>> {
+        let ident = contents.bytes(0, EI_NIDENT as u64).map_err(|_| ElfError::BadMagic)?;
+
+        if ident[EI_MAG0..=EI_MAG3] != ELF_MAGIC {
+            return Err(ElfError::BadMagic);
+        }
+
+        if ident[EI_DATA] != ELFDATA2LSB {
+            return Err(ElfError::BadEncoding(ident[EI_DATA]));
+        }
+
+        let class = ident[EI_CLASS];
+        let hdr: Elf64_Ehdr = match class {
+            ELFCLASS32 => read_at::<Elf32_Ehdr>(contents, 0)?.into(),
+            ELFCLASS64 => read_at::<Elf64_Ehdr>(contents, 0)?,
+            _          => return Err(ElfError::BadClass(class)),
+        };
+
+        let mut file = ElfFile { contents, class, hdr, shdrs: Vec::new(), phdrs: Vec::new() };
+        file.shdrs = file.read_section_headers()?;
+        file.phdrs = file.read_program_headers()?;
+        Ok(file)
+    }
+
+    /// Read section header table.
+    ///
+    /// Headers are few and used by nearly every query, so they are decoded
+    /// once and kept.
+    fn read_section_headers(&self) -> Result<Vec<Elf64_Shdr>> {
+        self.section_table()?.iter().map(Ok).collect()
+    }
+
+    /// Read program header table.
+    fn read_program_headers(&self) -> Result<Vec<Elf64_Phdr>> {
+        self.program_table()?.iter().map(Ok).collect()
+    }
+
+    /// Get view over section header table.
+    ///
+    /// # Returns
+    /// - Section header table - in case of success.
+    /// - `ElfError::Truncated` - if table lies outside of the file.
+    pub fn section_table(&self) -> Result<Table<'a, Elf64_Shdr>> {
+        let shoff   = self.hdr.e_shoff;
+        let entsize = self.hdr.e_shentsize as usize;
+
+        if shoff == 0 {
+            return Ok(self.table::<Elf32_Shdr, Elf64_Shdr>(&[], entsize));
+        }
+
+        let first = self.table::<Elf32_Shdr, Elf64_Shdr>(self.bytes(shoff, entsize as u64)?, entsize);
+
+        // Files with more than SHN_LORESERVE sections keep the real count in
+        // the sh_size field of the first section header.
+        let count = match self.hdr.e_shnum {
+            0 => first.get(0)?.sh_size,
+            n => n as u64,
+        };
+
+        let size = count.checked_mul(entsize as u64).ok_or(ElfError::Truncated)?;
+        Ok(self.table::<Elf32_Shdr, Elf64_Shdr>(self.bytes(shoff, size)?, entsize))
+    }
+
+    /// Get view over program header table.
+    ///
+    /// # Returns
+    /// - Program header table - in case of success.
+    /// - `ElfError::Truncated` - if table lies outside of the file.
+    pub fn program_table(&self) -> Result<Table<'a, Elf64_Phdr>> {
+        let phoff   = self.hdr.e_phoff;
+        let entsize = self.hdr.e_phentsize as usize;
+
+        if phoff == 0 {
+            return Ok(self.table::<Elf32_Phdr, Elf64_Phdr>(&[], entsize));
+        }
+
+        // Files with too many segments keep the real count in the sh_info
+        // field of the first section header.
+        let count = match self.hdr.e_phnum {
+            PN_XNUM => self.shdrs.first().map_or(0, |s| s.sh_info as u64),
+            n       => n as u64,
+        };
+
+        let size = count.checked_mul(entsize as u64).ok_or(ElfError::Truncated)?;
+        Ok(self.table::<Elf32_Phdr, Elf64_Phdr>(self.bytes(phoff, size)?, entsize))
+    }
+
+    /// Construct table view of class-dependent entries.
+    ///
+    /// # Parameters
+    /// - `data`    - given table bytes.
+    /// - `entsize` - given entry size from the file, zero for natural size.
+    fn table<R32, R64>(&self, data: &'a [u8], entsize: usize) -> Table<'a, R64>
+    where
+        R32: Copy + Into<R64>,
+        R64: Copy,
+    {
+        match self.class {
+            ELFCLASS32 => Table::with_entsize::<R32>(data, entsize),
+            _          => Table::with_entsize::<R64>(data, entsize),
+        }
+    }
+
+    /// Get size of the file.
+    pub fn size(&self) -> u64 {
+        self.contents.size()
+    }
+
+    /// Get bytes range of the file.
+    ///
+    /// # Parameters
+    /// - `offset` - given range offset.
+    /// - `size`   - given range size.
+    ///
+    /// # Returns
+    /// - Bytes - in case of success.
+    /// - `ElfError` - if range does not fit into the file or can not be read.
+    pub fn bytes(&self, offset: u64, size: u64) -> Result<&'a [u8]> {
+        self.contents.bytes(offset, size)
+    }
+
+    /// Get file class.
+    pub fn class(&self) -> u8 {
+        self.class
+    }
+
+    /// Check whether file belongs to the 64-bit class.
+    pub fn is_64(&self) -> bool {
+        self.class == ELFCLASS64
+    }
+
+    /// Get file header.
+    pub fn header(&self) -> &Elf64_Ehdr {
+        &self.hdr
+    }
+
+    /// Get section header table.
+    pub fn section_headers(&self) -> &[Elf64_Shdr] {
+        &self.shdrs
+    }
+
+    /// Get program header table.
+    pub fn program_headers(&self) -> &[Elf64_Phdr] {
+        &self.phdrs
+    }
+
+    /// Convert virtual address to file offset.
+    ///
+    /// Loadable segments are used when present, otherwise sections are.
+    ///
+    /// # Parameters
+    /// - `addr` - given virtual address.
+    ///
+    /// # Returns
+    /// - File offset - if address is backed by file contents.
+    /// - `None` - otherwise.
+    pub fn vaddr_to_offset(&self, addr: u64) -> Option<u64> {
+        let segment = self.phdrs.iter().find(|p| {
+            p.p_type == PT_LOAD && addr >= p.p_vaddr && addr - p.p_vaddr < p.p_filesz
+        });
+
+        if let Some(phdr) = segment {
+            return Some(phdr.p_offset + (addr - phdr.p_vaddr));
+        }
+
+        self.shdrs
+            .iter()
+            .find(|s| {
+                s.sh_type != SHT_NOBITS
+                    && s.sh_flags & SHF_ALLOC as u64 != 0
+                    && addr >= s.sh_addr
+                    && addr - s.sh_addr < s.sh_size
+            })
+            .map(|s| s.sh_offset + (addr - s.sh_addr))
+    }
+
+    /// Get section header by index.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    ///
+    /// # Returns
+    /// - Section header - in case of success.
+    /// - `ElfError::BadSectionIndex` - if there is no such section.
+    pub fn section_header(&self, index: usize) -> Result<&Elf64_Shdr> {
+        self.shdrs.get(index).ok_or(ElfError::BadSectionIndex(index))
+    }
+
+    /// Get index of the section header string table.
+    pub fn shstrndx(&self) -> usize {
+        match self.hdr.e_shstrndx as u32 {
+            SHN_XINDEX => self.shdrs.first().map_or(0, |s| s.sh_link as usize),
+            index      => index as usize,
+        }
+    }
+
+    /// Get section name.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Section name - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn section_name(&self, shdr: &Elf64_Shdr) -> Result<&'a str> {
+        self.string(self.shstrndx(), shdr.sh_name)
+    }
+
+    /// Find section by name.
+    ///
+    /// # Parameters
+    /// - `name` - given section name.
+    ///
+    /// # Returns
+    /// - Index of the first section with given name - if it exists.
+    /// - `None` - otherwise.
+    pub fn section_by_name(&self, name: &str) -> Option<usize> {
+        self.shdrs
+            .iter()
+            .position(|shdr| self.section_name(shdr).is_ok_and(|n| n == name))
+    }
+
+    /// Get section contents as they are stored in the file.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Section bytes, empty for `SHT_NOBITS` sections - in case of success.
+    /// - `ElfError::Truncated` - if section lies outside of the file.
+    pub fn raw_section_data(&self, shdr: &Elf64_Shdr) -> Result<&'a [u8]> {
+        if shdr.sh_type == SHT_NOBITS {
+            return Ok(&[]);
+        }
+
+        self.bytes(shdr.sh_offset, shdr.sh_size)
+    }
+
+    /// Get compression header of `SHF_COMPRESSED` section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Compression header - in case of success.
+    /// - `ElfError::Truncated` - if header lies outside of the file.
+    pub fn compression_header(&self, shdr: &Elf64_Shdr) -> Result<Elf64_Chdr> {
+        match self.class {
+            ELFCLASS32 => Ok(read_at::<Elf32_Chdr>(self.contents, shdr.sh_offset)?.into()),
+            _          => read_at::<Elf64_Chdr>(self.contents, shdr.sh_offset),
+        }
+    }
+
+    /// Get section contents, decompressed if the section is compressed.
+    ///
+    /// Both `SHF_COMPRESSED` sections and legacy `.zdebug` sections, whose
+    /// data starts with `ZLIB` followed by 8 bytes big-endian uncompressed
+    /// size, are decompressed. Contents of other sections are borrowed.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Section bytes, empty for `SHT_NOBITS` sections - in case of success.
+    /// - `ElfError` - if section can not be read or decompressed.
+    pub fn section_data(&self, shdr: &Elf64_Shdr) -> Result<Cow<'a, [u8]>> {
+        let data = self.raw_section_data(shdr)?;
+
+        let is_zdebug = || self.section_name(shdr).is_ok_and(|name| name.starts_with(".zdebug"));
+
+        let (ch_type, size, compressed) = if shdr.sh_flags & SHF_COMPRESSED as u64 != 0 {
+            let hdr_size = match self.class {
+                ELFCLASS32 => size_of::<Elf32_Chdr>(),
+                _          => size_of::<Elf64_Chdr>(),
+            };
+
+            if data.len() < hdr_size {
+                return Err(ElfError::Malformed(
+                    "Compressed section is too small even for a compression header",
+                ));
+            }
+
+            let chdr = self.compression_header(shdr)?;
+            (chdr.ch_type, chdr.ch_size, &data[hdr_size..])
+        } else if data.len() > 12 && data.starts_with(b"ZLIB") && is_zdebug() {
+            let size = u64::from_be_bytes(data[4..12].try_into().unwrap());
+            (ELFCOMPRESS_ZLIB, size, &data[12..])
+        } else {
+            return Ok(Cow::Borrowed(data));
+        };
+
+        if !matches!(ch_type, ELFCOMPRESS_ZLIB | ELFCOMPRESS_ZSTD) {
+            return Err(ElfError::UnsupportedCompression(ch_type));
+        }
+
+        if size == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
+
+        let size = usize::try_from(size).map_err(|_| ElfError::BadCompressedData)?;
+
+        let decompressed = match ch_type {
+            ELFCOMPRESS_ZLIB => zlib::decompress(compressed, size),
+            _                => zstd::decompress(compressed, size),
+        };
+
+        decompressed.map(Cow::Owned).ok_or(ElfError::BadCompressedData)
+    }
+
+    /// Get strings of section.
+    ///
+    /// Any section can be treated as a table of NUL-terminated strings, not
+    /// only `SHT_STRTAB` ones, e.g. `.comment` or `.modinfo`.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Iterator over strings - in case of success.
+    /// - `ElfError` - if section can not be read or decompressed.
+    pub fn strings(&self, shdr: &Elf64_Shdr) -> Result<StringIter<'a>> {
+        Ok(StringIter::new(self.section_data(shdr)?))
+    }
+
+    /// Get view over string table section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given string table section header.
+    ///
+    /// # Returns
+    /// - String table - in case of success.
+    /// - `ElfError` - if section can not be read or decompressed.
+    pub fn string_table(&self, shdr: &Elf64_Shdr) -> Result<StringTable<'a>> {
+        Ok(StringTable::new(self.section_data(shdr)?))
+    }
+
+    /// Get string from string table section.
+    ///
+    /// Names are read from string tables as they are stored in the file, so
+    /// they are borrowed from it. Section names can not be read otherwise,
+    /// because the `.zdebug` prefix decides whether a section is compressed.
+    ///
+    /// # Parameters
+    /// - `strndx` - given string table section index.
+    /// - `offset` - given string offset inside of the table.
+    ///
+    /// # Returns
+    /// - String - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn string(&self, strndx: usize, offset: u32) -> Result<&'a str> {
+        cstr(self.raw_section_data(self.section_header(strndx)?)?, offset as usize)
+    }
+
+    /// Get size of the symbol table entry for this file class.
+    fn sym_size(&self) -> usize {
+        match self.class {
+            ELFCLASS32 => size_of::<Elf32_Sym>(),
+            _          => size_of::<Elf64_Sym>(),
+        }
+    }
+
+    /// Get number of entries in symbol table section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given symbol table section header.
+    pub fn symbol_count(&self, shdr: &Elf64_Shdr) -> usize {
+        shdr.sh_size as usize / self.sym_size()
+    }
+
+    /// Get symbol table entry.
+    ///
+    /// # Parameters
+    /// - `shdr`  - given symbol table section header.
+    /// - `index` - given symbol index.
+    ///
+    /// # Returns
+    /// - Symbol - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn symbol(&self, shdr: &Elf64_Shdr, index: usize) -> Result<Elf64_Sym> {
+        self.symbol_table(shdr)?.get(index)
+    }
+
+    /// Get view over symbol table section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given symbol table section header.
+    ///
+    /// # Returns
+    /// - Symbol table - in case of success.
+    /// - `ElfError::Truncated` - if section lies outside of the file.
+    pub fn symbol_table(&self, shdr: &Elf64_Shdr) -> Result<Table<'a, Elf64_Sym>> {
+        Ok(self.table::<Elf32_Sym, Elf64_Sym>(self.raw_section_data(shdr)?, 0))
+    }
+
+    /// Iterate over symbol table entries.
+    ///
+    /// # Parameters
+    /// - `shdr` - given symbol table section header.
+    pub fn symbols(&self, shdr: &Elf64_Shdr) -> impl Iterator<Item = Elf64_Sym> + 'a {
+        self.symbol_table(shdr).into_iter().flatten()
+    }
+
+    /// Get symbol name.
+    ///
+    /// # Parameters
+    /// - `symtab` - given symbol table section header.
+    /// - `sym`    - given symbol.
+    ///
+    /// # Returns
+    /// - Symbol name - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn symbol_name(&self, symtab: &Elf64_Shdr, sym: &Elf64_Sym) -> Result<&'a str> {
+        self.string(symtab.sh_link as usize, sym.st_name)
+    }
+
+    /// Get relocation entries of relocation section.
+    ///
+    /// Entries without explicit addends get zero addend. Android packed
+    /// relocations are decoded to ordinary entries.
+    ///
+    /// # Parameters
+    /// - `shdr` - given `SHT_REL`, `SHT_RELA`, `SHT_ANDROID_REL` or
+    ///   `SHT_ANDROID_RELA` section header.
+    ///
+    /// # Returns
+    /// - Relocation entries - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn relocations(&self, shdr: &Elf64_Shdr) -> Result<Vec<Elf64_Rela>> {
+        let data = self.section_data(shdr)?;
+
+        match shdr.sh_type {
+            SHT_ANDROID_REL  => decode_packed_relocs(&data, self.is_64(), false),
+            SHT_ANDROID_RELA => decode_packed_relocs(&data, self.is_64(), true),
+            _ => Ok(self.relocation_table(shdr)?.iter().collect()),
+        }
+    }
+
+    /// Get view over relocation section.
+    ///
+    /// Entries without explicit addends get zero addend.
+    ///
+    /// # Parameters
+    /// - `shdr` - given `SHT_REL` or `SHT_RELA` section header.
+    ///
+    /// # Returns
+    /// - Relocation table - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn relocation_table(&self, shdr: &Elf64_Shdr) -> Result<Table<'a, Elf64_Rela>> {
+        let data = self.raw_section_data(shdr)?;
+
+        match (self.class, shdr.sh_type) {
+            (ELFCLASS32, SHT_REL)  => Ok(Table::new::<Elf32_Rel>(data)),
+            (ELFCLASS32, SHT_RELA) => Ok(Table::new::<Elf32_Rela>(data)),
+            (ELFCLASS64, SHT_REL)  => Ok(Table::new::<Elf64_Rel>(data)),
+            (ELFCLASS64, SHT_RELA) => Ok(Table::new::<Elf64_Rela>(data)),
+            _ => Err(ElfError::Malformed("Not a relocation section")),
+        }
+    }
+
+    /// Find dynamic section.
+    ///
+    /// The `PT_DYNAMIC` segment is used when present, otherwise the
+    /// `SHT_DYNAMIC` section is.
+    ///
+    /// # Returns
+    /// - File offset and size of the dynamic section - if it exists.
+    /// - `None` - otherwise.
+    pub fn dynamic_location(&self) -> Option<(u64, u64)> {
+        if let Some(phdr) = self.phdrs.iter().find(|p| p.p_type == PT_DYNAMIC) {
+            return Some((phdr.p_offset, phdr.p_filesz));
+        }
+
+        self.shdrs
+            .iter()
+            .find(|s| s.sh_type == SHT_DYNAMIC)
+            .map(|s| (s.sh_offset, s.sh_size))
+    }
+
+    /// Get dynamic section entries.
+    ///
+    /// Entries are read up to and including the first `DT_NULL` entry.
+    ///
+    /// # Returns
+    /// - Dynamic entries, empty if there is no dynamic section - in case of
+    ///   success.
+    /// - `ElfError` - otherwise.
+    pub fn dynamic(&self) -> Result<Vec<Elf64_Dyn>> {
+        let mut entries = Vec::new();
+
+        for entry in self.dynamic_table()? {
+            entries.push(entry);
+
+            if entry.d_tag == DT_NULL as i64 {
+                break;
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Get view over the whole dynamic section, including entries following
+    /// the first `DT_NULL` entry.
+    ///
+    /// # Returns
+    /// - Dynamic table, empty if there is no dynamic section - in case of
+    ///   success.
+    /// - `ElfError::Truncated` - if dynamic section lies outside of the file.
+    pub fn dynamic_table(&self) -> Result<Table<'a, Elf64_Dyn>> {
+        let data = match self.dynamic_location() {
+            Some((offset, size)) => self.bytes(offset, size)?,
+            None                 => &[],
+        };
+
+        Ok(self.table::<Elf32_Dyn, Elf64_Dyn>(data, 0))
+    }
+
+    /// Get value of the first dynamic entry with given tag.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `tag`     - given entry tag.
+    pub fn dynamic_value(entries: &[Elf64_Dyn], tag: DynamicTag) -> Option<u64> {
+        let tag = i64::from(tag);
+        entries.iter().find(|d| d.d_tag == tag).map(Elf64_Dyn::val)
+    }
+
+    /// Get dynamic string table.
+    ///
+    /// The table referenced by `DT_STRTAB` and `DT_STRSZ` entries is used when
+    /// it can be found in the file, otherwise the section linked to the
+    /// `SHT_DYNAMIC` section is.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// - String table bytes - if it exists.
+    /// - `None` - otherwise.
+    pub fn dynamic_strtab(&self, entries: &[Elf64_Dyn]) -> Option<&'a [u8]> {
+        let addr = Self::dynamic_value(entries, DynamicTag::Strtab);
+        let size = Self::dynamic_value(entries, DynamicTag::Strsz);
+
+        if let (Some(addr), Some(size)) = (addr, size) {
+            let table = self.vaddr_to_offset(addr).and_then(|off| self.bytes(off, size).ok());
+
+            if table.is_some() {
+                return table;
+            }
+        }
+
+        let dynamic = self.shdrs.iter().find(|s| s.sh_type == SHT_DYNAMIC)?;
+        let strtab  = self.section_header(dynamic.sh_link as usize).ok()?;
+        self.raw_section_data(strtab).ok()
+    }
+
+    /// Get dynamic symbol table entry.
+    ///
+    /// The table is located by `DT_SYMTAB` entry, like the dynamic linker does.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `index`   - given symbol index.
+    ///
+    /// # Returns
+    /// - Symbol - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn dynamic_symbol(&self, entries: &[Elf64_Dyn], index: usize) -> Result<Elf64_Sym> {
+        let addr   = Self::dynamic_value(entries, DynamicTag::Symtab)
+            .ok_or(ElfError::Malformed("Dynamic section has no DT_SYMTAB entry"))?;
+        let offset = self
+            .vaddr_to_offset(addr)
+            .and_then(|base| base.checked_add((index as u64).checked_mul(self.sym_size() as u64)?))
+            .ok_or(ElfError::Truncated)?;
+
+        match self.class {
+            ELFCLASS32 => Ok(read_at::<Elf32_Sym>(self.contents, offset)?.into()),
+            _          => read_at::<Elf64_Sym>(self.contents, offset),
+        }
+    }
+
+    /// Get name of dynamic symbol.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `index`   - given symbol index.
+    ///
+    /// # Returns
+    /// - Symbol name - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn dynamic_symbol_name(&self, entries: &[Elf64_Dyn], index: usize) -> Result<&'a str> {
+        let strtab = self
+            .dynamic_strtab(entries)
+            .ok_or(ElfError::Malformed("Dynamic section has no string table"))?;
+        let sym = self.dynamic_symbol(entries, index)?;
+        cstr(strtab, sym.st_name as usize)
+    }
+
+    /// Get bytes of table referenced by dynamic entry or section.
+    ///
+    /// Dynamic entries give no size of the table, so it is computed from the
+    /// table contents: hash tables are sized from their headers, the version
+    /// symbol table from the number of dynamic symbols, and the version
+    /// definition and needed tables by walking their entries.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `tag`     - given dynamic entry tag of the table address.
+    /// - `sh_type` - given section type of the table.
+    ///
+    /// # Returns
+    /// - Table bytes, `None` if there is no table - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn dynamic_table_data(
+        &self,
+        entries: &[Elf64_Dyn],
+        tag: DynamicTag,
+        sh_type: SectionType,
+    ) -> Result<Option<&'a [u8]>> {
+        if let Some(addr) = Self::dynamic_value(entries, tag) {
+            let offset = self.vaddr_to_offset(addr).ok_or(ElfError::Truncated)?;
+            let size   = self.dynamic_table_size(entries, tag, offset)?;
+            return self.bytes(offset, size).map(Some);
+        }
+
+        match self.shdrs.iter().find(|s| s.sh_type == u32::from(sh_type)) {
+            Some(shdr) => self.raw_section_data(shdr).map(Some),
+            None       => Ok(None),
+        }
+    }
+
+    /// Get size of table referenced by dynamic entry.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `tag`     - given dynamic entry tag of the table address.
+    /// - `offset`  - given file offset of the table.
+    ///
+    /// # Returns
+    /// - Table size in bytes - in case of success.
+    /// - `ElfError` - if table header can not be read.
+    fn dynamic_table_size(&self, entries: &[Elf64_Dyn], tag: DynamicTag, offset: u64) -> Result<u64> {
+        match tag {
+            DynamicTag::Hash => {
+                let nbucket: u32 = read_at(self.contents, offset)?;
+                let nchain: u32  = read_at(self.contents, offset.saturating_add(4))?;
+                Ok(8 + (nbucket as u64 + nchain as u64) * 4)
+            }
+            DynamicTag::GnuHash => self.gnu_hash_size(offset),
+            DynamicTag::Versym  => Ok(self.dynamic_symbol_count(entries) as u64 * 2),
+            DynamicTag::Verdef  => Ok(self.version_table_size::<Elf64_Verdef, Elf64_Verdaux>(
+                offset, Self::dynamic_value(entries, DynamicTag::Verdefnum), |d| (d.vd_aux, d.vd_cnt),
+            )),
+            DynamicTag::Verneed => Ok(self.version_table_size::<Elf64_Verneed, Elf64_Vernaux>(
+                offset, Self::dynamic_value(entries, DynamicTag::Verneednum), |n| (n.vn_aux, n.vn_cnt),
+            )),
+            _ => self.size().checked_sub(offset).ok_or(ElfError::Truncated),
+        }
+    }
+
+    /// Get size of GNU symbol hash table.
+    ///
+    /// The size of the chains is found by walking the chain of the last
+    /// bucket, the same way as [`GnuHash::parse`] does.
+    ///
+    /// # Parameters
+    /// - `offset` - given file offset of the table.
+    ///
+    /// # Returns
+    /// - Table size in bytes - in case of success.
+    /// - `ElfError` - if table can not be read.
+    fn gnu_hash_size(&self, offset: u64) -> Result<u64> {
+        let nbuckets: u32   = read_at(self.contents, offset)?;
+        let symoffset: u32  = read_at(self.contents, offset.saturating_add(4))?;
+        let bloom_size: u32 = read_at(self.contents, offset.saturating_add(8))?;
+        let wordsize        = if self.is_64() { 8 } else { 4 };
+
+        let buckets_start = 16 + bloom_size as u64 * wordsize;
+        let chains_start  = buckets_start + nbuckets as u64 * 4;
+
+        let buckets = self.bytes(offset.saturating_add(buckets_start), nbuckets as u64 * 4)?;
+        let last    = (0..nbuckets as usize)
+            .filter_map(|i| read::<u32>(buckets, i * 4).ok())
+            .max()
+            .unwrap_or(0);
+
+        if last < symoffset {
+            return Ok(chains_start);
+        }
+
+        let mut index = (last - symoffset) as u64;
+
+        loop {
+            let value: u32 = read_at(self.contents, offset.saturating_add(chains_start + index * 4))?;
+            index += 1;
+
+            if value & 1 != 0 {
+                return Ok(chains_start + index * 4);
+            }
+        }
+    }
+
+    /// Get size of version definition or needed table.
+    ///
+    /// Entries are walked until the given number of them is read, or until
+    /// the last one. Entries that can not be read end the table.
+    ///
+    /// # Parameters
+    /// - `offset` - given file offset of the table.
+    /// - `count`  - given number of entries, if known.
+    /// - `aux`    - given getter of the auxiliary entries offset and number.
+    ///
+    /// # Returns
+    /// - Table size in bytes, up to the end of the farthest entry.
+    fn version_table_size<E: VersionEntry, A: VersionEntry>(
+        &self,
+        offset: u64,
+        count: Option<u64>,
+        aux: impl Fn(&E) -> (u32, u16),
+    ) -> u64 {
+        let mut end   = 0;
+        let mut entry = Some(0u64);
+
+        for _ in 0..count.unwrap_or(u64::MAX) {
+            let Some(entry_offset) = entry.take() else {
+                break;
+            };
+            let Ok(value) = read_at::<E>(self.contents, offset.saturating_add(entry_offset)) else {
+                break;
+            };
+            end = end.max(entry_offset + size_of::<E>() as u64);
+
+            let (aux_offset, aux_count) = aux(&value);
+            let mut aux_offset = entry_offset + aux_offset as u64;
+
+            for _ in 0..aux_count {
+                let Ok(aux) = read_at::<A>(self.contents, offset.saturating_add(aux_offset)) else {
+                    break;
+                };
+                end = end.max(aux_offset + size_of::<A>() as u64);
+
+                match aux.next() {
+                    0    => break,
+                    next => aux_offset += next as u64,
+                }
+            }
+
+            entry = match value.next() {
+                0    => None,
+                next => Some(entry_offset + next as u64),
+            };
+        }
+
+        end
+    }
+
+    /// Get version of dynamic symbol.
+    ///
+    /// Versions defined by the object are looked up for defined symbols,
+    /// versions needed from other objects for all symbols, since copy
+    /// relocated variables are defined, yet have needed versions.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `index`   - given symbol index.
+    /// - `sym`     - given symbol.
+    ///
+    /// # Returns
+    /// - Symbol version, `None` for local, global and base versions, or if
+    ///   there is no versioning information - in case of success.
+    /// - `ElfError` - if version index can not be read.
+    pub fn symbol_version(
+        &self,
+        entries: &[Elf64_Dyn],
+        index: usize,
+        sym: &Elf64_Sym,
+    ) -> Result<Option<SymbolVersion<'a>>> {
+        let Some(versym) = self.dynamic_table_data(entries, DynamicTag::Versym, SectionType::GnuVersym)? else {
+            return Ok(None);
+        };
+
+        let value: u16 = read(versym, index.checked_mul(2).ok_or(ElfError::Truncated)?)?;
+
+        if value == 0 {
+            return Ok(None);
+        }
+
+        let ndx     = value & VERSYM_VERSION;
+        let hidden  = value & VERSYM_HIDDEN != 0;
+        let strtab  = self.dynamic_strtab(entries);
+        let name_at = |offset: u32| {
+            strtab.and_then(|t| cstr(t, offset as usize).ok()).unwrap_or("<corrupt>")
+        };
+        let defined = |name| match hidden {
+            true  => SymbolVersion::Hidden(name),
+            false => SymbolVersion::Default(name),
+        };
+
+        let mut max_vd_ndx = 0;
+
+        if sym.st_shndx as u32 != SHN_UNDEF
+            && value != (VERSYM_HIDDEN | VER_NDX_GLOBAL)
+            && let Some(verdef) = self.dynamic_table_data(entries, DynamicTag::Verdef, SectionType::GnuVerdef)?
+        {
+            for (offset, def) in VerdefIter::new(verdef, 0) {
+                max_vd_ndx = max_vd_ndx.max(def.vd_ndx & VERSYM_VERSION);
+
+                if def.vd_ndx != ndx {
+                    continue;
+                }
+
+                if def.vd_ndx == VER_NDX_GLOBAL && def.vd_flags == VER_FLG_BASE {
+                    return Ok(None);
+                }
+
+                let aux = offset.checked_add(def.vd_aux as usize);
+                let aux = aux.and_then(|aux| read::<Elf64_Verdaux>(verdef, aux).ok());
+
+                // Symbols naming the version itself have no version.
+                if let Some(aux) = aux.filter(|aux| aux.vda_name != sym.st_name) {
+                    return Ok(Some(defined(name_at(aux.vda_name))));
+                }
+                break;
+            }
+        }
+
+        let verneed = self.dynamic_table_data(entries, DynamicTag::Verneed, SectionType::GnuVerneed)?;
+
+        if let Some(verneed) = verneed {
+            for (offset, need) in VerneedIter::new(verneed, 0) {
+                let Some(aux) = offset.checked_add(need.vn_aux as usize) else {
+                    break;
+                };
+
+                for (_, aux) in VernauxIter::new(verneed, aux) {
+                    if aux.vna_other == value {
+                        return Ok(Some(SymbolVersion::Needed(name_at(aux.vna_name), value)));
+                    }
+                }
+            }
+
+            if (max_vd_ndx != 0 || ndx != VER_NDX_GLOBAL) && ndx > max_vd_ndx {
+                return Ok(Some(defined("<corrupt>")));
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Get names of needed libraries.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// Names of `DT_NEEDED` entries in order, names that can not be read are
+    /// skipped.
+    pub fn needed_libraries(&self, entries: &[Elf64_Dyn]) -> Vec<&'a str> {
+        let Some(strtab) = self.dynamic_strtab(entries) else {
+            return Vec::new();
+        };
+
+        entries
+            .iter()
+            .filter(|entry| entry.d_tag == DT_NEEDED as i64)
+            .filter_map(|entry| cstr(strtab, entry.val() as usize).ok())
+            .collect()
+    }
+
+    /// Get versions needed from other objects.
+    ///
+    /// Each needed version lists dynamic symbols bound to it through the
+    /// version symbols table.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// - Needed versions grouped by file, empty if there are no needed
+    ///   versions - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn version_needs(&self, entries: &[Elf64_Dyn]) -> Result<Vec<VersionNeed<'a>>> {
+        let verneed = self.dynamic_table_data(entries, DynamicTag::Verneed, SectionType::GnuVerneed)?;
+
+        let Some(verneed) = verneed else {
+            return Ok(Vec::new());
+        };
+
+        let strtab = self
+            .dynamic_strtab(entries)
+            .ok_or(ElfError::Malformed("Dynamic section has no string table"))?;
+
+        let mut needs = Vec::new();
+
+        for (offset, need) in VerneedIter::new(verneed, 0) {
+            let aux      = offset.checked_add(need.vn_aux as usize).ok_or(ElfError::Truncated)?;
+            let versions = VernauxIter::new(verneed, aux)
+                .take(need.vn_cnt as usize)
+                .map(|(_, aux)| {
+                    Ok(NeededVersion {
+                        name:    cstr(strtab, aux.vna_name as usize)?,
+                        index:   aux.vna_other,
+                        flags:   aux.vna_flags,
+                        symbols: Vec::new(),
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            needs.push(VersionNeed { file: cstr(strtab, need.vn_file as usize)?, versions });
+        }
+
+        let Some(versym) = self.dynamic_table_data(entries, DynamicTag::Versym, SectionType::GnuVersym)? else {
+            return Ok(needs);
+        };
+
+        for index in 1..self.dynamic_symbol_count(entries) {
+            let Ok(value) = read::<u16>(versym, index * 2) else {
+                break;
+            };
+
+            let version = needs
+                .iter_mut()
+                .flat_map(|need| need.versions.iter_mut())
+                .find(|version| version.index & VERSYM_VERSION == value & VERSYM_VERSION);
+
+            if let Some(version) = version {
+                version.symbols.push(self.dynamic_symbol_name(entries, index)?);
+            }
+        }
+
+        Ok(needs)
+    }
+
+    /// Get SysV symbol hash table.
+    ///
+    /// The table referenced by `DT_HASH` entry is used when present, otherwise
+    /// the `SHT_HASH` section is.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// - Hash table, `None` if there is no table - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn sysv_hash(&self, entries: &[Elf64_Dyn]) -> Result<Option<SysvHash<'a>>> {
+        self.dynamic_table_data(entries, DynamicTag::Hash, SectionType::Hash)?
+            .map(SysvHash::parse)
+            .transpose()
+    }
+
+    /// Get GNU symbol hash table.
+    ///
+    /// The table referenced by `DT_GNU_HASH` entry is used when present,
+    /// otherwise the `SHT_GNU_HASH` section is.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// - Hash table, `None` if there is no table - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn gnu_hash(&self, entries: &[Elf64_Dyn]) -> Result<Option<GnuHash<'a>>> {
+        self.dynamic_table_data(entries, DynamicTag::GnuHash, SectionType::GnuHash)?
+            .map(|data| GnuHash::parse(data, self.is_64()))
+            .transpose()
+    }
+
+    /// Get number of dynamic symbols.
+    ///
+    /// The size of `SHT_DYNSYM` section is used when present, otherwise the
+    /// number is taken from symbol hash tables, like the dynamic linker does.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    pub fn dynamic_symbol_count(&self, entries: &[Elf64_Dyn]) -> usize {
+        if let Some(shdr) = self.shdrs.iter().find(|s| s.sh_type == SHT_DYNSYM) {
+            return self.symbol_count(shdr);
+        }
+
+        if let Ok(Some(hash)) = self.sysv_hash(entries) {
+            return hash.nchain() as usize;
+        }
+
+        match self.gnu_hash(entries) {
+            Ok(Some(hash)) => hash.symbol_count() as usize,
+            _              => 0,
+        }
+    }
+
+    /// Look up dynamic symbol by name using symbol hash table.
+    ///
+    /// The GNU hash table is used when present, otherwise the SysV one is.
+    ///
+    /// # Parameters
+    /// - `name` - given symbol name.
+    ///
+    /// # Returns
+    /// - Symbol index and symbol, `None` if there is no such symbol - in case
+    ///   of success.
+    /// - `ElfError` - if there is no hash table or it can not be read.
+    pub fn lookup_dynamic_symbol(&self, name: &str) -> Result<Option<(usize, Elf64_Sym)>> {
+        let entries = self.dynamic()?;
+        let sym_name = |index: u32| self.dynamic_symbol_name(&entries, index as usize).ok();
+
+        let index = if let Some(hash) = self.gnu_hash(&entries)? {
+            hash.lookup(name, sym_name)
+        } else if let Some(hash) = self.sysv_hash(&entries)? {
+            hash.lookup(name, sym_name)
+        } else {
+            return Err(ElfError::Malformed("File has no symbol hash table"));
+        };
+
+        match index {
+            Some(index) => Ok(Some((index as usize, self.dynamic_symbol(&entries, index as usize)?))),
+            None        => Ok(None),
+        }
+    }
+
+    /// Find exported dynamic symbols that can not be found through GNU hash
+    /// table.
+    ///
+    /// Every defined non-local dynamic symbol must be in the chain selected
+    /// by its name hash.
+    ///
+    /// # Returns
+    /// - Indexes of unreachable symbols, empty if there is no GNU hash table
+    ///   - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn unreachable_gnu_hash_symbols(&self) -> Result<Vec<usize>> {
+        let entries = self.dynamic()?;
+        let Some(hash) = self.gnu_hash(&entries)? else {
+            return Ok(Vec::new());
+        };
+
+        let mut unreachable = Vec::new();
+
+        for index in 1..self.dynamic_symbol_count(&entries) {
+            let sym = self.dynamic_symbol(&entries, index)?;
+
+            if sym.st_shndx as u32 == SHN_UNDEF || elf32_st_bind(sym.st_info) == STB_LOCAL {
+                continue;
+            }
+
+            let name = self.dynamic_symbol_name(&entries, index)?;
+
+            if !hash.is_reachable(name, index as u32) {
+                unreachable.push(index);
+            }
+        }
+
+        Ok(unreachable)
+    }
+
+    /// Get section group of `SHT_GROUP` section.
+    ///
+    /// The number of members is derived from `sh_entsize`, as GNU readelf
+    /// does.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Section group - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn section_group(&self, shdr: &Elf64_Shdr) -> Result<SectionGroup<'a>> {
+        if shdr.sh_type != SHT_GROUP {
+            return Err(ElfError::Malformed("Not a section group"));
+        }
+
+        if shdr.sh_entsize == 0 || shdr.sh_entsize > shdr.sh_size {
+            return Err(ElfError::Malformed("Section group has invalid entry size"));
+        }
+
+        let data  = self.raw_section_data(shdr)?;
+        let flags = read::<u32>(data, 0)?;
+        let count = (shdr.sh_size / shdr.sh_entsize - 1) as usize;
+        let end   = count.saturating_mul(4).saturating_add(4).min(data.len());
+
+        Ok(SectionGroup::new(flags, &data[4.min(end)..end]))
+    }
+
+    /// Get notes of `SHT_NOTE` section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    ///
+    /// # Returns
+    /// - Iterator over notes - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn section_notes(&self, shdr: &Elf64_Shdr) -> Result<NoteIter<'a>> {
+        if shdr.sh_type != SHT_NOTE {
+            return Err(ElfError::Malformed("Not a note section"));
+        }
+
+        NoteIter::new(self.raw_section_data(shdr)?, shdr.sh_addralign)
+    }
+
+    /// Get notes of `PT_NOTE` segment.
+    ///
+    /// # Parameters
+    /// - `phdr` - given program header.
+    ///
+    /// # Returns
+    /// - Iterator over notes - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn segment_notes(&self, phdr: &Elf64_Phdr) -> Result<NoteIter<'a>> {
+        if phdr.p_type != PT_NOTE {
+            return Err(ElfError::Malformed("Not a note segment"));
+        }
+
+        NoteIter::new(self.bytes(phdr.p_offset, phdr.p_filesz)?, phdr.p_align)
+    }
+
+    /// Get size of the address for this file class.
+    pub fn word_size(&self) -> usize {
+        match self.class {
+            ELFCLASS32 => 4,
+            _          => 8,
+        }
+    }
+
+    /// Get number of words in packed relative relocation section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given `SHT_RELR` section header.
+    pub fn relr_count(&self, shdr: &Elf64_Shdr) -> usize {
+        shdr.sh_size as usize / self.word_size()
+    }
+
+    /// Decode packed relative relocation section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given `SHT_RELR` section header.
+    ///
+    /// # Returns
+    /// - Iterator over relocated addresses - in case of success.
+    /// - `ElfError` - otherwise.
+    pub fn relr(&self, shdr: &Elf64_Shdr) -> Result<RelrIter<impl Iterator<Item = u64> + 'a>> {
+        if shdr.sh_type != SHT_RELR {
+            return Err(ElfError::Malformed("Not a packed relocation section"));
+        }
+
+        let wordsize = self.word_size();
+        let entries  = self
+            .raw_section_data(shdr)?
+            .chunks_exact(wordsize)
+            .map(|word| word.iter().rev().fold(0, |value, &b| (value << 8) | b as u64));
+
+        Ok(RelrIter::new(entries, wordsize as u64))
+    }
+}
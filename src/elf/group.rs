@@ -0,0 +1,71 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Section group module.
+//!
+//! `SHT_GROUP` section holds a flags word followed by indices of its member
+//! sections. Its `sh_link` refers to the symbol table and `sh_info` to the
+//! symbol whose name is the group signature.
+
+use crate::elf::file::read;
+
+// Section group flags.
+
+/// Group is a COMDAT group: the link editor keeps only one group with the
+/// same signature.
+pub const GRP_COMDAT: u32 = 0x1;
+
+/// Operating system specific flags.
+pub const GRP_MASKOS: u32 = 0x0ff00000;
+
+/// Processor specific flags.
+pub const GRP_MASKPROC: u32 = 0xf0000000;
+
+/// Section group.
+#[derive(Debug, Clone, Copy)]
+pub struct SectionGroup<'a> {
+    /// Group flags.
+    pub flags: u32,
+    /// Member section indices.
+    members: &'a [u8],
+}
+
+impl<'a> SectionGroup<'a> {
+    /// Construct new section group.
+    ///
+    /// # Parameters
+    /// - `flags`   - given group flags.
+    /// - `members` - given bytes of member section indices.
+    pub fn new(flags: u32, members: &'a [u8]) -> Self {
+        SectionGroup { flags, members }
+    }
+
+    /// Get number of member sections.
+    pub fn len(&self) -> usize {
+        self.members.len() / 4
+    }
+
+    /// Check whether group has no member sections.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get iterator over member section indices.
+    pub fn members(&self) -> impl Iterator<Item = u32> + 'a {
+        let members = self.members;
+        (0..members.len() / 4).map(move |i| read::<u32>(members, i * 4).unwrap_or(0))
+    }
+}
@@ -0,0 +1,449 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! ELF symbol hash table module.
+//!
+//! The SysV hash table (`SHT_HASH`, `DT_HASH`) is an array of 32-bit words:
+//! `nbucket`, `nchain`, `nbucket` bucket entries and `nchain` chain entries.
+//! Bucket holds index of the first symbol with given hash modulo `nbucket`,
+//! chain entry of a symbol holds index of the next symbol with the same
+//! bucket. Index 0 (`STN_UNDEF`) terminates the chain.
+//!
+//! The GNU hash table adds a bloom filter in front of the buckets, see
+//! [`GnuHash`].
+
+use crate::elf::file::{read, ElfError, Result};
+
+/// Compute SysV ELF hash of symbol name.
+///
+/// # Parameters
+/// - `name` - given symbol name.
+pub fn elf_hash(name: &[u8]) -> u32 {
+    let mut h: u32 = 0;
+
+    for &c in name {
+        h = (h << 4).wrapping_add(c as u32);
+
+        let g = h & 0xf0000000;
+        if g != 0 {
+            h ^= g >> 24;
+        }
+        h &= !g;
+    }
+
+    h
+}
+
+/// SysV symbol hash table.
+#[derive(Debug, Clone, Copy)]
+pub struct SysvHash<'a> {
+    /// Number of buckets.
+    nbucket: u32,
+    /// Number of chain entries, which is equal to number of symbols.
+    nchain: u32,
+    /// Bucket and chain arrays.
+    data: &'a [u8],
+}
+
+impl<'a> SysvHash<'a> {
+    /// Parse SysV hash table.
+    ///
+    /// # Parameters
+    /// - `data` - given bytes starting with the table. Bytes past the end of
+    ///   the table are ignored.
+    ///
+    /// # Returns
+    /// - `SysvHash` - in case of success.
+    /// - `ElfError` - if table does not fit into `data`.
+    pub fn parse(data: &'a [u8]) -> Result<SysvHash<'a>> {
+        let nbucket: u32 = read(data, 0)?;
+        let nchain: u32  = read(data, 4)?;
+
+        let size = (nbucket as usize)
+            .checked_add(nchain as usize)
+            .and_then(|n| n.checked_mul(4))
+            .ok_or(ElfError::Truncated)?;
+
+        let data = data.get(8..).and_then(|d| d.get(..size)).ok_or(ElfError::Truncated)?;
+        Ok(SysvHash { nbucket, nchain, data })
+    }
+
+    /// Get number of buckets.
+    pub fn nbucket(&self) -> u32 {
+        self.nbucket
+    }
+
+    /// Get number of chain entries.
+    pub fn nchain(&self) -> u32 {
+        self.nchain
+    }
+
+    /// Get first symbol index of bucket.
+    ///
+    /// # Parameters
+    /// - `index` - given bucket index, less than `nbucket`.
+    pub fn bucket(&self, index: u32) -> u32 {
+        read(self.data, index as usize * 4).unwrap_or(0)
+    }
+
+    /// Get next symbol index in chain.
+    ///
+    /// # Parameters
+    /// - `index` - given symbol index.
+    ///
+    /// # Returns
+    /// Next symbol index, 0 at the end of chain or for indexes out of table.
+    pub fn chain(&self, index: u32) -> u32 {
+        match index < self.nchain {
+            true  => read(self.data, (self.nbucket + index) as usize * 4).unwrap_or(0),
+            false => 0,
+        }
+    }
+
+    /// Iterate over symbol indexes of bucket chain.
+    ///
+    /// Iteration stops at the end of chain, at index out of table, or after
+    /// `nchain` indexes, so corrupted looping chains terminate.
+    ///
+    /// # Parameters
+    /// - `bucket` - given bucket index.
+    pub fn chain_iter(&self, bucket: u32) -> impl Iterator<Item = u32> + '_ {
+        core::iter::successors(Some(self.bucket(bucket)), |&i| Some(self.chain(i)))
+            .take_while(|&i| i != 0 && i < self.nchain)
+            .take(self.nchain as usize)
+    }
+
+    /// Look up symbol by name.
+    ///
+    /// Walks the chain of the bucket selected by name hash exactly as the
+    /// dynamic linker does.
+    ///
+    /// # Parameters
+    /// - `name`     - given symbol name.
+    /// - `sym_name` - given function returning name of symbol with index.
+    ///
+    /// # Returns
+    /// - Symbol index - if symbol is found.
+    /// - `None` - otherwise.
+    pub fn lookup<'n>(&self, name: &str, sym_name: impl Fn(u32) -> Option<&'n str>) -> Option<u32> {
+        if self.nbucket == 0 {
+            return None;
+        }
+
+        let bucket = elf_hash(name.as_bytes()) % self.nbucket;
+        self.chain_iter(bucket).find(|&i| sym_name(i) == Some(name))
+    }
+}
+
+/// Compute GNU hash of symbol name.
+///
+/// # Parameters
+/// - `name` - given symbol name.
+pub fn gnu_hash(name: &[u8]) -> u32 {
+    name.iter().fold(5381u32, |h, &c| h.wrapping_mul(33).wrapping_add(c as u32))
+}
+
+/// GNU symbol hash table.
+///
+/// The table (`SHT_GNU_HASH`, `DT_GNU_HASH`) starts with four 32-bit words:
+/// `nbuckets`, `symoffset`, `bloom_size` and `bloom_shift`. They are followed
+/// by the bloom filter of `bloom_size` address-sized words, `nbuckets` bucket
+/// entries and the chain array. Only symbols starting from `symoffset` are
+/// hashed, sorted by bucket. Chain entry holds the symbol hash with the lowest
+/// bit replaced by the end of chain flag.
+#[derive(Debug, Clone, Copy)]
+pub struct GnuHash<'a> {
+    /// Number of buckets.
+    nbuckets: u32,
+    /// Index of the first hashed symbol.
+    symoffset: u32,
+    /// Number of bloom filter words.
+    bloom_size: u32,
+    /// Shift of the second bloom filter hash.
+    bloom_shift: u32,
+    /// Size of bloom filter word in bytes.
+    wordsize: usize,
+    /// Bloom filter words.
+    bloom: &'a [u8],
+    /// Bucket entries.
+    buckets: &'a [u8],
+    /// Chain entries.
+    chains: &'a [u8],
+}
+
+impl<'a> GnuHash<'a> {
+    /// Parse GNU hash table.
+    ///
+    /// The size of the chain array is not stored in the table, it is found by
+    /// walking the chain of the last hashed symbol.
+    ///
+    /// # Parameters
+    /// - `data`  - given bytes starting with the table. Bytes past the end of
+    ///   the table are ignored.
+    /// - `is_64` - given file class flag.
+    ///
+    /// # Returns
+    /// - `GnuHash` - in case of success.
+    /// - `ElfError` - if table does not fit into `data`.
+    pub fn parse(data: &'a [u8], is_64: bool) -> Result<GnuHash<'a>> {
+        let nbuckets: u32    = read(data, 0)?;
+        let symoffset: u32   = read(data, 4)?;
+        let bloom_size: u32  = read(data, 8)?;
+        let bloom_shift: u32 = read(data, 12)?;
+        let wordsize         = if is_64 { 8 } else { 4 };
+
+        let bloom_end = (bloom_size as usize)
+            .checked_mul(wordsize)
+            .and_then(|size| size.checked_add(16))
+            .ok_or(ElfError::Truncated)?;
+        let buckets_end = (nbuckets as usize)
+            .checked_mul(4)
+            .and_then(|size| size.checked_add(bloom_end))
+            .ok_or(ElfError::Truncated)?;
+
+        let bloom   = data.get(16..bloom_end).ok_or(ElfError::Truncated)?;
+        let buckets = data.get(bloom_end..buckets_end).ok_or(ElfError::Truncated)?;
+        let rest    = &data[buckets_end..];
+
+        let mut hash = GnuHash {
+            nbuckets, symoffset, bloom_size, bloom_shift, wordsize, bloom, buckets, chains: &[],
+        };
+
+        let last = (0..nbuckets).map(|i| hash.bucket(i)).max().unwrap_or(0);
+
+        if last >= symoffset {
+            let mut index = (last - symoffset) as usize;
+
+            loop {
+                let value: u32 = read(rest, index * 4)?;
+
+                if value & 1 != 0 {
+                    break;
+                }
+                index += 1;
+            }
+
+            hash.chains = &rest[..(index + 1) * 4];
+        }
+
+        Ok(hash)
+    }
+
+    /// Get number of buckets.
+    pub fn nbuckets(&self) -> u32 {
+        self.nbuckets
+    }
+
+    /// Get index of the first hashed symbol.
+    pub fn symoffset(&self) -> u32 {
+        self.symoffset
+    }
+
+    /// Get number of bloom filter words.
+    pub fn bloom_size(&self) -> u32 {
+        self.bloom_size
+    }
+
+    /// Get shift of the second bloom filter hash.
+    pub fn bloom_shift(&self) -> u32 {
+        self.bloom_shift
+    }
+
+    /// Get number of chain entries.
+    pub fn nchains(&self) -> u32 {
+        (self.chains.len() / 4) as u32
+    }
+
+    /// Get number of symbols covered by the table, including the unhashed
+    /// symbols before `symoffset`.
+    pub fn symbol_count(&self) -> u32 {
+        match self.chains.is_empty() {
+            true  => self.symoffset,
+            false => self.symoffset.saturating_add(self.nchains()),
+        }
+    }
+
+    /// Get bloom filter word.
+    ///
+    /// # Parameters
+    /// - `index` - given word index, less than `bloom_size`.
+    pub fn bloom_word(&self, index: u32) -> u64 {
+        let offset = index as usize * self.wordsize;
+
+        match self.wordsize {
+            8 => read::<u64>(self.bloom, offset).unwrap_or(0),
+            _ => read::<u32>(self.bloom, offset).unwrap_or(0) as u64,
+        }
+    }
+
+    /// Get first symbol index of bucket.
+    ///
+    /// # Parameters
+    /// - `index` - given bucket index, less than `nbuckets`.
+    ///
+    /// # Returns
+    /// Symbol index, 0 for empty bucket.
+    pub fn bucket(&self, index: u32) -> u32 {
+        read(self.buckets, index as usize * 4).unwrap_or(0)
+    }
+
+    /// Get chain entry of symbol.
+    ///
+    /// # Parameters
+    /// - `index` - given symbol index.
+    ///
+    /// # Returns
+    /// - Symbol hash with the end of chain flag - if symbol is hashed.
+    /// - `None` - otherwise.
+    pub fn chain(&self, index: u32) -> Option<u32> {
+        let offset = index.checked_sub(self.symoffset)? as usize * 4;
+        read(self.chains, offset).ok()
+    }
+
+    /// Iterate over symbol indexes of bucket chain.
+    ///
+    /// # Parameters
+    /// - `bucket` - given bucket index.
+    pub fn chain_iter(&self, bucket: u32) -> impl Iterator<Item = u32> + '_ {
+        let first = Some(self.bucket(bucket)).filter(|&i| i != 0 && self.chain(i).is_some());
+
+        core::iter::successors(first, |&i| match self.chain(i)? & 1 {
+            0 => i.checked_add(1).filter(|&next| self.chain(next).is_some()),
+            _ => None,
+        })
+    }
+
+    /// Check whether bloom filter may contain symbol with given hash.
+    ///
+    /// # Parameters
+    /// - `hash` - given GNU hash of symbol name.
+    ///
+    /// # Returns
+    /// - `false` - if symbol is certainly not in the table.
+    /// - `true` - otherwise.
+    pub fn bloom_check(&self, hash: u32) -> bool {
+        if self.bloom_size == 0 {
+            return false;
+        }
+
+        let bits  = self.wordsize as u32 * 8;
+        let word  = self.bloom_word((hash / bits) % self.bloom_size);
+        let hash2 = hash.checked_shr(self.bloom_shift).unwrap_or(0);
+        let mask  = (1u64 << (hash % bits)) | (1u64 << (hash2 % bits));
+
+        word & mask == mask
+    }
+
+    /// Look up symbol by name.
+    ///
+    /// Checks the bloom filter, then walks the chain of the bucket selected by
+    /// name hash comparing hashes before names, exactly as the dynamic linker
+    /// does.
+    ///
+    /// # Parameters
+    /// - `name`     - given symbol name.
+    /// - `sym_name` - given function returning name of symbol with index.
+    ///
+    /// # Returns
+    /// - Symbol index - if symbol is found.
+    /// - `None` - otherwise.
+    pub fn lookup<'n>(&self, name: &str, sym_name: impl Fn(u32) -> Option<&'n str>) -> Option<u32> {
+        let hash = gnu_hash(name.as_bytes());
+
+        if self.nbuckets == 0 || !self.bloom_check(hash) {
+            return None;
+        }
+
+        self.chain_iter(hash % self.nbuckets).find(|&i| {
+            self.chain(i).is_some_and(|h| (h | 1) == (hash | 1)) && sym_name(i) == Some(name)
+        })
+    }
+
+    /// Check whether symbol can be reached through the table.
+    ///
+    /// Unlike `lookup` this does not stop at the first symbol with the same
+    /// name, so symbols with several versions are handled as well.
+    ///
+    /// # Parameters
+    /// - `name`  - given symbol name.
+    /// - `index` - given symbol index.
+    ///
+    /// # Returns
+    /// - `true` - if bloom filter accepts the name and the symbol is in the
+    ///   chain of its bucket with matching hash.
+    /// - `false` - otherwise.
+    pub fn is_reachable(&self, name: &str, index: u32) -> bool {
+        let hash = gnu_hash(name.as_bytes());
+
+        if self.nbuckets == 0 || !self.bloom_check(hash) {
+            return false;
+        }
+
+        self.chain_iter(hash % self.nbuckets)
+            .any(|i| i == index && self.chain(i).is_some_and(|h| (h | 1) == (hash | 1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec::Vec;
+
+    /// Build 64-bit GNU hash table with one bucket and all bloom bits set.
+    fn gnu_table(symoffset: u32, bucket: u32, chains: &[u32]) -> Vec<u8> {
+        [1, symoffset, 1, 0, u32::MAX, u32::MAX, bucket]
+            .iter()
+            .chain(chains)
+            .flat_map(|word| word.to_le_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn gnu_lookup() {
+        let names = ["", "foo", "bar"];
+        let data  = gnu_table(1, 1, &[gnu_hash(b"foo") & !1, gnu_hash(b"bar") | 1]);
+        let hash  = GnuHash::parse(&data, true).unwrap();
+        let name  = |i: u32| names.get(i as usize).copied();
+
+        assert_eq!(hash.symbol_count(), 3);
+        assert_eq!(hash.chain_iter(0).collect::<Vec<_>>(), [1, 2]);
+        assert_eq!(hash.lookup("foo", name), Some(1));
+        assert_eq!(hash.lookup("bar", name), Some(2));
+        assert_eq!(hash.lookup("baz", name), None);
+        assert!(hash.is_reachable("bar", 2));
+    }
+
+    #[test]
+    fn gnu_chain_at_the_last_index() {
+        // Chain continues past the last representable symbol index.
+        let data = gnu_table(u32::MAX, u32::MAX, &[2, 3]);
+        let hash = GnuHash::parse(&data, true).unwrap();
+
+        assert_eq!(hash.symbol_count(), u32::MAX);
+        assert_eq!(hash.chain_iter(0).collect::<Vec<_>>(), [u32::MAX]);
+        assert_eq!(hash.lookup("foo", |_| Some("foo")), None);
+        assert!(!hash.is_reachable("foo", u32::MAX));
+    }
+
+    #[test]
+    fn hash_functions() {
+        assert_eq!(elf_hash(b""), 0);
+        assert_eq!(elf_hash(b"printf"), 0x077905a6);
+        assert_eq!(gnu_hash(b""), 5381);
+        assert_eq!(gnu_hash(b"printf"), 0x156b2bb8);
+    }
+}
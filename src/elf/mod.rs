@@ -16,12 +16,81 @@
 
 //! ELF module.
 
+use core::fmt;
+
+/// Define enumeration of raw ELF field values.
+///
+/// Each known value is listed with its constant and GNU readelf name, any
+/// other value is kept in the `Unknown` variant. Conversions from and to the
+/// raw value and `name()` are generated, `Display` is implemented by each
+/// enumeration since unknown values are displayed differently.
+macro_rules! elf_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $raw:ty {
+            $($(#[$vmeta:meta])* $variant:ident = $value:path => $str:literal,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+            /// Value without a known name, e.g. reserved or processor-specific.
+            Unknown($raw),
+        }
+
+        impl $name {
+            /// Get GNU readelf name of known value.
+            ///
+            /// # Returns
+            /// - Name - if value is known.
+            /// - `None` - for `Unknown` values.
+            pub fn name(&self) -> Option<&'static str> {
+                match self {
+                    $($name::$variant => Some($str),)*
+                    $name::Unknown(_) => None,
+                }
+            }
+        }
+
+        impl From<$raw> for $name {
+            fn from(raw: $raw) -> Self {
+                match raw {
+                    $(raw if raw == <$raw>::from($value) => $name::$variant,)*
+                    raw => $name::Unknown(raw),
+                }
+            }
+        }
+
+        impl From<$name> for $raw {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => <$raw>::from($value),)*
+                    $name::Unknown(raw) => raw,
+                }
+            }
+        }
+    };
+}
+
+pub mod android;
+pub mod coredump;
 pub mod dynsec;
 pub mod elfhdr;
+pub mod file;
+pub mod group;
+pub mod hash;
+pub mod note;
 pub mod progtbl;
 pub mod reloc;
+pub mod relocate;
 pub mod sechdr;
+pub mod source;
+pub mod strtab;
 pub mod symtbl;
+pub mod table;
+pub mod version;
 
 // ELF 32 types.
 pub type Elf32_Addr  = u32;
@@ -29,3 +98,25 @@ pub type Elf32_Half  = u16;
 pub type Elf32_Off   = u32;
 pub type Elf32_Sword = i32;
 pub type Elf32_Word  = u32;
+
+// ELF 64 types.
+pub type Elf64_Addr   = u64;
+pub type Elf64_Half   = u16;
+pub type Elf64_Off    = u64;
+pub type Elf64_Sword  = i32;
+pub type Elf64_Sxword = i64;
+pub type Elf64_Word   = u32;
+pub type Elf64_Xword  = u64;
+
+/// Hexadecimal value displayed the way `printf("%#x")` does, without prefix
+/// for zero.
+pub(crate) struct AltHex(pub u64);
+
+impl fmt::Display for AltHex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            0 => write!(f, "0"),
+            v => write!(f, "{:#x}", v),
+        }
+    }
+}
@@ -0,0 +1,461 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! ELF note module.
+//!
+//! Notes of `SHT_NOTE` sections and `PT_NOTE` segments are a sequence of
+//! entries, each made of a header, the owner name and the descriptor. Name and
+//! descriptor are padded to the note alignment, which is 4 bytes, or 8 bytes
+//! for 64-bit objects following the gABI.
+
+use core::mem::size_of;
+
+use crate::elf::file::{read, ElfError, Result};
+use crate::elf::Elf64_Word;
+
+/// ELF note header.
+///
+/// The layout is the same for both file classes.
+#[repr(C, packed)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Elf64_Nhdr {
+    /// Size of the owner name including the terminating null byte.
+    pub n_namesz: Elf64_Word,
+    /// Size of the descriptor.
+    pub n_descsz: Elf64_Word,
+    /// Note type, its meaning depends on the owner name.
+    pub n_type: Elf64_Word,
+}
+
+/// ELF 32 note header.
+pub type Elf32_Nhdr = Elf64_Nhdr;
+
+// Owner names of notes.
+
+/// Owner name of GNU notes.
+pub const ELF_NOTE_GNU: &str = "GNU";
+
+// GNU note type enumeration.
+
+/// The descriptor holds the OS and the earliest kernel version the object
+/// runs on.
+pub const NT_GNU_ABI_TAG: u32 = 1;
+/// The descriptor holds hardware capabilities supplied by the object.
+pub const NT_GNU_HWCAP: u32 = 2;
+/// The descriptor holds the unique build ID bitstring.
+pub const NT_GNU_BUILD_ID: u32 = 3;
+/// The descriptor holds the version of the gold linker.
+pub const NT_GNU_GOLD_VERSION: u32 = 4;
+/// The descriptor holds program properties.
+pub const NT_GNU_PROPERTY_TYPE_0: u32 = 5;
+/// The descriptor holds build attributes of an address range.
+pub const NT_GNU_BUILD_ATTRIBUTE_OPEN: u32 = 0x100;
+/// The descriptor holds build attributes of a function.
+pub const NT_GNU_BUILD_ATTRIBUTE_FUNC: u32 = 0x101;
+
+// Generic note type enumeration for objects other than core files.
+
+/// The descriptor holds version string.
+pub const NT_VERSION: u32 = 1;
+/// The descriptor holds architecture string.
+pub const NT_ARCH: u32 = 2;
+/// The descriptor holds Go build ID.
+pub const NT_GO_BUILDID: u32 = 4;
+/// The descriptor holds JSON package metadata.
+pub const FDO_PACKAGING_METADATA: u32 = 0xcafe1a7e;
+
+// NT_GNU_ABI_TAG OS enumeration.
+
+/// Linux.
+pub const GNU_ABI_TAG_LINUX: u32 = 0;
+/// GNU Hurd.
+pub const GNU_ABI_TAG_HURD: u32 = 1;
+/// Solaris.
+pub const GNU_ABI_TAG_SOLARIS: u32 = 2;
+/// FreeBSD.
+pub const GNU_ABI_TAG_FREEBSD: u32 = 3;
+/// NetBSD.
+pub const GNU_ABI_TAG_NETBSD: u32 = 4;
+/// Syllable.
+pub const GNU_ABI_TAG_SYLLABLE: u32 = 5;
+/// Google Native Client.
+pub const GNU_ABI_TAG_NACL: u32 = 6;
+
+// GNU program property type enumeration.
+
+/// Stack size the program needs.
+pub const GNU_PROPERTY_STACK_SIZE: u32 = 1;
+/// Copy relocations must not be used against protected symbols.
+pub const GNU_PROPERTY_NO_COPY_ON_PROTECTED: u32 = 2;
+/// Start of properties combined with bitwise AND when linking.
+pub const GNU_PROPERTY_UINT32_AND_LO: u32 = 0xb0000000;
+/// End of properties combined with bitwise AND when linking.
+pub const GNU_PROPERTY_UINT32_AND_HI: u32 = 0xb0007fff;
+/// Start of properties combined with bitwise OR when linking.
+pub const GNU_PROPERTY_UINT32_OR_LO: u32 = 0xb0008000;
+/// End of properties combined with bitwise OR when linking.
+pub const GNU_PROPERTY_UINT32_OR_HI: u32 = 0xb000ffff;
+/// Features the program needs from the run-time.
+pub const GNU_PROPERTY_1_NEEDED: u32 = GNU_PROPERTY_UINT32_OR_LO;
+/// Start of processor-specific properties.
+pub const GNU_PROPERTY_LOPROC: u32 = 0xc0000000;
+/// End of processor-specific properties.
+pub const GNU_PROPERTY_HIPROC: u32 = 0xdfffffff;
+/// Start of application-specific properties.
+pub const GNU_PROPERTY_LOUSER: u32 = 0xe0000000;
+/// End of application-specific properties.
+pub const GNU_PROPERTY_HIUSER: u32 = 0xffffffff;
+
+/// Program accesses external data and functions indirectly.
+pub const GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS: u32 = 1;
+
+// x86 program property type enumeration.
+
+/// Obsolete ISA the program uses.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_USED: u32 = 0xc0000000;
+/// Obsolete ISA the program needs.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_NEEDED: u32 = 0xc0000001;
+/// Start of x86 properties combined with bitwise AND when linking.
+pub const GNU_PROPERTY_X86_UINT32_AND_LO: u32 = 0xc0000002;
+/// Start of x86 properties combined with bitwise OR when linking.
+pub const GNU_PROPERTY_X86_UINT32_OR_LO: u32 = 0xc0008000;
+/// Start of x86 properties combined with bitwise OR when linking, and with
+/// bitwise AND when some input lacks them.
+pub const GNU_PROPERTY_X86_UINT32_OR_AND_LO: u32 = 0xc0010000;
+/// Obsolete ISA the program needs, second version.
+pub const GNU_PROPERTY_X86_COMPAT_2_ISA_1_NEEDED: u32 = GNU_PROPERTY_X86_UINT32_OR_LO;
+/// Obsolete ISA the program uses, second version.
+pub const GNU_PROPERTY_X86_COMPAT_2_ISA_1_USED: u32 = GNU_PROPERTY_X86_UINT32_OR_AND_LO;
+/// Control-flow enforcement features supported by all inputs.
+pub const GNU_PROPERTY_X86_FEATURE_1_AND: u32 = GNU_PROPERTY_X86_UINT32_AND_LO;
+/// Features the program needs.
+pub const GNU_PROPERTY_X86_FEATURE_2_NEEDED: u32 = GNU_PROPERTY_X86_UINT32_OR_LO + 1;
+/// ISA level the program needs.
+pub const GNU_PROPERTY_X86_ISA_1_NEEDED: u32 = GNU_PROPERTY_X86_UINT32_OR_LO + 2;
+/// Features the program uses.
+pub const GNU_PROPERTY_X86_FEATURE_2_USED: u32 = GNU_PROPERTY_X86_UINT32_OR_AND_LO + 1;
+/// ISA level the program uses.
+pub const GNU_PROPERTY_X86_ISA_1_USED: u32 = GNU_PROPERTY_X86_UINT32_OR_AND_LO + 2;
+
+// GNU_PROPERTY_X86_FEATURE_1_AND bits.
+
+/// Indirect branch tracking.
+pub const GNU_PROPERTY_X86_FEATURE_1_IBT: u32 = 1 << 0;
+/// Shadow stack.
+pub const GNU_PROPERTY_X86_FEATURE_1_SHSTK: u32 = 1 << 1;
+/// Linear address masking with 48-bit user addresses.
+pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U48: u32 = 1 << 2;
+/// Linear address masking with 57-bit user addresses.
+pub const GNU_PROPERTY_X86_FEATURE_1_LAM_U57: u32 = 1 << 3;
+
+// GNU_PROPERTY_X86_ISA_1_USED and GNU_PROPERTY_X86_ISA_1_NEEDED bits.
+
+/// x86-64 baseline ISA level.
+pub const GNU_PROPERTY_X86_ISA_1_BASELINE: u32 = 1 << 0;
+/// x86-64-v2 ISA level.
+pub const GNU_PROPERTY_X86_ISA_1_V2: u32 = 1 << 1;
+/// x86-64-v3 ISA level.
+pub const GNU_PROPERTY_X86_ISA_1_V3: u32 = 1 << 2;
+/// x86-64-v4 ISA level.
+pub const GNU_PROPERTY_X86_ISA_1_V4: u32 = 1 << 3;
+
+// GNU_PROPERTY_X86_COMPAT_ISA_1_USED and GNU_PROPERTY_X86_COMPAT_ISA_1_NEEDED
+// bits.
+
+/// i486 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_486: u32 = 1 << 0;
+/// Pentium instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_586: u32 = 1 << 1;
+/// Pentium Pro instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_686: u32 = 1 << 2;
+/// SSE instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_SSE: u32 = 1 << 3;
+/// SSE2 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_SSE2: u32 = 1 << 4;
+/// SSE3 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_SSE3: u32 = 1 << 5;
+/// SSSE3 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_SSSE3: u32 = 1 << 6;
+/// SSE4.1 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_SSE4_1: u32 = 1 << 7;
+/// SSE4.2 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_SSE4_2: u32 = 1 << 8;
+/// AVX instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX: u32 = 1 << 9;
+/// AVX2 instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX2: u32 = 1 << 10;
+/// AVX512F instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512F: u32 = 1 << 11;
+/// AVX512CD instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512CD: u32 = 1 << 12;
+/// AVX512ER instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512ER: u32 = 1 << 13;
+/// AVX512PF instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512PF: u32 = 1 << 14;
+/// AVX512VL instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512VL: u32 = 1 << 15;
+/// AVX512DQ instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512DQ: u32 = 1 << 16;
+/// AVX512BW instructions.
+pub const GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512BW: u32 = 1 << 17;
+
+// GNU_PROPERTY_X86_FEATURE_2_USED and GNU_PROPERTY_X86_FEATURE_2_NEEDED bits.
+
+/// General purpose registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_X86: u32 = 1 << 0;
+/// x87 registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_X87: u32 = 1 << 1;
+/// MMX registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_MMX: u32 = 1 << 2;
+/// XMM registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_XMM: u32 = 1 << 3;
+/// YMM registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_YMM: u32 = 1 << 4;
+/// ZMM registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_ZMM: u32 = 1 << 5;
+/// FXSAVE and FXRSTOR instructions.
+pub const GNU_PROPERTY_X86_FEATURE_2_FXSR: u32 = 1 << 6;
+/// XSAVE instructions.
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVE: u32 = 1 << 7;
+/// XSAVEOPT instruction.
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT: u32 = 1 << 8;
+/// XSAVEC instruction.
+pub const GNU_PROPERTY_X86_FEATURE_2_XSAVEC: u32 = 1 << 9;
+/// Tile registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_TMM: u32 = 1 << 10;
+/// Mask registers.
+pub const GNU_PROPERTY_X86_FEATURE_2_MASK: u32 = 1 << 11;
+
+// AArch64 program property type enumeration.
+
+/// Branch protection features supported by all inputs.
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_AND: u32 = 0xc0000000;
+
+// GNU_PROPERTY_AARCH64_FEATURE_1_AND bits.
+
+/// Branch target identification.
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_BTI: u32 = 1 << 0;
+/// Pointer authentication.
+pub const GNU_PROPERTY_AARCH64_FEATURE_1_PAC: u32 = 1 << 1;
+
+/// Round size up to the alignment, which is a power of two.
+fn align_up(size: usize, align: usize) -> Option<usize> {
+    Some(size.checked_add(align - 1)? & !(align - 1))
+}
+
+/// Read little-endian 32-bit word.
+fn word(data: &[u8], offset: usize) -> Result<u32> {
+    read::<u32>(data, offset)
+}
+
+/// ELF note entry.
+#[derive(Debug, Clone, Copy)]
+pub struct Note<'a> {
+    /// Note type.
+    pub n_type: u32,
+    /// Owner name without the terminating null byte.
+    pub name: &'a [u8],
+    /// Descriptor.
+    pub desc: &'a [u8],
+    /// Offset of the note in the notes data.
+    pub offset: usize,
+}
+
+impl Note<'_> {
+    /// Check whether note is owned by GNU.
+    pub fn is_gnu(&self) -> bool {
+        self.name == ELF_NOTE_GNU.as_bytes()
+    }
+
+    /// Read descriptor word.
+    ///
+    /// # Parameters
+    /// - `index` - given index of the 32-bit word.
+    pub fn desc_word(&self, index: usize) -> Option<u32> {
+        word(self.desc, index * 4).ok()
+    }
+}
+
+/// Iterator over notes.
+///
+/// Iteration stops after the first malformed note, which is reported as an
+/// error.
+pub struct NoteIter<'a> {
+    /// Notes data.
+    data: &'a [u8],
+    /// Offset of the next note.
+    pos: usize,
+    /// Alignment of name and descriptor.
+    align: usize,
+}
+
+impl<'a> NoteIter<'a> {
+    /// Create notes iterator.
+    ///
+    /// # Parameters
+    /// - `data`  - given notes data.
+    /// - `align` - given section or segment alignment. Values below 4 are
+    ///   treated as 4.
+    ///
+    /// # Returns
+    /// - `NoteIter` - in case of success.
+    /// - `ElfError` - if alignment is neither 4 nor 8.
+    pub fn new(data: &'a [u8], align: u64) -> Result<NoteIter<'a>> {
+        let align = match align {
+            0..=4 => 4,
+            8     => 8,
+            _     => return Err(ElfError::Malformed("Corrupt note: alignment is not 4 or 8")),
+        };
+
+        Ok(NoteIter { data, pos: 0, align })
+    }
+
+    /// Read note at the current position.
+    fn read_note(&self) -> Result<(Note<'a>, usize)> {
+        let hdr: Elf64_Nhdr = read(self.data, self.pos)?;
+        let namesz = hdr.n_namesz as usize;
+        let descsz = hdr.n_descsz as usize;
+
+        let malformed = ElfError::Malformed("Note with invalid namesz and/or descsz found");
+        let name_pos  = self.pos + size_of::<Elf64_Nhdr>();
+        let desc_pos  = name_pos
+            .checked_add(namesz)
+            .and_then(|end| align_up(end, self.align))
+            .ok_or(malformed.clone())?;
+        let next = desc_pos
+            .checked_add(descsz)
+            .and_then(|end| align_up(end, self.align))
+            .filter(|&next| next <= self.data.len())
+            .ok_or(malformed)?;
+
+        let name = &self.data[name_pos..name_pos + namesz];
+        let name = match name.iter().position(|&b| b == 0) {
+            Some(len) => &name[..len],
+            None      => name,
+        };
+
+        let note = Note {
+            n_type: hdr.n_type,
+            name,
+            desc: &self.data[desc_pos..desc_pos + descsz],
+            offset: self.pos,
+        };
+
+        Ok((note, next))
+    }
+}
+
+impl<'a> Iterator for NoteIter<'a> {
+    type Item = Result<Note<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.data.len() {
+            return None;
+        }
+
+        match self.read_note() {
+            Ok((note, next)) => {
+                self.pos = next;
+                Some(Ok(note))
+            }
+            Err(err) => {
+                self.pos = self.data.len();
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// GNU program property.
+#[derive(Debug, Clone, Copy)]
+pub struct GnuProperty<'a> {
+    /// Property type.
+    pub pr_type: u32,
+    /// Property data.
+    pub data: &'a [u8],
+}
+
+impl GnuProperty<'_> {
+    /// Read property data as 32-bit word.
+    ///
+    /// # Returns
+    /// - Data value - if data size is 4 bytes.
+    /// - `None` - otherwise.
+    pub fn word(&self) -> Option<u32> {
+        match self.data.len() {
+            4 => word(self.data, 0).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Iterator over properties of `NT_GNU_PROPERTY_TYPE_0` note.
+///
+/// Property data is padded to 4 bytes in 32-bit objects and to 8 bytes in
+/// 64-bit objects.
+pub struct GnuPropertyIter<'a> {
+    /// Note descriptor.
+    desc: &'a [u8],
+    /// Offset of the next property.
+    pos: usize,
+    /// Alignment of property data.
+    align: usize,
+    /// Whether malformed property was met.
+    failed: bool,
+}
+
+impl<'a> GnuPropertyIter<'a> {
+    /// Create properties iterator.
+    ///
+    /// # Parameters
+    /// - `desc`  - given note descriptor.
+    /// - `is_64` - given file class flag.
+    pub fn new(desc: &'a [u8], is_64: bool) -> GnuPropertyIter<'a> {
+        GnuPropertyIter { desc, pos: 0, align: if is_64 { 8 } else { 4 }, failed: false }
+    }
+
+    /// Get offset of the next property in the descriptor.
+    ///
+    /// After an error this is the offset of the malformed property.
+    pub fn offset(&self) -> usize {
+        self.pos
+    }
+}
+
+impl<'a> Iterator for GnuPropertyIter<'a> {
+    type Item = Result<GnuProperty<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.pos >= self.desc.len() {
+            return None;
+        }
+
+        let (Ok(pr_type), Ok(datasz)) = (word(self.desc, self.pos), word(self.desc, self.pos + 4))
+        else {
+            self.failed = true;
+            return Some(Err(ElfError::Malformed("Corrupt GNU property descriptor size")));
+        };
+
+        let start = self.pos + 8;
+        let Some(data) = self.desc.get(start..start + datasz as usize) else {
+            self.failed = true;
+            return Some(Err(ElfError::Malformed("Corrupt GNU property data size")));
+        };
+
+        self.pos = align_up(start + data.len(), self.align).unwrap_or(usize::MAX);
+        Some(Ok(GnuProperty { pr_type, data }))
+    }
+}
@@ -16,7 +16,12 @@
 
 //! ELF program table module.
 
-use crate::elf::{Elf32_Addr, Elf32_Off, Elf32_Word};
+use core::fmt;
+
+use crate::elf::{
+    AltHex, Elf32_Addr, Elf32_Off, Elf32_Word, Elf64_Addr, Elf64_Off, Elf64_Word,
+    Elf64_Xword,
+};
 
 /// ELF program table entry struct.
 ///
@@ -27,6 +32,7 @@ use crate::elf::{Elf32_Addr, Elf32_Off, Elf32_Word};
 /// Program headers are meaningful only for executable and shared object files.
 /// A file specifies its own program header size with the ELF
 /// header’s e_phentsize and e_phnum members.
+#[derive(Debug, Clone, Copy)]
 #[repr(C, packed)]
 pub struct Elf32_Phdr {
     /// This member tells what kind of segment this array element describes or
@@ -59,6 +65,53 @@ pub struct Elf32_Phdr {
     pub p_align: Elf32_Word,
 }
 
+/// ELF program table entry struct for the 64-bit class of object files.
+///
+/// It holds the same members as `Elf32_Phdr`, with offsets, addresses and
+/// sizes widened to 64 bits and `p_flags` moved next to `p_type`.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C, packed)]
+pub struct Elf64_Phdr {
+    /// Segment type.
+    pub p_type: Elf64_Word,
+    /// Segment flags.
+    pub p_flags: Elf64_Word,
+    /// Offset of the segment in the file.
+    pub p_offset: Elf64_Off,
+    /// Virtual address of the segment in memory.
+    pub p_vaddr: Elf64_Addr,
+    /// Physical address of the segment, if relevant.
+    pub p_paddr: Elf64_Addr,
+    /// Size of the segment in the file.
+    pub p_filesz: Elf64_Xword,
+    /// Size of the segment in memory.
+    pub p_memsz: Elf64_Xword,
+    /// Segment alignment.
+    pub p_align: Elf64_Xword,
+}
+
+impl From<Elf32_Phdr> for Elf64_Phdr {
+    fn from(phdr: Elf32_Phdr) -> Self {
+        Elf64_Phdr {
+            p_type:   phdr.p_type,
+            p_flags:  phdr.p_flags,
+            p_offset: phdr.p_offset as Elf64_Off,
+            p_vaddr:  phdr.p_vaddr as Elf64_Addr,
+            p_paddr:  phdr.p_paddr as Elf64_Addr,
+            p_filesz: phdr.p_filesz as Elf64_Xword,
+            p_memsz:  phdr.p_memsz as Elf64_Xword,
+            p_align:  phdr.p_align as Elf64_Xword,
+        }
+    }
+}
+
+impl Elf64_Phdr {
+    /// Get segment type.
+    pub fn segment_type(&self) -> SegmentType {
+        SegmentType::from(self.p_type)
+    }
+}
+
 // ELF segment type enumeration.
 
 /// The array element is unused; other members’ values are undefined. This type
@@ -91,7 +144,84 @@ pub const PT_SHLIB: u32 = 5;
 /// it may occur only if the program header table is part of the memory image of
 /// the program. If it is present, it must precede any loadable segment entry.
 pub const PT_PHDR: u32 = 6;
+/// The array element specifies the thread-local storage template.
+pub const PT_TLS: u32 = 7;
+/// This value is used in `e_phnum` when the number of program headers does
+/// not fit into it. The real number is held by `sh_info` of section 0.
+pub const PN_XNUM: u16 = 0xffff;
 /// Values in this inclusive range are reserved for processor-specific
 /// semantics.
 pub const PT_LOPROC: u32 = 0x70000000;
 pub const PT_HIPROC: u32 = 0x7fffffff;
+/// Values in this inclusive range are reserved for operating system-specific
+/// semantics.
+pub const PT_LOOS: u32 = 0x60000000;
+pub const PT_HIOS: u32 = 0x6fffffff;
+/// The array element specifies the location and size of `.eh_frame_hdr`
+/// section.
+pub const PT_GNU_EH_FRAME: u32 = 0x6474e550;
+/// The array element specifies whether stack should be executable.
+pub const PT_GNU_STACK: u32 = 0x6474e551;
+/// The array element specifies the part of the segment which is made
+/// read-only after relocation.
+pub const PT_GNU_RELRO: u32 = 0x6474e552;
+/// The array element specifies the location and size of
+/// `.note.gnu.property` section.
+pub const PT_GNU_PROPERTY: u32 = 0x6474e553;
+/// The array element specifies the location and size of `.sframe` section.
+pub const PT_GNU_SFRAME: u32 = 0x6474e554;
+
+elf_enum! {
+    /// Segment type, `p_type` field of program header.
+    ///
+    /// Processor-specific types depend on the machine, so they are always
+    /// `Unknown`.
+    pub enum SegmentType: u32 {
+        /// Unused entry.
+        Null = PT_NULL => "NULL",
+        /// Loadable segment.
+        Load = PT_LOAD => "LOAD",
+        /// Dynamic linking information.
+        Dynamic = PT_DYNAMIC => "DYNAMIC",
+        /// Program interpreter path name.
+        Interp = PT_INTERP => "INTERP",
+        /// Auxiliary information.
+        Note = PT_NOTE => "NOTE",
+        /// Reserved.
+        Shlib = PT_SHLIB => "SHLIB",
+        /// Program header table.
+        Phdr = PT_PHDR => "PHDR",
+        /// Thread-local storage template.
+        Tls = PT_TLS => "TLS",
+        /// Exception handling frame header.
+        GnuEhFrame = PT_GNU_EH_FRAME => "GNU_EH_FRAME",
+        /// Stack executability.
+        GnuStack = PT_GNU_STACK => "GNU_STACK",
+        /// Read-only after relocation.
+        GnuRelro = PT_GNU_RELRO => "GNU_RELRO",
+        /// GNU program properties.
+        GnuProperty = PT_GNU_PROPERTY => "GNU_PROPERTY",
+        /// Stack trace information.
+        GnuSframe = PT_GNU_SFRAME => "GNU_SFRAME",
+    }
+}
+
+// ELF segment flags enumeration.
+
+/// Segment is executable.
+pub const PF_X: u32 = 0x1;
+/// Segment is writable.
+pub const PF_W: u32 = 0x2;
+/// Segment is readable.
+pub const PF_R: u32 = 0x4;
+
+impl fmt::Display for SegmentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SegmentType::Unknown(raw @ PT_LOPROC..=PT_HIPROC) => write!(f, "LOPROC+{}", AltHex((raw - PT_LOPROC) as u64)),
+            SegmentType::Unknown(raw @ PT_LOOS..=PT_HIOS)     => write!(f, "LOOS+{}", AltHex((raw - PT_LOOS) as u64)),
+            SegmentType::Unknown(raw)                         => write!(f, "<unknown>: {:x}", raw),
+            known => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
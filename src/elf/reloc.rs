@@ -16,7 +16,11 @@
 
 //! ELF relocation module.
 
-use crate::elf::{Elf32_Addr, Elf32_Sword, Elf32_Word};
+use core::fmt;
+
+use crate::elf::{
+    Elf32_Addr, Elf32_Sword, Elf32_Word, Elf64_Addr, Elf64_Sxword, Elf64_Xword,
+};
 
 /// ELF relocation entry struct.
 ///
@@ -27,6 +31,7 @@ use crate::elf::{Elf32_Addr, Elf32_Sword, Elf32_Word};
 /// describes how to modify their section contents, thus allowing executable and
 /// shared object files to hold the right informa- tion for a process’s program
 /// image. Relocation entries are these data.
+#[derive(Debug, Clone, Copy)]
 #[repr(C, packed)]
 pub struct Elf32_Rel {
     /// This member gives the location at which to apply the relocation action.
@@ -49,6 +54,7 @@ pub struct Elf32_Rel {
 }
 
 /// ELF relocation entry struct.
+#[derive(Debug, Clone, Copy)]
 #[repr(C, packed)]
 pub struct Elf32_Rela {
     /// This member gives the location at which to apply the relocation action.
@@ -73,6 +79,143 @@ pub struct Elf32_Rela {
     pub r_addend: Elf32_Sword,
 }
 
+/// ELF relocation entry struct for the 64-bit class of object files.
+#[derive(Debug, Clone, Copy)]
+#[repr(C, packed)]
+pub struct Elf64_Rel {
+    /// Location at which to apply the relocation action.
+    pub r_offset: Elf64_Addr,
+    /// Symbol table index and relocation type, see `elf64_r_sym()` and
+    /// `elf64_r_type()`.
+    pub r_info: Elf64_Xword,
+}
+
+/// ELF relocation entry struct with explicit addend for the 64-bit class of
+/// object files.
+///
+/// Relocations of both classes, with or without explicit addends, are
+/// converted to this layout. Entries without an addend get zero.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C, packed)]
+pub struct Elf64_Rela {
+    /// Location at which to apply the relocation action.
+    pub r_offset: Elf64_Addr,
+    /// Symbol table index and relocation type, see `elf64_r_sym()` and
+    /// `elf64_r_type()`.
+    pub r_info: Elf64_Xword,
+    /// Constant addend used to compute the value to be stored into the
+    /// relocatable field.
+    pub r_addend: Elf64_Sxword,
+}
+
+impl From<Elf32_Rel> for Elf64_Rela {
+    fn from(rel: Elf32_Rel) -> Self {
+        let info = rel.r_info;
+
+        Elf64_Rela {
+            r_offset: rel.r_offset as Elf64_Addr,
+            r_info:   elf64_r_info(elf32_r_sym(info), elf32_r_type(info) as u32),
+            r_addend: 0,
+        }
+    }
+}
+
+impl From<Elf32_Rela> for Elf64_Rela {
+    fn from(rela: Elf32_Rela) -> Self {
+        let info = rela.r_info;
+
+        Elf64_Rela {
+            r_offset: rela.r_offset as Elf64_Addr,
+            r_info:   elf64_r_info(elf32_r_sym(info), elf32_r_type(info) as u32),
+            r_addend: rela.r_addend as Elf64_Sxword,
+        }
+    }
+}
+
+impl From<Elf64_Rel> for Elf64_Rela {
+    fn from(rel: Elf64_Rel) -> Self {
+        Elf64_Rela {
+            r_offset: rel.r_offset,
+            r_info:   rel.r_info,
+            r_addend: 0,
+        }
+    }
+}
+
+/// ELF packed relative relocation entry.
+///
+/// A table of these entries describes a list of `R_*_RELATIVE` relocations
+/// with implicit addends, which is usually the vast majority of relocations
+/// in position-independent code. An entry with the lowest bit clear is an
+/// address: the word at that address is relocated. An entry with the lowest
+/// bit set is a bitmap: its bit `i` (starting from 1) means that the word at
+/// `where + (i - 1) * wordsize` is relocated, where `where` is the address
+/// following the last relocated address covered by the previous entry.
+pub type Elf32_Relr = Elf32_Word;
+pub type Elf64_Relr = Elf64_Xword;
+
+/// Packed relative relocations decoder.
+///
+/// Iterates over addresses described by a table of `Elf32_Relr` or
+/// `Elf64_Relr` entries.
+#[derive(Debug, Clone)]
+pub struct RelrIter<I> {
+    /// Table entries, zero-extended to 64 bits.
+    entries: I,
+    /// Size of word in bytes, 4 or 8.
+    wordsize: u64,
+    /// Address of the word described by the first bit of the next bitmap.
+    next: u64,
+    /// Address of the word described by the current bitmap bit.
+    addr: u64,
+    /// Current bitmap without already handled bits.
+    bitmap: u64,
+}
+
+impl<I: Iterator<Item = u64>> RelrIter<I> {
+    /// Create packed relative relocations decoder.
+    ///
+    /// # Parameters
+    /// - `entries`  - given table entries.
+    /// - `wordsize` - given word size in bytes: 4 for `Elf32_Relr` and 8 for
+    ///   `Elf64_Relr`.
+    pub fn new(entries: I, wordsize: u64) -> Self {
+        RelrIter { entries, wordsize, next: 0, addr: 0, bitmap: 0 }
+    }
+}
+
+impl<I: Iterator<Item = u64>> Iterator for RelrIter<I> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        loop {
+            while self.bitmap != 0 {
+                let set  = self.bitmap & 1 != 0;
+                let addr = self.addr;
+
+                self.bitmap >>= 1;
+                self.addr = self.addr.wrapping_add(self.wordsize);
+
+                if set {
+                    return Some(addr);
+                }
+            }
+
+            let entry = self.entries.next()?;
+
+            if entry & 1 == 0 {
+                self.next = entry.wrapping_add(self.wordsize);
+                return Some(entry);
+            }
+
+            // Each bitmap describes the next (wordsize * 8 - 1) words.
+            self.bitmap = entry >> 1;
+            self.addr   = self.next;
+            self.next   = self.next.wrapping_add((self.wordsize * 8 - 1) * self.wordsize);
+        }
+    }
+}
+
 /// Extracts the symbol index from the relocation info.
 ///
 /// # Parameters
@@ -107,14 +250,59 @@ pub fn elf32_r_info(sym: u32, typ: u8) -> u32 {
     (sym << 8) | (typ as u32)
 }
 
-/// ELF relocation type enumeration.
+/// Extracts the symbol index from the 64-bit relocation info.
+///
+/// # Parameters
+/// - `info` - given relocation info.
+///
+/// # Returns
+/// Symbol index.
+pub fn elf64_r_sym(info: u64) -> u32 {
+    (info >> 32) as u32
+}
+
+/// Extracts the type from the 64-bit relocation info.
+///
+/// # Parameters
+/// - `info` - given relocation info.
 ///
-/// - `A` - This means the addend used to compute the value of the relocatable
-///   field.
-/// - `P` - This means the place (section offset or address) of the storage unit
-///   being relocated (computed using r_offset).
-/// - `S` - This means the value of the symbol whose index resides in the
-///   relocation entry.
+/// # Returns
+/// Relocation type.
+pub fn elf64_r_type(info: u64) -> u32 {
+    (info & 0xffffffff) as u32
+}
+
+/// Combines the symbol index and type into the 64-bit relocation info.
+///
+/// # Parameters
+/// - `sym` - given symbol index.
+/// - `typ` - given relocation type.
+///
+/// # Returns
+/// Relocation info.
+pub fn elf64_r_info(sym: u32, typ: u32) -> u64 {
+    ((sym as u64) << 32) | (typ as u64)
+}
+
+// ELF relocation type enumeration.
+//
+// - `A`   - This means the addend used to compute the value of the relocatable
+//   field.
+// - `B`   - This means the base address at which a shared object has been
+//   loaded into memory during execution. Generally, a shared object file is
+//   built with a 0 base virtual address, but the execution address will be
+//   different.
+// - `G`   - This means the offset into the global offset table at which the
+//   address of the relocation entry’s symbol will reside during execution.
+// - `GOT` - This means the address of the global offset table.
+// - `L`   - This means the place (section offset or address) of the procedure
+//   linkage table entry for a symbol.
+// - `P`   - This means the place (section offset or address) of the storage
+//   unit being relocated (computed using r_offset).
+// - `S`   - This means the value of the symbol whose index resides in the
+//   relocation entry.
+// - `Z`   - This means the size of the symbol whose index resides in the
+//   relocation entry.
 
 /// No relocation is needed.
 pub const R_386_NONE: u32 = 0;
@@ -157,3 +345,264 @@ pub const R_386_GOTOFF: u32 = 9;
 /// relocation normally is _GLOBAL_OFFSET_TABLE_, which additionally instructs
 /// the link editor to build the global offset table.
 pub const R_386_GOTPC: u32 = 10;
+/// Relocation type for a 32-bit PLT address: L + A.
+pub const R_386_32PLT: u32 = 11;
+/// Offset in static TLS block, for dynamic linking.
+pub const R_386_TLS_TPOFF: u32 = 14;
+/// Address of GOT entry for static TLS block offset.
+pub const R_386_TLS_IE: u32 = 15;
+/// GOT entry for static TLS block offset.
+pub const R_386_TLS_GOTIE: u32 = 16;
+/// Offset relative to static TLS block.
+pub const R_386_TLS_LE: u32 = 17;
+/// Direct 32-bit for GNU version of general dynamic thread local data.
+pub const R_386_TLS_GD: u32 = 18;
+/// Direct 32-bit for GNU version of local dynamic thread local data in LE code.
+pub const R_386_TLS_LDM: u32 = 19;
+/// Relocation type for a 16-bit word: S + A.
+pub const R_386_16: u32 = 20;
+/// Relocation type for a 16-bit word: S + A - P.
+pub const R_386_PC16: u32 = 21;
+/// Relocation type for a byte: S + A.
+pub const R_386_8: u32 = 22;
+/// Relocation type for a byte: S + A - P.
+pub const R_386_PC8: u32 = 23;
+/// Direct 32-bit for general dynamic thread local data.
+pub const R_386_TLS_GD_32: u32 = 24;
+/// Tag for pushl in GD TLS code.
+pub const R_386_TLS_GD_PUSH: u32 = 25;
+/// Relocation for call to `__tls_get_addr()`.
+pub const R_386_TLS_GD_CALL: u32 = 26;
+/// Tag for popl in GD TLS code.
+pub const R_386_TLS_GD_POP: u32 = 27;
+/// Direct 32-bit for local dynamic thread local data in LE code.
+pub const R_386_TLS_LDM_32: u32 = 28;
+/// Tag for pushl in LDM TLS code.
+pub const R_386_TLS_LDM_PUSH: u32 = 29;
+/// Relocation for call to `__tls_get_addr()` in LDM code.
+pub const R_386_TLS_LDM_CALL: u32 = 30;
+/// Tag for popl in LDM TLS code.
+pub const R_386_TLS_LDM_POP: u32 = 31;
+/// Offset relative to TLS block.
+pub const R_386_TLS_LDO_32: u32 = 32;
+/// GOT entry for negated static TLS block offset.
+pub const R_386_TLS_IE_32: u32 = 33;
+/// Negated offset relative to static TLS block.
+pub const R_386_TLS_LE_32: u32 = 34;
+/// ID of module containing symbol.
+pub const R_386_TLS_DTPMOD32: u32 = 35;
+/// Offset in TLS block.
+pub const R_386_TLS_DTPOFF32: u32 = 36;
+/// Negated offset in static TLS block.
+pub const R_386_TLS_TPOFF32: u32 = 37;
+/// Relocation type for a 32-bit symbol size: Z + A.
+pub const R_386_SIZE32: u32 = 38;
+/// GOT offset for TLS descriptor.
+pub const R_386_TLS_GOTDESC: u32 = 39;
+/// Marker of call through TLS descriptor for relaxation.
+pub const R_386_TLS_DESC_CALL: u32 = 40;
+/// TLS descriptor containing pointer to code and to argument.
+pub const R_386_TLS_DESC: u32 = 41;
+/// Adjust indirectly by program base.
+pub const R_386_IRELATIVE: u32 = 42;
+/// Load from 32-bit GOT entry, relaxable.
+pub const R_386_GOT32X: u32 = 43;
+
+elf_enum! {
+    /// Intel 80386 relocation type, `ELF32_R_TYPE` of `r_info` field of
+    /// relocation.
+    pub enum Reloc386: u32 {
+        /// No relocation.
+        None = R_386_NONE => "R_386_NONE",
+        /// Direct 32-bit.
+        Abs32 = R_386_32 => "R_386_32",
+        /// PC-relative 32-bit.
+        Pc32 = R_386_PC32 => "R_386_PC32",
+        /// 32-bit GOT entry.
+        Got32 = R_386_GOT32 => "R_386_GOT32",
+        /// 32-bit PLT address.
+        Plt32 = R_386_PLT32 => "R_386_PLT32",
+        /// Copy symbol at runtime.
+        Copy = R_386_COPY => "R_386_COPY",
+        /// Create GOT entry.
+        GlobDat = R_386_GLOB_DAT => "R_386_GLOB_DAT",
+        /// Create PLT entry.
+        JumpSlot = R_386_JMP_SLOT => "R_386_JUMP_SLOT",
+        /// Adjust by program base.
+        Relative = R_386_RELATIVE => "R_386_RELATIVE",
+        /// 32-bit offset to GOT.
+        Gotoff = R_386_GOTOFF => "R_386_GOTOFF",
+        /// 32-bit PC-relative offset to GOT.
+        Gotpc = R_386_GOTPC => "R_386_GOTPC",
+        /// Relocation type for a 32-bit PLT address: L + A.
+        Plt32Abs = R_386_32PLT => "R_386_32PLT",
+        /// Offset in static TLS block, for dynamic linking.
+        TlsTpoff = R_386_TLS_TPOFF => "R_386_TLS_TPOFF",
+        /// Address of GOT entry for static TLS block offset.
+        TlsIe = R_386_TLS_IE => "R_386_TLS_IE",
+        /// GOT entry for static TLS block offset.
+        TlsGotie = R_386_TLS_GOTIE => "R_386_TLS_GOTIE",
+        /// Offset relative to static TLS block.
+        TlsLe = R_386_TLS_LE => "R_386_TLS_LE",
+        /// Direct 32-bit for GNU version of general dynamic thread local data.
+        TlsGd = R_386_TLS_GD => "R_386_TLS_GD",
+        /// Direct 32-bit for GNU version of local dynamic thread local data in LE code.
+        TlsLdm = R_386_TLS_LDM => "R_386_TLS_LDM",
+        /// Direct 16-bit.
+        Abs16 = R_386_16 => "R_386_16",
+        /// PC-relative 16-bit.
+        Pc16 = R_386_PC16 => "R_386_PC16",
+        /// Direct 8-bit.
+        Abs8 = R_386_8 => "R_386_8",
+        /// PC-relative 8-bit.
+        Pc8 = R_386_PC8 => "R_386_PC8",
+        /// Direct 32-bit for general dynamic thread local data.
+        TlsGd32 = R_386_TLS_GD_32 => "R_386_TLS_GD_32",
+        /// Tag for pushl in GD TLS code.
+        TlsGdPush = R_386_TLS_GD_PUSH => "R_386_TLS_GD_PUSH",
+        /// Relocation for call to `__tls_get_addr()`.
+        TlsGdCall = R_386_TLS_GD_CALL => "R_386_TLS_GD_CALL",
+        /// Tag for popl in GD TLS code.
+        TlsGdPop = R_386_TLS_GD_POP => "R_386_TLS_GD_POP",
+        /// Direct 32-bit for local dynamic thread local data in LE code.
+        TlsLdm32 = R_386_TLS_LDM_32 => "R_386_TLS_LDM_32",
+        /// Tag for pushl in LDM TLS code.
+        TlsLdmPush = R_386_TLS_LDM_PUSH => "R_386_TLS_LDM_PUSH",
+        /// Relocation for call to `__tls_get_addr()` in LDM code.
+        TlsLdmCall = R_386_TLS_LDM_CALL => "R_386_TLS_LDM_CALL",
+        /// Tag for popl in LDM TLS code.
+        TlsLdmPop = R_386_TLS_LDM_POP => "R_386_TLS_LDM_POP",
+        /// Offset relative to TLS block.
+        TlsLdo32 = R_386_TLS_LDO_32 => "R_386_TLS_LDO_32",
+        /// GOT entry for negated static TLS block offset.
+        TlsIe32 = R_386_TLS_IE_32 => "R_386_TLS_IE_32",
+        /// Negated offset relative to static TLS block.
+        TlsLe32 = R_386_TLS_LE_32 => "R_386_TLS_LE_32",
+        /// ID of module containing symbol.
+        TlsDtpmod32 = R_386_TLS_DTPMOD32 => "R_386_TLS_DTPMOD32",
+        /// Offset in TLS block.
+        TlsDtpoff32 = R_386_TLS_DTPOFF32 => "R_386_TLS_DTPOFF32",
+        /// Negated offset in static TLS block.
+        TlsTpoff32 = R_386_TLS_TPOFF32 => "R_386_TLS_TPOFF32",
+        /// Relocation type for a 32-bit symbol size: Z + A.
+        Size32 = R_386_SIZE32 => "R_386_SIZE32",
+        /// GOT offset for TLS descriptor.
+        TlsGotdesc = R_386_TLS_GOTDESC => "R_386_TLS_GOTDESC",
+        /// Marker of call through TLS descriptor for relaxation.
+        TlsDescCall = R_386_TLS_DESC_CALL => "R_386_TLS_DESC_CALL",
+        /// TLS descriptor containing pointer to code and to argument.
+        TlsDesc = R_386_TLS_DESC => "R_386_TLS_DESC",
+        /// Adjust indirectly by program base.
+        Irelative = R_386_IRELATIVE => "R_386_IRELATIVE",
+        /// Load from 32-bit GOT entry, relaxable.
+        Got32X = R_386_GOT32X => "R_386_GOT32X",
+    }
+}
+
+impl fmt::Display for Reloc386 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Reloc386::Unknown(raw) => write!(f, "unrecognized: {:x}", raw),
+            known                  => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
+
+// x86-64 relocation type enumeration.
+
+/// No relocation is needed.
+pub const R_X86_64_NONE: u32 = 0;
+/// Relocation type for a 64-bit word: S + A.
+pub const R_X86_64_64: u32 = 1;
+/// Relocation type for a 32-bit word: S + A - P.
+pub const R_X86_64_PC32: u32 = 2;
+/// Relocation type for a 32-bit word: G + A.
+pub const R_X86_64_GOT32: u32 = 3;
+/// Relocation type for a 32-bit word: L + A - P.
+pub const R_X86_64_PLT32: u32 = 4;
+/// The dynamic linker copies the symbol’s data into the executable.
+pub const R_X86_64_COPY: u32 = 5;
+/// Relocation type for a 64-bit word: S.
+pub const R_X86_64_GLOB_DAT: u32 = 6;
+/// Relocation type for a 64-bit word: S.
+pub const R_X86_64_JUMP_SLOT: u32 = 7;
+/// Relocation type for a 64-bit word: B + A.
+pub const R_X86_64_RELATIVE: u32 = 8;
+/// Relocation type for a 32-bit word: G + GOT + A - P.
+pub const R_X86_64_GOTPCREL: u32 = 9;
+/// Relocation type for a 32-bit word, zero-extended: S + A.
+pub const R_X86_64_32: u32 = 10;
+/// Relocation type for a 32-bit word, sign-extended: S + A.
+pub const R_X86_64_32S: u32 = 11;
+/// Relocation type for a 16-bit word: S + A.
+pub const R_X86_64_16: u32 = 12;
+/// Relocation type for a 16-bit word: S + A - P.
+pub const R_X86_64_PC16: u32 = 13;
+/// Relocation type for a byte: S + A.
+pub const R_X86_64_8: u32 = 14;
+/// Relocation type for a byte: S + A - P.
+pub const R_X86_64_PC8: u32 = 15;
+/// Relocation type for a 64-bit word: S + A - P.
+pub const R_X86_64_PC64: u32 = 24;
+/// Relocation type for a 64-bit word: S + A - GOT.
+pub const R_X86_64_GOTOFF64: u32 = 25;
+/// Relocation type for a 32-bit word: GOT + A - P.
+pub const R_X86_64_GOTPC32: u32 = 26;
+/// Relocation type for a 32-bit word: Z + A.
+pub const R_X86_64_SIZE32: u32 = 32;
+/// Relocation type for a 64-bit word: Z + A.
+pub const R_X86_64_SIZE64: u32 = 33;
+/// Relocation type for a 64-bit word: indirect (B + A).
+pub const R_X86_64_IRELATIVE: u32 = 37;
+/// Relocation type for a 32-bit word: G + GOT + A - P, relaxable.
+pub const R_X86_64_GOTPCRELX: u32 = 41;
+/// Relocation type for a 32-bit word: G + GOT + A - P, relaxable with REX
+/// prefix.
+pub const R_X86_64_REX_GOTPCRELX: u32 = 42;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decode table entries into addresses.
+    fn relr(entries: &[u64], wordsize: u64) -> Vec<u64> {
+        RelrIter::new(entries.iter().copied(), wordsize).collect()
+    }
+
+    #[test]
+    fn addresses() {
+        assert_eq!(relr(&[0x1000, 0x2000], 8), [0x1000, 0x2000]);
+    }
+
+    #[test]
+    fn bitmap_follows_address() {
+        assert_eq!(relr(&[0x1000, 0b1011], 8), [0x1000, 0x1008, 0x1018]);
+    }
+
+    #[test]
+    fn consecutive_bitmaps() {
+        // The second bitmap starts 63 words after the first one.
+        assert_eq!(relr(&[0x1000, 0b11, 0b11], 8), [0x1000, 0x1008, 0x1200]);
+    }
+
+    #[test]
+    fn highest_bitmap_bit() {
+        assert_eq!(relr(&[0x1000, 0x8000_0000_0000_0001], 8), [0x1000, 0x1000 + 63 * 8]);
+        assert_eq!(relr(&[0x100, 0x8000_0001], 4), [0x100, 0x100 + 31 * 4]);
+    }
+
+    #[test]
+    fn empty_bitmap() {
+        assert_eq!(relr(&[0x1000, 0b1, 0x2000], 8), [0x1000, 0x2000]);
+    }
+
+    #[test]
+    fn bitmap_without_address() {
+        assert_eq!(relr(&[0b101], 4), [4]);
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!(relr(&[], 8), [] as [u64; 0]);
+    }
+}
@@ -0,0 +1,398 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! ELF relocation application module.
+//!
+//! Applies relocations of relocatable (`ET_REL`) files to section contents.
+//! Only calculations that can be done without the link editor are supported:
+//! the `G` and `GOT` terms do not exist before linking, so relocation types
+//! depending on them are reported as unsupported. There is no procedure
+//! linkage table either, so `L` resolves to the symbol value `S`.
+//!
+//! `PLT32` relocations are therefore deliberately applied as `S + A - P`,
+//! the value the link editor produces for a call to a locally defined
+//! function. GNU `readelf -R` leaves them unapplied, so relocated dumps of
+//! such fields differ from its output.
+
+use alloc::vec::Vec;
+
+use crate::elf::elfhdr::{Machine, ET_REL};
+use crate::elf::file::{ElfError, ElfFile, Result};
+use crate::elf::reloc::*;
+use crate::elf::sechdr::{
+    SHN_ABS, SHN_COMMON, SHN_LORESERVE, SHN_UNDEF, SHT_REL, SHT_RELA,
+};
+
+/// Relocation calculation terms.
+///
+/// See the relocation type enumeration in `reloc` module for the meaning of
+/// each term. All arithmetic is modulo 2^64, the result is truncated to the
+/// size of the relocated field.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Terms {
+    /// Addend, explicit or read from the relocated field.
+    pub a: u64,
+    /// Base address, zero for relocatable files.
+    pub b: u64,
+    /// Place of the storage unit being relocated.
+    pub p: u64,
+    /// Symbol value.
+    pub s: u64,
+    /// Symbol size.
+    pub z: u64,
+}
+
+/// Relocation calculation description.
+#[derive(Clone, Copy)]
+pub struct Howto {
+    /// Size of the relocated field in bytes.
+    pub size: usize,
+    /// Calculate field value.
+    pub calc: fn(&Terms) -> u64,
+}
+
+/// Get relocation calculation description.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+/// - `typ`     - given relocation type, its meaning depends on the machine.
+///
+/// # Returns
+/// - `Howto` - if relocation type is supported.
+/// - `None` - otherwise.
+pub fn howto(machine: Machine, typ: u32) -> Option<Howto> {
+    let (size, calc): (usize, fn(&Terms) -> u64) = match (machine, typ) {
+        (Machine::I386, R_386_NONE)          => (0, |_| 0),
+        (Machine::I386, R_386_32)            => (4, |t| t.s.wrapping_add(t.a)),
+        (Machine::I386, R_386_PC32)          => (4, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::I386, R_386_PLT32)         => (4, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::I386, R_386_RELATIVE)      => (4, |t| t.b.wrapping_add(t.a)),
+        (Machine::I386, R_386_16)            => (2, |t| t.s.wrapping_add(t.a)),
+        (Machine::I386, R_386_PC16)          => (2, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::I386, R_386_8)             => (1, |t| t.s.wrapping_add(t.a)),
+        (Machine::I386, R_386_PC8)           => (1, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::X86_64, R_X86_64_NONE)     => (0, |_| 0),
+        (Machine::X86_64, R_X86_64_64)       => (8, |t| t.s.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_PC32)     => (4, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::X86_64, R_X86_64_PLT32)    => (4, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::X86_64, R_X86_64_RELATIVE) => (8, |t| t.b.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_32)       => (4, |t| t.s.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_32S)      => (4, |t| t.s.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_16)       => (2, |t| t.s.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_PC16)     => (2, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::X86_64, R_X86_64_8)        => (1, |t| t.s.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_PC8)      => (1, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::X86_64, R_X86_64_PC64)     => (8, |t| t.s.wrapping_add(t.a).wrapping_sub(t.p)),
+        (Machine::X86_64, R_X86_64_SIZE32)   => (4, |t| t.z.wrapping_add(t.a)),
+        (Machine::X86_64, R_X86_64_SIZE64)   => (8, |t| t.z.wrapping_add(t.a)),
+        _ => return None,
+    };
+
+    Some(Howto { size, calc })
+}
+
+/// Relocation that could not be applied.
+#[derive(Debug, Clone, Copy)]
+pub struct Skipped {
+    /// Relocation entry.
+    pub rela: Elf64_Rela,
+    /// Whether relocation type is unsupported. Otherwise the relocated field
+    /// lies outside of the section.
+    pub unsupported: bool,
+}
+
+/// Relocated section contents.
+#[derive(Debug, Clone, Default)]
+pub struct Relocated {
+    /// Section bytes with relocations applied.
+    pub data: Vec<u8>,
+    /// Relocations that could not be applied, in the order they were met.
+    pub skipped: Vec<Skipped>,
+}
+
+/// Read little-endian field.
+fn read_field(data: &[u8], size: usize) -> u64 {
+    data[..size]
+        .iter()
+        .rev()
+        .fold(0, |value, &b| (value << 8) | b as u64)
+}
+
+/// Write little-endian field, truncating value to the field size.
+fn write_field(data: &mut [u8], size: usize, value: u64) {
+    data[..size].copy_from_slice(&value.to_le_bytes()[..size]);
+}
+
+/// Sign-extend value of the field with given size.
+fn sign_extend(value: u64, size: usize) -> u64 {
+    match size {
+        1 => value as i8 as u64,
+        2 => value as i16 as u64,
+        4 => value as i32 as u64,
+        _ => value,
+    }
+}
+
+/// Apply relocations to section contents.
+///
+/// Compressed sections are decompressed first. All `SHT_REL` and `SHT_RELA`
+/// sections whose `sh_info` refers to the given section are applied. Files
+/// other than `ET_REL` are returned unchanged, because their relocations are
+/// meant for the dynamic linker.
+///
+/// # Parameters
+/// - `file`  - given ELF file.
+/// - `index` - given index of the section to relocate.
+///
+/// # Returns
+/// - `Relocated` - in case of success.
+/// - `ElfError` - if section or its relocations can not be read.
+pub fn relocate_section(file: &ElfFile, index: usize) -> Result<Relocated> {
+    let target = file.section_header(index)?;
+    relocate_data(file, index, file.section_data(target)?.to_vec())
+}
+
+/// Apply relocations to given contents of section.
+///
+/// Same as [`relocate_section`], but relocates contents obtained by the
+/// caller, e.g. as they are stored in the file.
+///
+/// # Parameters
+/// - `file`  - given ELF file.
+/// - `index` - given index of the section to relocate.
+/// - `data`  - given section contents.
+///
+/// # Returns
+/// - `Relocated` - in case of success.
+/// - `ElfError` - if section or its relocations can not be read.
+pub fn relocate_data(file: &ElfFile, index: usize, data: Vec<u8>) -> Result<Relocated> {
+    let target = file.section_header(index)?;
+    let mut relocated = Relocated {
+        data,
+        skipped: Vec::new(),
+    };
+
+    if file.header().e_type != ET_REL {
+        return Ok(relocated);
+    }
+
+    let machine = file.header().machine();
+
+    for shdr in file.section_headers() {
+        if !matches!(shdr.sh_type, SHT_REL | SHT_RELA) || shdr.sh_info as usize != index {
+            continue;
+        }
+
+        let symtab = *file.section_header(shdr.sh_link as usize)?;
+
+        for rela in file.relocation_table(shdr)? {
+            let info   = rela.r_info;
+            let offset = rela.r_offset;
+
+            let Some(howto) = howto(machine, elf64_r_type(info)) else {
+                relocated.skipped.push(Skipped { rela, unsupported: true });
+                continue;
+            };
+
+            let field = usize::try_from(offset)
+                .ok()
+                .and_then(|start| relocated.data.get_mut(start..start.checked_add(howto.size)?));
+
+            let Some(field) = field else {
+                relocated.skipped.push(Skipped { rela, unsupported: false });
+                continue;
+            };
+
+            let sym = file.symbol(&symtab, elf64_r_sym(info) as usize)?;
+            let s = match sym.st_shndx as u32 {
+                SHN_UNDEF | SHN_COMMON => 0,
+                SHN_ABS                => sym.st_value,
+                shndx if shndx >= SHN_LORESERVE => sym.st_value,
+                shndx => file.section_header(shndx as usize)
+                    .map_err(|_| ElfError::Malformed("Symbol refers to missing section"))?
+                    .sh_addr
+                    .wrapping_add(sym.st_value),
+            };
+
+            // Relocations without explicit addend keep it in the field itself.
+            let a = match shdr.sh_type {
+                SHT_REL => sign_extend(read_field(field, howto.size), howto.size),
+                _       => rela.r_addend as u64,
+            };
+
+            let terms = Terms {
+                a,
+                b: 0,
+                p: target.sh_addr.wrapping_add(offset),
+                s,
+                z: sym.st_size,
+            };
+
+            write_field(field, howto.size, (howto.calc)(&terms));
+        }
+    }
+
+    Ok(relocated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloc::vec;
+
+    use crate::elf::elfhdr::EM_X86_64;
+    use crate::elf::sechdr::{SHT_PROGBITS, SHT_STRTAB, SHT_SYMTAB};
+
+    /// Address of the `.text` section.
+    const TEXT_ADDR: u64 = 0x1000;
+    /// Value of the `foo` symbol, relative to the `.text` section.
+    const FOO_VALUE: u64 = 0x20;
+
+    /// Append little-endian fields of given sizes.
+    fn put(data: &mut Vec<u8>, fields: &[(u64, usize)]) {
+        for &(value, size) in fields {
+            data.extend_from_slice(&value.to_le_bytes()[..size]);
+        }
+    }
+
+    /// Build x86-64 relocatable file with 16 byte `.text` section, `foo`
+    /// symbol defined in it and `.rela.text` section holding given entries.
+    fn build(relas: &[(u64, u32, i64)]) -> Vec<u8> {
+        let text   = [0u8; 16];
+        let strtab = b"\0foo\0";
+        let shstr  = b"\0.text\0.symtab\0.strtab\0.rela.text\0.shstrtab\0";
+
+        let mut symtab = Vec::new();
+        put(&mut symtab, &[(0, 4), (0, 1), (0, 1), (0, 2), (0, 8), (0, 8)]);
+        put(&mut symtab, &[(1, 4), (0x12, 1), (0, 1), (1, 2), (FOO_VALUE, 8), (8, 8)]);
+
+        let mut rela = Vec::new();
+        for &(offset, typ, addend) in relas {
+            put(&mut rela, &[(offset, 8), (elf64_r_info(1, typ), 8), (addend as u64, 8)]);
+        }
+
+        // Section contents follow the header, section headers follow them.
+        let contents: [&[u8]; 5] = [&text, &symtab, strtab, &rela, shstr];
+        let mut offsets = Vec::new();
+        let mut body    = Vec::new();
+
+        for content in contents {
+            offsets.push(64 + body.len() as u64);
+            body.extend_from_slice(content);
+        }
+
+        let shoff = 64 + body.len() as u64;
+        let mut data = Vec::new();
+
+        data.extend_from_slice(b"\x7fELF\x02\x01\x01");
+        data.resize(16, 0);
+        put(&mut data, &[
+            (ET_REL as u64, 2), (EM_X86_64 as u64, 2), (1, 4), (0, 8), (0, 8), (shoff, 8),
+            (0, 4), (64, 2), (0, 2), (0, 2), (64, 2), (6, 2), (5, 2),
+        ]);
+        data.extend_from_slice(&body);
+
+        // Name, type, address, link, info and entry size of each section.
+        let headers = [
+            (1,  SHT_PROGBITS, TEXT_ADDR, 0, 0, 0),
+            (7,  SHT_SYMTAB,   0,         3, 1, 24),
+            (15, SHT_STRTAB,   0,         0, 0, 0),
+            (23, SHT_RELA,     0,         2, 1, 24),
+            (34, SHT_STRTAB,   0,         0, 0, 0),
+        ];
+
+        data.resize(data.len() + 64, 0);
+
+        for (i, &(name, typ, addr, link, info, entsize)) in headers.iter().enumerate() {
+            put(&mut data, &[
+                (name, 4), (typ as u64, 4), (0, 8), (addr, 8), (offsets[i], 8),
+                (contents[i].len() as u64, 8), (link, 4), (info, 4), (1, 8), (entsize, 8),
+            ]);
+        }
+
+        data
+    }
+
+    /// Apply relocations of file built with given entries to `.text`
+    /// contents filled with `0xaa` bytes.
+    fn relocate(relas: &[(u64, u32, i64)]) -> Relocated {
+        let data = build(relas);
+        let file = ElfFile::parse(&data).unwrap();
+        relocate_data(&file, 1, vec![0xaa; 16]).unwrap()
+    }
+
+    #[test]
+    fn howto_calculations() {
+        let terms = Terms { a: (-4i64) as u64, b: 0x400000, p: 0x1008, s: 0x1020, z: 8 };
+        let calc  = |machine, typ| howto(machine, typ).map(|h| (h.size, (h.calc)(&terms)));
+
+        assert_eq!(calc(Machine::X86_64, R_X86_64_64),       Some((8, 0x101c)));
+        assert_eq!(calc(Machine::X86_64, R_X86_64_PC32),     Some((4, 0x14)));
+        assert_eq!(calc(Machine::X86_64, R_X86_64_PLT32),    Some((4, 0x14)));
+        assert_eq!(calc(Machine::X86_64, R_X86_64_RELATIVE), Some((8, 0x3ffffc)));
+        assert_eq!(calc(Machine::X86_64, R_X86_64_SIZE32),   Some((4, 4)));
+        assert_eq!(calc(Machine::I386,   R_386_PLT32),       Some((4, 0x14)));
+        assert_eq!(calc(Machine::I386,   R_386_32),          Some((4, 0x101c)));
+    }
+
+    #[test]
+    fn howto_unsupported() {
+        assert!(howto(Machine::X86_64, R_X86_64_GOTPCREL).is_none());
+        assert!(howto(Machine::X86_64, R_X86_64_GOTPCRELX).is_none());
+        assert!(howto(Machine::I386, R_386_GOT32).is_none());
+        assert!(howto(Machine::Aarch64, R_X86_64_64).is_none());
+    }
+
+    #[test]
+    fn absolute_and_pc_relative() {
+        let foo = TEXT_ADDR + FOO_VALUE;
+        let relocated = relocate(&[
+            (0,  R_X86_64_64,    5),
+            (8,  R_X86_64_PC32,  -4),
+            (12, R_X86_64_PLT32, -4),
+        ]);
+
+        let pc32  = foo.wrapping_sub(4).wrapping_sub(TEXT_ADDR + 8) as u32;
+        let plt32 = foo.wrapping_sub(4).wrapping_sub(TEXT_ADDR + 12) as u32;
+
+        assert!(relocated.skipped.is_empty());
+        assert_eq!(relocated.data[..8], (foo + 5).to_le_bytes());
+        assert_eq!(relocated.data[8..12], pc32.to_le_bytes());
+        assert_eq!(relocated.data[12..], plt32.to_le_bytes());
+    }
+
+    #[test]
+    fn skipped_relocations() {
+        let relocated = relocate(&[
+            (12, R_X86_64_64,       0),
+            (0,  R_X86_64_GOTPCREL, 0),
+            (u64::MAX, R_X86_64_32, 0),
+            (4,  R_X86_64_32,       1),
+        ]);
+
+        let skipped: Vec<_> = relocated
+            .skipped
+            .iter()
+            .map(|s| ({ s.rela.r_offset }, s.unsupported))
+            .collect();
+
+        assert_eq!(skipped, [(12, false), (0, true), (u64::MAX, false)]);
+        assert_eq!(relocated.data[..4], [0xaa; 4]);
+        assert_eq!(relocated.data[4..8], ((TEXT_ADDR + FOO_VALUE + 1) as u32).to_le_bytes());
+        assert_eq!(relocated.data[8..], [0xaa; 8]);
+    }
+}
@@ -16,32 +16,46 @@
 
 //! ELF sections module.
 
-use crate::elf::{Elf32_Addr, Elf32_Off, Elf32_Word};
+use core::fmt;
+
+use crate::elf::{
+    AltHex, Elf32_Addr, Elf32_Off, Elf32_Word, Elf64_Addr, Elf64_Off, Elf64_Word,
+    Elf64_Xword,
+};
 
 // ELF special section indexes enumeration.
 
 /// This value marks an undefined, missing, irrelevant, or otherwise meaningless
 /// section reference. For example, a symbol ‘‘defined’’ relative to section
 /// number SHN_UNDEF is an undefined symbol.
-const SHN_UNDEF: u32 = 0;
+pub const SHN_UNDEF: u32 = 0;
 /// This value specifies the lower bound of the range of reserved indexes.
-const SHN_LORESERVE: u32 = 0xff00;
+pub const SHN_LORESERVE: u32 = 0xff00;
 /// Values in this inclusive range are reserved for processor-specific
 /// semantics.
-const SHN_LOPROC: u32 = 0xff00;
-const SHN_HIPROC: u32 = 0xff1f;
+pub const SHN_LOPROC: u32 = 0xff00;
+pub const SHN_HIPROC: u32 = 0xff1f;
+/// Values in this inclusive range are reserved for operating system-specific
+/// semantics.
+pub const SHN_LOOS: u32 = 0xff20;
+pub const SHN_HIOS: u32 = 0xff3f;
 /// This value specifies absolute values for the corresponding reference. For
 /// example, symbols defined relative to section number SHN_ABS have absolute
 /// values and are not affected by relocation.
-const SHN_ABS: u32 = 0xfff1;
+pub const SHN_ABS: u32 = 0xfff1;
 /// Symbols defined relative to this section are common symbols, such as FORTRAN
 /// COMMON or unallocated C external variables.
-const SHN_COMMON: u32 = 0xfff2;
+pub const SHN_COMMON: u32 = 0xfff2;
+/// This value is an escape value. It indicates that the actual section header
+/// index is too large to fit in the containing field and is to be found in
+/// another location (`sh_link` of section 0 for `e_shstrndx`, `sh_size` of
+/// section 0 for `e_shnum`).
+pub const SHN_XINDEX: u32 = 0xffff;
 /// This value specifies the upper bound of the range of reserved indexes. The
 /// system reserves indexes between SHN_LORESERVE and SHN_HIRESERVE, inclusive;
 /// the values do not reference the section header table. That is, the section
 /// header table does not contain entries for the reserved indexes.
-const SHN_HIRESERVE: u32 = 0xfff;
+pub const SHN_HIRESERVE: u32 = 0xffff;
 
 /// ELF section header struct.
 /// Sections contain all information in an object file, except the ELF header,
@@ -55,6 +69,7 @@ const SHN_HIRESERVE: u32 = 0xfff;
 /// in more than one section. An object file may have inactive space. The
 /// various headers and the sections might not ‘‘cover’’ every byte in an object
 /// file. The contents of the inactive data are unspecified.
+#[derive(Debug, Clone, Copy)]
 #[repr(C, packed)]
 pub struct Elf32_Shdr {
     /// This member specifies the name of the section. Its value is an index
@@ -100,6 +115,59 @@ pub struct Elf32_Shdr {
     pub sh_entsize: Elf32_Word,
 }
 
+/// ELF section header struct for the 64-bit class of object files.
+///
+/// It holds the same members as `Elf32_Shdr`, with flags, addresses, offsets
+/// and sizes widened to 64 bits.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C, packed)]
+pub struct Elf64_Shdr {
+    /// Section name, index into the section header string table.
+    pub sh_name: Elf64_Word,
+    /// Section type.
+    pub sh_type: Elf64_Word,
+    /// Section attributes.
+    pub sh_flags: Elf64_Xword,
+    /// Virtual address in memory.
+    pub sh_addr: Elf64_Addr,
+    /// Offset in file.
+    pub sh_offset: Elf64_Off,
+    /// Size of section.
+    pub sh_size: Elf64_Xword,
+    /// Link to other section.
+    pub sh_link: Elf64_Word,
+    /// Miscellaneous information.
+    pub sh_info: Elf64_Word,
+    /// Address alignment boundary.
+    pub sh_addralign: Elf64_Xword,
+    /// Size of entries, if section has table.
+    pub sh_entsize: Elf64_Xword,
+}
+
+impl From<Elf32_Shdr> for Elf64_Shdr {
+    fn from(shdr: Elf32_Shdr) -> Self {
+        Elf64_Shdr {
+            sh_name:      shdr.sh_name,
+            sh_type:      shdr.sh_type,
+            sh_flags:     shdr.sh_flags as Elf64_Xword,
+            sh_addr:      shdr.sh_addr as Elf64_Addr,
+            sh_offset:    shdr.sh_offset as Elf64_Off,
+            sh_size:      shdr.sh_size as Elf64_Xword,
+            sh_link:      shdr.sh_link,
+            sh_info:      shdr.sh_info,
+            sh_addralign: shdr.sh_addralign as Elf64_Xword,
+            sh_entsize:   shdr.sh_entsize as Elf64_Xword,
+        }
+    }
+}
+
+impl Elf64_Shdr {
+    /// Get section type.
+    pub fn section_type(&self) -> SectionType {
+        SectionType::from(self.sh_type)
+    }
+}
+
 // ELF section type enumeration.
 
 /// This value marks the section header as inactive; it does not have an
@@ -146,10 +214,66 @@ pub const SHT_REL: u32 = 9;
 /// This section type is reserved but has unspecified semantics. Programs that
 /// contain a section of this type do not conform to the ABI.
 pub const SHT_SHLIB: u32 = 10;
+/// The section holds an array of pointers to initialization functions.
+pub const SHT_INIT_ARRAY: u32 = 14;
+/// The section holds an array of pointers to termination functions.
+pub const SHT_FINI_ARRAY: u32 = 15;
+/// The section holds an array of pointers to functions invoked before all
+/// other initialization functions.
+pub const SHT_PREINIT_ARRAY: u32 = 16;
+/// The section defines a section group, a set of sections that are related
+/// and must be treated specially by the linker.
+pub const SHT_GROUP: u32 = 17;
+/// The section holds extended section indexes of the symbol table entries
+/// whose `st_shndx` is `SHN_XINDEX`.
+pub const SHT_SYMTAB_SHNDX: u32 = 18;
+/// The section holds relative relocations in the packed `Elf32_Relr` or
+/// `Elf64_Relr` format, see `reloc` module.
+pub const SHT_RELR: u32 = 19;
+/// The section holds relocation entries without explicit addends in the
+/// Android packed format, see `android` module.
+pub const SHT_ANDROID_REL: u32 = 0x60000001;
+/// The section holds relocation entries with explicit addends in the Android
+/// packed format, see `android` module.
+pub const SHT_ANDROID_RELA: u32 = 0x60000002;
+/// Values in this inclusive range are reserved for operating system-specific
+/// semantics.
+pub const SHT_LOOS: u32 = 0x60000000;
+pub const SHT_HIOS: u32 = 0x6fffffff;
+/// The section holds incremental linking inputs of gold.
+pub const SHT_GNU_INCREMENTAL_INPUTS: u32 = 0x6fff4700;
+/// The section holds GNU object attributes.
+pub const SHT_GNU_ATTRIBUTES: u32 = 0x6ffffff5;
+/// The section holds prelink library list.
+pub const SHT_GNU_LIBLIST: u32 = 0x6ffffff7;
+/// The section holds a GNU-style symbol hash table, see `hash` module.
+pub const SHT_GNU_HASH: u32 = 0x6ffffff6;
+/// The section holds version definitions, see `version` module.
+pub const SHT_GNU_VERDEF: u32 = 0x6ffffffd;
+/// The section holds versions needed from other objects.
+pub const SHT_GNU_VERNEED: u32 = 0x6ffffffe;
+/// The section holds version index of each dynamic symbol.
+pub const SHT_GNU_VERSYM: u32 = 0x6fffffff;
 /// Values in this inclusive range are reserved for processor-specific
 /// semantics.
 pub const SHT_LOPROC: u32 = 0x70000000;
 pub const SHT_HIPROC: u32 = 0x7fffffff;
+/// The section holds x86-64 unwind information.
+pub const SHT_X86_64_UNWIND: u32 = 0x70000001;
+/// The section holds ARM exception index table.
+pub const SHT_ARM_EXIDX: u32 = 0x70000001;
+/// The section holds ARM BPABI DLL dynamic linking pre-emption map.
+pub const SHT_ARM_PREEMPTMAP: u32 = 0x70000002;
+/// The section holds ARM object file compatibility attributes.
+pub const SHT_ARM_ATTRIBUTES: u32 = 0x70000003;
+/// The section holds ARM debug overlay information.
+pub const SHT_ARM_DEBUGOVERLAY: u32 = 0x70000004;
+/// The section holds ARM overlay descriptions.
+pub const SHT_ARM_OVERLAYSECTION: u32 = 0x70000005;
+/// The section holds AArch64 build attributes.
+pub const SHT_AARCH64_ATTRIBUTES: u32 = 0x70000003;
+/// The section holds RISC-V build attributes.
+pub const SHT_RISCV_ATTRIBUTES: u32 = 0x70000003;
 /// This value specifies the lower bound of the range of indexes reserved for
 /// application programs.
 pub const SHT_LOUSER: u32 = 0x80000000;
@@ -159,6 +283,77 @@ pub const SHT_LOUSER: u32 = 0x80000000;
 /// system-defined section types.
 pub const SHT_HIUSER: u32 = 0xffffffff;
 
+elf_enum! {
+    /// Section type, `sh_type` field of section header.
+    ///
+    /// Processor-specific types depend on the machine, so they are always
+    /// `Unknown`.
+    pub enum SectionType: u32 {
+        /// Inactive section header.
+        Null = SHT_NULL => "NULL",
+        /// Program defined information.
+        Progbits = SHT_PROGBITS => "PROGBITS",
+        /// Symbol table.
+        Symtab = SHT_SYMTAB => "SYMTAB",
+        /// String table.
+        Strtab = SHT_STRTAB => "STRTAB",
+        /// Relocations with explicit addends.
+        Rela = SHT_RELA => "RELA",
+        /// Symbol hash table.
+        Hash = SHT_HASH => "HASH",
+        /// Dynamic linking information.
+        Dynamic = SHT_DYNAMIC => "DYNAMIC",
+        /// Notes.
+        Note = SHT_NOTE => "NOTE",
+        /// Section occupying no space in the file.
+        Nobits = SHT_NOBITS => "NOBITS",
+        /// Relocations without explicit addends.
+        Rel = SHT_REL => "REL",
+        /// Reserved.
+        Shlib = SHT_SHLIB => "SHLIB",
+        /// Dynamic linking symbol table.
+        Dynsym = SHT_DYNSYM => "DYNSYM",
+        /// Initialization functions.
+        InitArray = SHT_INIT_ARRAY => "INIT_ARRAY",
+        /// Termination functions.
+        FiniArray = SHT_FINI_ARRAY => "FINI_ARRAY",
+        /// Pre-initialization functions.
+        PreinitArray = SHT_PREINIT_ARRAY => "PREINIT_ARRAY",
+        /// Section group.
+        Group = SHT_GROUP => "GROUP",
+        /// Extended section indexes of symbol table.
+        SymtabShndx = SHT_SYMTAB_SHNDX => "SYMTAB SECTION INDICES",
+        /// Relative relocations.
+        Relr = SHT_RELR => "RELR",
+        /// Incremental build data.
+        GnuIncrementalInputs = SHT_GNU_INCREMENTAL_INPUTS => "GNU_INCREMENTAL_INPUTS",
+        /// Object attributes.
+        GnuAttributes = SHT_GNU_ATTRIBUTES => "GNU_ATTRIBUTES",
+        /// GNU-style symbol hash table.
+        GnuHash = SHT_GNU_HASH => "GNU_HASH",
+        /// Prelink library list.
+        GnuLiblist = SHT_GNU_LIBLIST => "GNU_LIBLIST",
+        /// Version definitions.
+        GnuVerdef = SHT_GNU_VERDEF => "VERDEF",
+        /// Versions needed.
+        GnuVerneed = SHT_GNU_VERNEED => "VERNEED",
+        /// Symbol versions.
+        GnuVersym = SHT_GNU_VERSYM => "VERSYM",
+    }
+}
+
+impl fmt::Display for SectionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SectionType::Unknown(raw @ SHT_LOPROC..=SHT_HIPROC) => write!(f, "LOPROC+{}", AltHex((raw - SHT_LOPROC) as u64)),
+            SectionType::Unknown(raw @ SHT_LOOS..=SHT_HIOS)     => write!(f, "LOOS+{}", AltHex((raw - SHT_LOOS) as u64)),
+            SectionType::Unknown(raw @ SHT_LOUSER..)            => write!(f, "LOUSER+{}", AltHex((raw - SHT_LOUSER) as u64)),
+            SectionType::Unknown(raw)                           => write!(f, "{:08x}: <unknown>", raw),
+            known => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
+
 // ELF section flags enumeration.
 
 /// If a flag bit is set in `sh_flags`, the attribute is "on" for the section.
@@ -173,17 +368,110 @@ pub const SHF_WRITE: u32 = 0x1;
 pub const SHF_ALLOC: u32 = 0x2;
 /// The section contains executable machine instructions.
 pub const SHF_EXECINSTR: u32 = 0x4;
+/// The data in the section may be merged to eliminate duplication.
+pub const SHF_MERGE: u32 = 0x10;
+/// The section consists of null-terminated character strings.
+pub const SHF_STRINGS: u32 = 0x20;
+/// The `sh_info` field of this section header holds a section header table
+/// index.
+pub const SHF_INFO_LINK: u32 = 0x40;
+/// The section must keep its order relative to the section it links to.
+pub const SHF_LINK_ORDER: u32 = 0x80;
+/// The section requires special OS-specific processing.
+pub const SHF_OS_NONCONFORMING: u32 = 0x100;
+/// The section is a member of a section group.
+pub const SHF_GROUP: u32 = 0x200;
+/// The section holds thread-local storage.
+pub const SHF_TLS: u32 = 0x400;
+/// The section holds compressed data, which starts with compression header,
+/// see `Elf32_Chdr`.
+pub const SHF_COMPRESSED: u32 = 0x800;
+/// All bits included in this mask are reserved for operating system-specific
+/// semantics.
+pub const SHF_MASKOS: u32 = 0x0ff00000;
+/// The section must not be garbage collected by the linker.
+pub const SHF_GNU_RETAIN: u32 = 0x200000;
+/// The section is bound to a memory type.
+pub const SHF_GNU_MBIND: u32 = 0x01000000;
 /// All bits included in this mask are reserved for processor-specific
 /// semantics.
 pub const SHF_MASKPROC: u32 = 0xf0000000;
+/// The section holds large data on x86-64.
+pub const SHF_X86_64_LARGE: u32 = 0x10000000;
+/// The section is ordered on SPARC and x86.
+pub const SHF_ORDERED: u32 = 0x40000000;
+/// The section is excluded from executables and shared objects.
+pub const SHF_EXCLUDE: u32 = 0x80000000;
+/// The section is an entry section on ARM.
+pub const SHF_ENTRYSECT: u32 = 0x10000000;
+/// The section holds only executable code on ARM.
+pub const SHF_ARM_PURECODE: u32 = 0x20000000;
+/// The section is a common definition on ARM.
+pub const SHF_COMDEF: u32 = 0x80000000;
+/// The section holds VLE code on PowerPC.
+pub const SHF_PPC_VLE: u32 = 0x10000000;
+
+// Solaris special section indexes in `sh_link`.
+
+/// The section is ordered before all other sections.
+pub const SHN_BEFORE: u32 = 0xff00;
+/// The section is ordered after all other sections.
+pub const SHN_AFTER: u32 = 0xff01;
+
+// ELF compression types enumeration.
+
+/// The section data is compressed with zlib.
+pub const ELFCOMPRESS_ZLIB: u32 = 1;
+/// The section data is compressed with Zstandard.
+pub const ELFCOMPRESS_ZSTD: u32 = 2;
+
+/// ELF compression header struct.
+///
+/// Data of `SHF_COMPRESSED` section starts with this header, followed by the
+/// compressed bytes.
+#[derive(Debug, Clone, Copy)]
+#[repr(C, packed)]
+pub struct Elf32_Chdr {
+    /// Compression algorithm.
+    pub ch_type: Elf32_Word,
+    /// Size of uncompressed data.
+    pub ch_size: Elf32_Word,
+    /// Alignment of uncompressed data.
+    pub ch_addralign: Elf32_Word,
+}
+
+/// ELF compression header struct for the 64-bit class of object files.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C, packed)]
+pub struct Elf64_Chdr {
+    /// Compression algorithm.
+    pub ch_type: Elf64_Word,
+    /// Reserved.
+    pub ch_reserved: Elf64_Word,
+    /// Size of uncompressed data.
+    pub ch_size: Elf64_Xword,
+    /// Alignment of uncompressed data.
+    pub ch_addralign: Elf64_Xword,
+}
+
+impl From<Elf32_Chdr> for Elf64_Chdr {
+    fn from(chdr: Elf32_Chdr) -> Self {
+        Elf64_Chdr {
+            ch_type:      chdr.ch_type,
+            ch_reserved:  0,
+            ch_size:      chdr.ch_size as Elf64_Xword,
+            ch_addralign: chdr.ch_addralign as Elf64_Xword,
+        }
+    }
+}
 
 /// Check if a section is .bss.
 ///
 /// - `.bss` - This section holds uninitialized data that contribute to the
 ///   program’s memory image. By
-/// definition, the system initializes the data with zeros when the program
-/// begins to run. The section occupies no file space, as indicated by the
-/// section type, SHT_NOBITS.
+///   definition, the system initializes the data with zeros when the program
+///   begins to run. The section occupies no file space, as indicated by the
+///   section type, SHT_NOBITS.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -200,8 +488,8 @@ pub fn is_bss_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.data` - This section holds initialized data that contribute to the
 ///   program’s memory image.
-/// The section is allocated in memory and is writable, as indicated by the
-/// presence of the SHF_ALLOC and SHF_WRITE flags.
+///   The section is allocated in memory and is writable, as indicated by the
+///   presence of the SHF_ALLOC and SHF_WRITE flags.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -217,8 +505,8 @@ pub fn is_data_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.data1` - This section holds initialized data that contribute to the
 ///   program’s memory image.
-/// Similar to `.data`, it is allocated in memory and is writable, as indicated
-/// by the presence of the SHF_ALLOC and SHF_WRITE flags.
+///   Similar to `.data`, it is allocated in memory and is writable, as indicated
+///   by the presence of the SHF_ALLOC and SHF_WRITE flags.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -238,8 +526,8 @@ pub fn is_data1_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.debug` - This section holds information for symbolic debugging. The
 ///   contents are unspecified,
-/// and it is typically used by debuggers to provide information about the
-/// source code and its correspondence to the machine code.
+///   and it is typically used by debuggers to provide information about the
+///   source code and its correspondence to the machine code.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -256,9 +544,9 @@ pub fn is_debug_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.dynamic` - This section holds dynamic linking information. The section’s
 ///   attributes will
-/// include the SHF_ALLOC bit. Whether the SHF_WRITE bit is set is
-/// processor-specific. It is used by the dynamic linker to manage shared
-/// libraries and dynamic symbols.
+///   include the SHF_ALLOC bit. Whether the SHF_WRITE bit is set is
+///   processor-specific. It is used by the dynamic linker to manage shared
+///   libraries and dynamic symbols.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -275,8 +563,8 @@ pub fn is_dynamic_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.dynstr` - This section holds strings needed for dynamic linking, most
 ///   commonly the strings
-/// that represent the names associated with symbol table entries. It is
-/// essential for the dynamic linker to resolve symbol names at runtime.
+///   that represent the names associated with symbol table entries. It is
+///   essential for the dynamic linker to resolve symbol names at runtime.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -295,8 +583,8 @@ pub fn is_dynstr_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.dynsym` - This section holds the dynamic linking symbol table, which
 ///   contains entries for
-/// symbols that are used during dynamic linking. It is crucial for the dynamic
-/// linker to resolve symbols at runtime.
+///   symbols that are used during dynamic linking. It is crucial for the dynamic
+///   linker to resolve symbols at runtime.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -315,8 +603,8 @@ pub fn is_dynsym_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.fini` - This section holds executable instructions that contribute to
 ///   the process termination
-/// code. When a program exits normally, the system arranges to execute the code
-/// in this section.
+///   code. When a program exits normally, the system arranges to execute the code
+///   in this section.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -336,8 +624,8 @@ pub fn is_fini_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.got` - This section holds the global offset table, which is used for
 ///   dynamic linking to
-/// resolve addresses of global variables and functions. It is essential for the
-/// correct execution of dynamically linked programs.
+///   resolve addresses of global variables and functions. It is essential for the
+///   correct execution of dynamically linked programs.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -354,8 +642,8 @@ pub fn is_got_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.hash` - This section holds a symbol hash table, which is used by the
 ///   dynamic linker to
-/// quickly resolve symbols. It provides a way to efficiently look up symbols
-/// during dynamic linking.
+///   quickly resolve symbols. It provides a way to efficiently look up symbols
+///   during dynamic linking.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -374,9 +662,9 @@ pub fn is_hash_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.init` - This section holds executable instructions that contribute to
 ///   the process initialization
-/// code. When a program starts to run, the system arranges to execute the code
-/// in this section before calling the main program entry point (commonly `main`
-/// for C programs).
+///   code. When a program starts to run, the system arranges to execute the code
+///   in this section before calling the main program entry point (commonly `main`
+///   for C programs).
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -393,8 +681,8 @@ pub fn is_init_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.line` - This section holds line number information for symbolic
 ///   debugging, which describes the
-/// correspondence between the source program and the machine code. The contents
-/// are unspecified.
+///   correspondence between the source program and the machine code. The contents
+///   are unspecified.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -411,8 +699,8 @@ pub fn is_line_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.note` - This section holds information in a format that is described in
 ///   the "Note Section"
-/// in the ELF specification. It is often used for storing metadata about the
-/// file.
+///   in the ELF specification. It is often used for storing metadata about the
+///   file.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -429,8 +717,8 @@ pub fn is_note_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.plt` - This section holds the procedure linkage table, which is used for
 ///   dynamic linking.
-/// It allows for the resolution of function addresses at runtime, enabling
-/// calls to shared library functions.
+///   It allows for the resolution of function addresses at runtime, enabling
+///   calls to shared library functions.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -447,8 +735,8 @@ pub fn is_plt_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.rodata` - This section holds read-only data that typically contributes
 ///   to a non-writable
-/// segment in the process image. It is used for constants and string literals
-/// that should not be modified during execution.
+///   segment in the process image. It is used for constants and string literals
+///   that should not be modified during execution.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -467,9 +755,9 @@ pub fn is_rodata_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.rodata1` - This section holds additional read-only data that typically
 ///   contributes to a
-/// non-writable segment in the process image. Similar to `.rodata`, it is used
-/// for constants and string literals that should not be modified during
-/// execution.
+///   non-writable segment in the process image. Similar to `.rodata`, it is used
+///   for constants and string literals that should not be modified during
+///   execution.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -488,8 +776,8 @@ pub fn is_rodata1_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.shstrtab` - This section holds section names, which are used to identify
 ///   the various sections
-/// in the ELF file. It is essential for the proper interpretation of the
-/// section headers.
+///   in the ELF file. It is essential for the proper interpretation of the
+///   section headers.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -506,9 +794,9 @@ pub fn is_shstrtab_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.strtab` - This section holds strings, most commonly the strings that
 ///   represent the names
-/// associated with symbol table entries. If the file has a loadable segment
-/// that includes the symbol string table, the section’s attributes will include
-/// the SHF_ALLOC bit; otherwise, that bit will be off.
+///   associated with symbol table entries. If the file has a loadable segment
+///   that includes the symbol string table, the section’s attributes will include
+///   the SHF_ALLOC bit; otherwise, that bit will be off.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -525,10 +813,10 @@ pub fn is_strtab_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.symtab` - This section holds a symbol table, which contains information
 ///   about the symbols
-/// used in the program. It provides a mapping between symbolic names and their
-/// corresponding addresses or values. If the file has a loadable segment that
-/// includes the symbol table, the section’s attributes will include the
-/// `SHF_ALLOC` bit; otherwise, that bit will be off.
+///   used in the program. It provides a mapping between symbolic names and their
+///   corresponding addresses or values. If the file has a loadable segment that
+///   includes the symbol table, the section’s attributes will include the
+///   `SHF_ALLOC` bit; otherwise, that bit will be off.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
@@ -545,8 +833,8 @@ pub fn is_symtab_section(section: &Elf32_Shdr, name: &str) -> bool {
 ///
 /// - `.text` - This section holds the "text," or executable instructions, of a
 ///   program. It
-/// contains the compiled code that the CPU executes. The section is typically
-/// marked as executable and may also be marked as readable, but not writable.
+///   contains the compiled code that the CPU executes. The section is typically
+///   marked as executable and may also be marked as readable, but not writable.
 ///
 /// # Parameters
 /// - `section` - given ELF section header struct.
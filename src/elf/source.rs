@@ -0,0 +1,193 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! ELF file data source module.
+//!
+//! `ElfFile` reads file contents either from bytes (in-memory or memory
+//! mapped files) or through `Source`, e.g. `StreamSource` reading byte ranges
+//! on demand through `Read + Seek`.
+
+#[cfg(feature = "std")]
+use alloc::boxed::Box;
+#[cfg(feature = "std")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use alloc::vec;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use core::cell::RefCell;
+#[cfg(feature = "std")]
+use std::io::{Read, Seek, SeekFrom};
+
+#[cfg(feature = "std")]
+use crate::elf::file::ElfError;
+use crate::elf::file::{slice, Result};
+
+/// Source of ELF file contents.
+pub trait Source {
+    /// Get size of the file.
+    fn size(&self) -> u64;
+
+    /// Get bytes range of the file.
+    ///
+    /// # Parameters
+    /// - `offset` - given range offset.
+    /// - `size`   - given range size.
+    ///
+    /// # Returns
+    /// - Bytes - in case of success.
+    /// - `ElfError::Truncated` - if range does not fit into the file.
+    /// - `ElfError::Io` - if range can not be read.
+    fn bytes(&self, offset: u64, size: u64) -> Result<&[u8]>;
+}
+
+/// Borrowed file contents, either bytes in memory or a source.
+#[derive(Clone, Copy)]
+pub(crate) enum Contents<'a> {
+    /// In-memory or memory mapped file contents.
+    Bytes(&'a [u8]),
+    /// Source reading contents on demand.
+    Source(&'a dyn Source),
+}
+
+impl<'a> Contents<'a> {
+    /// Get size of the file.
+    pub(crate) fn size(&self) -> u64 {
+        match self {
+            Contents::Bytes(data)    => data.len() as u64,
+            Contents::Source(source) => source.size(),
+        }
+    }
+
+    /// Get bytes range of the file.
+    pub(crate) fn bytes(&self, offset: u64, size: u64) -> Result<&'a [u8]> {
+        match self {
+            Contents::Bytes(data)    => slice(data, offset, size),
+            Contents::Source(source) => source.bytes(offset, size),
+        }
+    }
+}
+
+/// Source reading byte ranges through `Read + Seek`.
+///
+/// Every range is read once and kept until the source is dropped, so bytes
+/// borrowed from the source stay valid. Ranges lying inside of the longest
+/// range read at a lower or equal offset are not read again.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct StreamSource<R: Read + Seek> {
+    /// Underlying reader.
+    reader: RefCell<R>,
+    /// Size of the file.
+    size: u64,
+    /// Read ranges, never removed while the source lives.
+    ranges: RefCell<Vec<Box<[u8]>>>,
+    /// Longest read range by its offset, as range end and `ranges` index.
+    index: RefCell<BTreeMap<u64, (u64, usize)>>,
+}
+
+#[cfg(feature = "std")]
+impl<R: Read + Seek> StreamSource<R> {
+    /// Construct new stream source.
+    ///
+    /// # Parameters
+    /// - `reader` - given reader.
+    ///
+    /// # Returns
+    /// - `StreamSource` - in case of success.
+    /// - `ElfError::Io` - if size of the stream can not be determined.
+    pub fn new(mut reader: R) -> Result<StreamSource<R>> {
+        let size = reader.seek(SeekFrom::End(0)).map_err(|e| ElfError::Io(e.kind()))?;
+
+        Ok(StreamSource {
+            reader: RefCell::new(reader),
+            size,
+            ranges: RefCell::new(Vec::new()),
+            index:  RefCell::new(BTreeMap::new()),
+        })
+    }
+
+    /// Get reader back, dropping read ranges.
+    pub fn into_inner(self) -> R {
+        self.reader.into_inner()
+    }
+
+    /// Get number of bytes read from the reader so far.
+    pub fn bytes_read(&self) -> usize {
+        self.ranges.borrow().iter().map(|range| range.len()).sum()
+    }
+
+    /// Get range bytes.
+    ///
+    /// # Parameters
+    /// - `range`  - given index of read range.
+    /// - `offset` - given offset inside of the range.
+    /// - `size`   - given size of bytes.
+    fn range(&self, range: usize, offset: usize, size: usize) -> &[u8] {
+        let ranges = self.ranges.borrow();
+        let bytes  = &ranges[range][offset..offset + size];
+
+        // Ranges are boxed and never removed or modified while the source
+        // lives, so their bytes outlive the borrow of the vector.
+        unsafe { &*(bytes as *const [u8]) }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Read + Seek> Source for StreamSource<R> {
+    fn size(&self) -> u64 {
+        self.size
+    }
+
+    fn bytes(&self, offset: u64, size: u64) -> Result<&[u8]> {
+        let end = offset.checked_add(size).filter(|&end| end <= self.size).ok_or(ElfError::Truncated)?;
+
+        let cached = self
+            .index
+            .borrow()
+            .range(..=offset)
+            .next_back()
+            .filter(|(_, (range_end, _))| *range_end >= end)
+            .map(|(&start, &(_, range))| (range, start));
+
+        if let Some((range, start)) = cached {
+            return Ok(self.range(range, (offset - start) as usize, size as usize));
+        }
+
+        let len        = usize::try_from(size).map_err(|_| ElfError::Truncated)?;
+        let mut bytes  = vec![0; len].into_boxed_slice();
+        let mut reader = self.reader.borrow_mut();
+
+        reader.seek(SeekFrom::Start(offset)).map_err(|e| ElfError::Io(e.kind()))?;
+        reader.read_exact(&mut bytes).map_err(|e| ElfError::Io(e.kind()))?;
+
+        let mut ranges = self.ranges.borrow_mut();
+        ranges.push(bytes);
+
+        let range = ranges.len() - 1;
+        drop(ranges);
+
+        let mut index = self.index.borrow_mut();
+        let longest   = index.entry(offset).or_insert((end, range));
+
+        if longest.0 < end {
+            *longest = (end, range);
+        }
+
+        Ok(self.range(range, 0, len))
+    }
+}
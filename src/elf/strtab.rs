@@ -0,0 +1,111 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! String table module.
+
+use alloc::borrow::Cow;
+
+use crate::elf::file::{cstr, Result};
+
+/// View over string table, borrowed or decompressed.
+#[derive(Debug, Clone)]
+pub struct StringTable<'a> {
+    /// String table bytes.
+    data: Cow<'a, [u8]>,
+}
+
+impl<'a> StringTable<'a> {
+    /// Construct new string table view.
+    ///
+    /// # Parameters
+    /// - `data` - given string table bytes.
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Self {
+        StringTable { data: data.into() }
+    }
+
+    /// Get string table bytes.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Get string at given offset.
+    ///
+    /// # Parameters
+    /// - `offset` - given string offset.
+    ///
+    /// # Returns
+    /// - String - in case of success.
+    /// - `ElfError` - if offset is out of table or string is not valid UTF-8.
+    pub fn get(&self, offset: usize) -> Result<&str> {
+        cstr(&self.data, offset)
+    }
+
+    /// Get iterator over all strings of the table.
+    pub fn iter(&self) -> StringIter<'_> {
+        StringIter::new(&*self.data)
+    }
+}
+
+/// String of string table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableString<'a> {
+    /// Offset of the string inside of the table.
+    pub offset: usize,
+    /// String bytes without terminating NUL, copied if the table is not
+    /// borrowed.
+    pub bytes: Cow<'a, [u8]>,
+}
+
+/// Iterator over NUL-terminated strings of string table.
+///
+/// Every string is yielded, including empty ones, so offsets of consecutive
+/// strings are contiguous. Last string may lack terminating NUL.
+#[derive(Debug, Clone)]
+pub struct StringIter<'a> {
+    /// String table bytes.
+    data: Cow<'a, [u8]>,
+    /// Offset of the next string.
+    pos: usize,
+}
+
+impl<'a> StringIter<'a> {
+    /// Construct new string table iterator.
+    ///
+    /// # Parameters
+    /// - `data` - given string table bytes.
+    pub fn new(data: impl Into<Cow<'a, [u8]>>) -> Self {
+        StringIter { data: data.into(), pos: 0 }
+    }
+}
+
+impl<'a> Iterator for StringIter<'a> {
+    type Item = TableString<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.data.get(self.pos..).filter(|rest| !rest.is_empty())?;
+        let len  = rest.iter().position(|&b| b == 0).unwrap_or(rest.len());
+        let pos  = self.pos;
+
+        self.pos += len + 1;
+
+        let bytes = match &self.data {
+            Cow::Borrowed(data) => Cow::Borrowed(&data[pos..pos + len]),
+            Cow::Owned(data)    => Cow::Owned(data[pos..pos + len].to_vec()),
+        };
+
+        Some(TableString { offset: pos, bytes })
+    }
+}
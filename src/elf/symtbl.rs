@@ -16,7 +16,12 @@
 
 //! ELF symbol table module.
 
-use crate::elf::{Elf32_Addr, Elf32_Half, Elf32_Word};
+use core::fmt;
+
+use crate::elf::{
+    Elf32_Addr, Elf32_Half, Elf32_Word, Elf64_Addr, Elf64_Half, Elf64_Word,
+    Elf64_Xword,
+};
 
 /// ELF symbol table entry struct.
 ///
@@ -24,6 +29,7 @@ use crate::elf::{Elf32_Addr, Elf32_Half, Elf32_Word};
 /// relocate a program’s symbolic definitions and references. A symbol table
 /// index is a subscript into this array. Index 0 both designates
 /// the first entry in the table and serves as the undefined symbol index.
+#[derive(Debug, Clone, Copy)]
 #[repr(C, packed)]
 pub struct Elf32_Sym {
     /// This member holds an index into the object file’s symbol string table,
@@ -49,6 +55,52 @@ pub struct Elf32_Sym {
     pub st_shndx: Elf32_Half,
 }
 
+/// ELF symbol table entry struct for the 64-bit class of object files.
+///
+/// It holds the same members as `Elf32_Sym`, reordered so that the 64-bit
+/// value and size are naturally aligned.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C, packed)]
+pub struct Elf64_Sym {
+    /// Symbol name, index into the symbol string table.
+    pub st_name: Elf64_Word,
+    /// Symbol type and binding attributes.
+    pub st_info: u8,
+    /// Symbol visibility.
+    pub st_other: u8,
+    /// Index of the section the symbol is defined in.
+    pub st_shndx: Elf64_Half,
+    /// Symbol value.
+    pub st_value: Elf64_Addr,
+    /// Size of the associated object.
+    pub st_size: Elf64_Xword,
+}
+
+impl From<Elf32_Sym> for Elf64_Sym {
+    fn from(sym: Elf32_Sym) -> Self {
+        Elf64_Sym {
+            st_name:  sym.st_name,
+            st_info:  sym.st_info,
+            st_other: sym.st_other,
+            st_shndx: sym.st_shndx,
+            st_value: sym.st_value as Elf64_Addr,
+            st_size:  sym.st_size as Elf64_Xword,
+        }
+    }
+}
+
+impl Elf64_Sym {
+    /// Get symbol binding.
+    pub fn binding(&self) -> SymbolBinding {
+        SymbolBinding::from(elf32_st_bind(self.st_info))
+    }
+
+    /// Get symbol type.
+    pub fn symbol_type(&self) -> SymbolType {
+        SymbolType::from(elf32_st_type(self.st_info))
+    }
+}
+
 /// Extracts the binding information from the symbol info.
 ///
 /// # Parameters
@@ -96,6 +148,13 @@ pub const STB_GLOBAL: u8 = 1;
 /// Weak symbols resemble global symbols, but their definitions have lower
 /// precedence.
 pub const STB_WEAK: u8 = 2;
+/// Values in this inclusive range are reserved for operating system-specific
+/// semantics.
+pub const STB_LOOS: u8 = 10;
+pub const STB_HIOS: u8 = 12;
+/// Unique symbol, there is only one definition of it in the whole process.
+/// GNU extension.
+pub const STB_GNU_UNIQUE: u8 = 10;
 /// Values in this inclusive range are reserved for processor-specific
 /// semantics.
 pub const STB_LOPROC: u8 = 13;
@@ -118,7 +177,118 @@ pub const STT_SECTION: u8 = 3;
 /// section index is SHN_ABS, and it precedes the other STB_LOCAL symbols for
 /// the file, if it is present.
 pub const STT_FILE: u8 = 4;
+/// The symbol labels an uninitialized common block.
+pub const STT_COMMON: u8 = 5;
+/// The symbol specifies a Thread-Local Storage entity.
+pub const STT_TLS: u8 = 6;
+/// Complex relocation expression.
+pub const STT_RELC: u8 = 8;
+/// Signed complex relocation expression.
+pub const STT_SRELC: u8 = 9;
+/// Values in this inclusive range are reserved for operating system-specific
+/// semantics.
+pub const STT_LOOS: u8 = 10;
+pub const STT_HIOS: u8 = 12;
+/// The symbol is an indirect function, its value is the address of resolver
+/// returning the actual function address. GNU extension.
+pub const STT_GNU_IFUNC: u8 = 10;
 /// Values in this inclusive range are reserved for processor-specific
 /// semantics.
 pub const STT_LOPROC: u8 = 13;
 pub const STT_HIPROC: u8 = 15;
+
+elf_enum! {
+    /// Symbol binding, upper bits of `st_info` field of symbol.
+    ///
+    /// `GnuUnique` shares its value with the first operating system-specific
+    /// binding and applies only to objects using GNU extensions.
+    pub enum SymbolBinding: u8 {
+        /// Local symbol.
+        Local = STB_LOCAL => "LOCAL",
+        /// Global symbol.
+        Global = STB_GLOBAL => "GLOBAL",
+        /// Weak symbol.
+        Weak = STB_WEAK => "WEAK",
+        /// Unique global symbol.
+        GnuUnique = STB_GNU_UNIQUE => "UNIQUE",
+    }
+}
+
+impl fmt::Display for SymbolBinding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SymbolBinding::Unknown(raw @ STB_LOPROC..=STB_HIPROC) => write!(f, "<processor specific>: {}", raw),
+            SymbolBinding::Unknown(raw @ STB_LOOS..=STB_HIOS)     => write!(f, "<OS specific>: {}", raw),
+            SymbolBinding::Unknown(raw)                           => write!(f, "<unknown>: {}", raw),
+            known => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
+
+elf_enum! {
+    /// Symbol type, lower bits of `st_info` field of symbol.
+    ///
+    /// `GnuIfunc` shares its value with the first operating system-specific
+    /// type and applies only to objects using GNU extensions.
+    pub enum SymbolType: u8 {
+        /// Unspecified type.
+        NoType = STT_NOTYPE => "NOTYPE",
+        /// Data object.
+        Object = STT_OBJECT => "OBJECT",
+        /// Function or other executable code.
+        Func = STT_FUNC => "FUNC",
+        /// Section.
+        Section = STT_SECTION => "SECTION",
+        /// Source file.
+        File = STT_FILE => "FILE",
+        /// Uninitialized common block.
+        Common = STT_COMMON => "COMMON",
+        /// Thread-local storage entity.
+        Tls = STT_TLS => "TLS",
+        /// Complex relocation expression.
+        Relc = STT_RELC => "RELC",
+        /// Signed complex relocation expression.
+        Srelc = STT_SRELC => "SRELC",
+        /// Indirect function.
+        GnuIfunc = STT_GNU_IFUNC => "IFUNC",
+    }
+}
+
+impl fmt::Display for SymbolType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SymbolType::Unknown(raw @ STT_LOPROC..=STT_HIPROC) => write!(f, "<processor specific>: {}", raw),
+            SymbolType::Unknown(raw @ STT_LOOS..=STT_HIOS)     => write!(f, "<OS specific>: {}", raw),
+            SymbolType::Unknown(raw)                           => write!(f, "<unknown>: {}", raw),
+            known => f.write_str(known.name().unwrap_or_default()),
+        }
+    }
+}
+
+/// Extracts the visibility from the symbol `st_other` field.
+///
+/// # Parameters
+/// - `other` - given symbol `st_other` field.
+///
+/// # Returns
+/// Symbol visibility.
+pub fn elf64_st_visibility(other: u8) -> u8 {
+    other & 0x3
+}
+
+// ELF symbol visibility enumeration.
+
+/// The visibility of the symbol is as specified by its binding type.
+pub const STV_DEFAULT: u8 = 0;
+/// The meaning of this visibility attribute may be defined by processor
+/// supplements to further constrain hidden symbols.
+pub const STV_INTERNAL: u8 = 1;
+/// The symbol is not visible to components outside of the defining component.
+pub const STV_HIDDEN: u8 = 2;
+/// The symbol is visible to other components but it can not be preempted.
+pub const STV_PROTECTED: u8 = 3;
+
+/// The function follows variant procedure call standard (AArch64).
+pub const STO_AARCH64_VARIANT_PCS: u8 = 0x80;
+/// The function follows variant calling convention (RISC-V).
+pub const STO_RISCV_VARIANT_CC: u8 = 0x80;
@@ -0,0 +1,160 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Table view module.
+//!
+//! Tables of fixed-size entries (section and program headers, symbols,
+//! relocations, dynamic entries) are borrowed from the file contents and
+//! decoded to the common 64-bit layout only when an entry is accessed.
+
+use core::fmt;
+use core::mem::size_of;
+
+use crate::elf::file::{read, ElfError, Result};
+
+/// Borrowed view over table of fixed-size entries.
+pub struct Table<'a, T> {
+    /// Table bytes.
+    data: &'a [u8],
+    /// Size of a single entry in the file.
+    entsize: usize,
+    /// Entry decoder.
+    decode: fn(&[u8], usize) -> Result<T>,
+}
+
+impl<'a, T> Table<'a, T> {
+    /// Construct table of raw `R` entries decoded to `T`.
+    ///
+    /// # Parameters
+    /// - `data` - given table bytes.
+    pub fn new<R: Copy + Into<T>>(data: &'a [u8]) -> Self {
+        Self::with_entsize::<R>(data, size_of::<R>())
+    }
+
+    /// Construct table of raw `R` entries decoded to `T` with entry size
+    /// taken from the file, e.g. `e_shentsize`.
+    ///
+    /// # Parameters
+    /// - `data`    - given table bytes.
+    /// - `entsize` - given entry size, not less than size of `R`.
+    pub fn with_entsize<R: Copy + Into<T>>(data: &'a [u8], entsize: usize) -> Self {
+        Table { data, entsize: entsize.max(size_of::<R>()), decode: decode::<R, T> }
+    }
+
+    /// Get number of entries.
+    pub fn len(&self) -> usize {
+        self.data.len() / self.entsize
+    }
+
+    /// Check whether table has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get size of a single entry in the file.
+    pub fn entsize(&self) -> usize {
+        self.entsize
+    }
+
+    /// Get table bytes.
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Get table entry.
+    ///
+    /// # Parameters
+    /// - `index` - given entry index.
+    ///
+    /// # Returns
+    /// - Decoded entry - in case of success.
+    /// - `ElfError::Truncated` - if index is out of table.
+    pub fn get(&self, index: usize) -> Result<T> {
+        if index >= self.len() {
+            return Err(ElfError::Truncated);
+        }
+
+        (self.decode)(self.data, index * self.entsize)
+    }
+
+    /// Get iterator over table entries.
+    pub fn iter(&self) -> TableIter<'a, T> {
+        TableIter { table: *self, index: 0 }
+    }
+}
+
+impl<T> Clone for Table<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Table<'_, T> {}
+
+impl<T> fmt::Debug for Table<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Table")
+            .field("len", &self.len())
+            .field("entsize", &self.entsize)
+            .finish()
+    }
+}
+
+impl<'a, T> IntoIterator for Table<'a, T> {
+    type Item     = T;
+    type IntoIter = TableIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over table entries.
+#[derive(Debug)]
+pub struct TableIter<'a, T> {
+    /// Table.
+    table: Table<'a, T>,
+    /// Index of the next entry.
+    index: usize,
+}
+
+impl<T> Clone for TableIter<'_, T> {
+    fn clone(&self) -> Self {
+        TableIter { table: self.table, index: self.index }
+    }
+}
+
+impl<T> Iterator for TableIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.table.get(self.index).ok()?;
+        self.index += 1;
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.table.len().saturating_sub(self.index);
+        (left, Some(left))
+    }
+}
+
+impl<T> ExactSizeIterator for TableIter<'_, T> {}
+
+/// Decode raw entry at given offset.
+fn decode<R: Copy + Into<T>, T>(data: &[u8], offset: usize) -> Result<T> {
+    read::<R>(data, offset).map(Into::into)
+}
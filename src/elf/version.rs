@@ -0,0 +1,321 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! ELF symbol versioning module.
+//!
+//! Symbol versions are described by three tables:
+//! - `.gnu.version` (`SHT_GNU_VERSYM`, `DT_VERSYM`) holds a 16-bit version
+//!   index for each dynamic symbol.
+//! - `.gnu.version_d` (`SHT_GNU_VERDEF`, `DT_VERDEF`) holds versions defined
+//!   by the object, each definition followed by its names (`Verdaux`).
+//! - `.gnu.version_r` (`SHT_GNU_VERNEED`, `DT_VERNEED`) holds versions needed
+//!   from other objects, grouped by file (`Vernaux` entries).
+//!
+//! Entries of both definition and needed tables are linked by offsets relative
+//! to the current entry. The layout is the same for both file classes.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+use core::marker::PhantomData;
+
+use crate::elf::file::read;
+use crate::elf::{Elf64_Half, Elf64_Word};
+
+/// Version definition entry.
+#[repr(C, packed)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Elf64_Verdef {
+    /// Version revision, see `VER_DEF_*` values.
+    pub vd_version: Elf64_Half,
+    /// Version information flags, see `VER_FLG_*` values.
+    pub vd_flags: Elf64_Half,
+    /// Version index, referenced by `.gnu.version` entries.
+    pub vd_ndx: Elf64_Half,
+    /// Number of associated `Verdaux` entries.
+    pub vd_cnt: Elf64_Half,
+    /// Hash of the version name.
+    pub vd_hash: Elf64_Word,
+    /// Offset of the first `Verdaux` entry relative to this entry.
+    pub vd_aux: Elf64_Word,
+    /// Offset of the next `Verdef` entry relative to this entry.
+    pub vd_next: Elf64_Word,
+}
+
+/// Version definition name entry.
+///
+/// The first entry holds the name of the version, the following ones hold
+/// names of its parents.
+#[repr(C, packed)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Elf64_Verdaux {
+    /// Offset of the name in the dynamic string table.
+    pub vda_name: Elf64_Word,
+    /// Offset of the next `Verdaux` entry relative to this entry.
+    pub vda_next: Elf64_Word,
+}
+
+/// Needed versions entry of one file.
+#[repr(C, packed)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Elf64_Verneed {
+    /// Version of the structure, see `VER_NEED_*` values.
+    pub vn_version: Elf64_Half,
+    /// Number of associated `Vernaux` entries.
+    pub vn_cnt: Elf64_Half,
+    /// Offset of the file name in the dynamic string table.
+    pub vn_file: Elf64_Word,
+    /// Offset of the first `Vernaux` entry relative to this entry.
+    pub vn_aux: Elf64_Word,
+    /// Offset of the next `Verneed` entry relative to this entry.
+    pub vn_next: Elf64_Word,
+}
+
+/// Needed version entry.
+#[repr(C, packed)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Elf64_Vernaux {
+    /// Hash of the version name.
+    pub vna_hash: Elf64_Word,
+    /// Version information flags, see `VER_FLG_*` values.
+    pub vna_flags: Elf64_Half,
+    /// Version index, referenced by `.gnu.version` entries.
+    pub vna_other: Elf64_Half,
+    /// Offset of the version name in the dynamic string table.
+    pub vna_name: Elf64_Word,
+    /// Offset of the next `Vernaux` entry relative to this entry.
+    pub vna_next: Elf64_Word,
+}
+
+/// ELF 32 version definition entry.
+pub type Elf32_Verdef = Elf64_Verdef;
+/// ELF 32 version definition name entry.
+pub type Elf32_Verdaux = Elf64_Verdaux;
+/// ELF 32 needed versions entry.
+pub type Elf32_Verneed = Elf64_Verneed;
+/// ELF 32 needed version entry.
+pub type Elf32_Vernaux = Elf64_Vernaux;
+
+// Version revision enumeration.
+
+/// No version.
+pub const VER_DEF_NONE: u16 = 0;
+/// Current version of `Verdef` structure.
+pub const VER_DEF_CURRENT: u16 = 1;
+/// No version.
+pub const VER_NEED_NONE: u16 = 0;
+/// Current version of `Verneed` structure.
+pub const VER_NEED_CURRENT: u16 = 1;
+
+// Version information flags.
+
+/// Version definition of the file itself.
+pub const VER_FLG_BASE: u16 = 0x1;
+/// Weak version identifier.
+pub const VER_FLG_WEAK: u16 = 0x2;
+/// Reference exists for informational purposes only.
+pub const VER_FLG_INFO: u16 = 0x4;
+
+// Special version indexes.
+
+/// Symbol is local, not available outside the object.
+pub const VER_NDX_LOCAL: u16 = 0;
+/// Symbol is global, it has the base version.
+pub const VER_NDX_GLOBAL: u16 = 1;
+/// Beginning of reserved indexes.
+pub const VER_NDX_LORESERVE: u16 = 0xff00;
+/// Symbol is to be eliminated.
+pub const VER_NDX_ELIMINATE: u16 = 0xff01;
+
+/// The symbol is hidden, it is not the default version of the symbol.
+pub const VERSYM_HIDDEN: u16 = 0x8000;
+/// Mask of the version index in `.gnu.version` entry.
+pub const VERSYM_VERSION: u16 = 0x7fff;
+
+/// Version table entry linked to the next entry by relative offset.
+pub trait VersionEntry: Copy {
+    /// Get offset of the next entry relative to this one, 0 for the last entry.
+    fn next(&self) -> u32;
+}
+
+impl VersionEntry for Elf64_Verdef {
+    fn next(&self) -> u32 {
+        self.vd_next
+    }
+}
+
+impl VersionEntry for Elf64_Verdaux {
+    fn next(&self) -> u32 {
+        self.vda_next
+    }
+}
+
+impl VersionEntry for Elf64_Verneed {
+    fn next(&self) -> u32 {
+        self.vn_next
+    }
+}
+
+impl VersionEntry for Elf64_Vernaux {
+    fn next(&self) -> u32 {
+        self.vna_next
+    }
+}
+
+/// Iterator over linked version table entries.
+///
+/// Yields entries together with their offsets in the table. Iteration stops
+/// after entry with zero next offset, or at entry not fitting into the table.
+#[derive(Debug, Clone)]
+pub struct VersionIter<'a, T> {
+    /// Version table bytes.
+    data: &'a [u8],
+    /// Offset of the next entry.
+    offset: Option<usize>,
+    /// Entry type marker.
+    entry: PhantomData<T>,
+}
+
+/// Iterator over version definitions.
+pub type VerdefIter<'a> = VersionIter<'a, Elf64_Verdef>;
+/// Iterator over names of version definition.
+pub type VerdauxIter<'a> = VersionIter<'a, Elf64_Verdaux>;
+/// Iterator over files of needed versions.
+pub type VerneedIter<'a> = VersionIter<'a, Elf64_Verneed>;
+/// Iterator over needed versions of file.
+pub type VernauxIter<'a> = VersionIter<'a, Elf64_Vernaux>;
+
+impl<'a, T: VersionEntry> VersionIter<'a, T> {
+    /// Construct new iterator.
+    ///
+    /// # Parameters
+    /// - `data`   - given version table bytes.
+    /// - `offset` - given offset of the first entry.
+    pub fn new(data: &'a [u8], offset: usize) -> Self {
+        VersionIter { data, offset: Some(offset), entry: PhantomData }
+    }
+}
+
+impl<T: VersionEntry> Iterator for VersionIter<'_, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset.take()?;
+        let entry: T = read(self.data, offset).ok()?;
+
+        self.offset = match entry.next() {
+            0    => None,
+            next => offset.checked_add(next as usize),
+        };
+
+        Some((offset, entry))
+    }
+}
+
+/// Version of dynamic symbol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVersion<'a> {
+    /// Default version defined by the object (`name@@VERSION`).
+    Default(&'a str),
+    /// Non-default version defined by the object (`name@VERSION`).
+    Hidden(&'a str),
+    /// Version needed from other object with its version index
+    /// (`name@VERSION (index)`).
+    Needed(&'a str, u16),
+}
+
+impl<'a> SymbolVersion<'a> {
+    /// Get version name.
+    pub fn name(&self) -> &'a str {
+        match *self {
+            SymbolVersion::Default(name)   => name,
+            SymbolVersion::Hidden(name)    => name,
+            SymbolVersion::Needed(name, _) => name,
+        }
+    }
+
+    /// Check whether version is the default version of the symbol.
+    pub fn is_default(&self) -> bool {
+        matches!(self, SymbolVersion::Default(_))
+    }
+}
+
+impl fmt::Display for SymbolVersion<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SymbolVersion::Default(name)       => write!(f, "@@{}", name),
+            SymbolVersion::Hidden(name)        => write!(f, "@{}", name),
+            SymbolVersion::Needed(name, index) => write!(f, "@{} ({})", name, index),
+        }
+    }
+}
+
+/// Version needed from other object together with symbols requiring it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NeededVersion<'a> {
+    /// Version name, e.g. `GLIBC_2.34`.
+    pub name: &'a str,
+    /// Version index, referenced by `.gnu.version` entries.
+    pub index: u16,
+    /// Version information flags, see `VER_FLG_*` values.
+    pub flags: u16,
+    /// Names of dynamic symbols bound to the version.
+    pub symbols: Vec<&'a str>,
+}
+
+/// Versions needed from one file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionNeed<'a> {
+    /// Needed file name, e.g. `libc.so.6`.
+    pub file: &'a str,
+    /// Needed versions.
+    pub versions: Vec<NeededVersion<'a>>,
+}
+
+/// Split version name into prefix and numeric components.
+///
+/// # Parameters
+/// - `name` - given version name, e.g. `GLIBC_2.2.5`.
+///
+/// # Returns
+/// - Prefix and numbers, e.g. `GLIBC` and `[2, 2, 5]` - if name ends with
+///   dot separated numbers.
+/// - `None` - otherwise, e.g. for `GLIBC_PRIVATE`.
+pub fn split_version_name(name: &str) -> Option<(&str, Vec<u32>)> {
+    let (prefix, numbers) = name.rsplit_once('_')?;
+    let numbers = numbers.split('.').map(|n| n.parse().ok()).collect::<Option<Vec<u32>>>()?;
+    Some((prefix, numbers))
+}
+
+/// Compare version names.
+///
+/// # Parameters
+/// - `a` - given first version name.
+/// - `b` - given second version name.
+///
+/// # Returns
+/// - Ordering of versions - if both names have the same prefix and numeric
+///   components, e.g. `GLIBC_2.4` is less than `GLIBC_2.34`.
+/// - `None` - otherwise.
+pub fn compare_version_names(a: &str, b: &str) -> Option<Ordering> {
+    let (a_prefix, a_numbers) = split_version_name(a)?;
+    let (b_prefix, b_numbers) = split_version_name(b)?;
+
+    match a_prefix == b_prefix {
+        true  => Some(a_numbers.cmp(&b_numbers)),
+        false => None,
+    }
+}
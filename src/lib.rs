@@ -0,0 +1,40 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Readelf library - ELF structures definitions and parser.
+//!
+//! ELF structures and decoding (`elf` and `decompress` modules) need only
+//! `core` and `alloc`, so with `std` feature disabled the library builds for
+//! `#![no_std]` targets such as bootloaders and kernels. Archives, memory
+//! mapping, streaming source and printing need `std` feature, enabled by
+//! default.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(non_camel_case_types)]
+#![allow(dead_code)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod archive;
+pub mod decompress;
+#[cfg(feature = "std")]
+pub mod demangle;
+pub mod elf;
+#[cfg(feature = "std")]
+pub mod mmap;
+#[cfg(feature = "std")]
+pub mod parser;
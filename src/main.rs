@@ -16,22 +16,348 @@
 
 //! Readelf entry point.
 
-#![allow(non_camel_case_types)]
-#![allow(dead_code)]
+mod args;
 
-mod parser;
-mod elf;
+use std::io::{ErrorKind, Read};
+use std::path::Path;
+use std::process::ExitCode;
 
-use elf::elfhdr::Elf32_Ehdr;
-use parser::ElfParser;
+use readelf::archive::{Archive, ARMAG};
+use readelf::elf::elfhdr::EI_NIDENT;
+use readelf::elf::file::ElfFile;
+use readelf::mmap::Mmap;
+use readelf::outln;
+use readelf::parser::ElfParser;
+use readelf::parser::archive::{check_archive_index, print_archive_index, qualified_name};
+use readelf::parser::json::{archive_index_json, Json};
+use readelf::parser::output;
 
-// TODO: add command line flags handler.
-static BYTES: &[u8;528] = include_bytes!("../tmp/app");
+use args::OutputStyle;
 
+/// Input file path standing for standard input.
+const STDIN: &str = "-";
 
-fn main() {
-    let header = unsafe { *(BYTES.as_ptr() as *const Elf32_Ehdr) };
+/// Name of standard input in messages.
+const STDIN_NAME: &str = "<stdin>";
 
-    let elf_parser = ElfParser::new(header);
-    elf_parser.print_header();
-}
\ No newline at end of file
+fn main() -> ExitCode {
+    let args = match args::parse(std::env::args().skip(1)) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("readelf: Error: {}", err);
+            eprintln!("{}", args::USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if args.help {
+        outln!("{}", args::USAGE);
+        return ExitCode::SUCCESS;
+    }
+
+    if !args.has_display_option() {
+        eprintln!("{}", args::USAGE);
+        return ExitCode::FAILURE;
+    }
+
+    let show_name     = args.files.len() > 1;
+    let mut status    = ExitCode::SUCCESS;
+    let mut documents = Vec::new();
+
+    for path in &args.files {
+        if process_file(&args, path, show_name, &mut documents) != ExitCode::SUCCESS {
+            status = ExitCode::FAILURE;
+        }
+
+        if output::error().is_some() {
+            break;
+        }
+    }
+
+    if args.output == OutputStyle::Json {
+        outln!("{}", Json::Array(documents));
+    }
+
+    // Reader of the output exiting early is not an error.
+    match output::flush() {
+        None | Some(ErrorKind::BrokenPipe) => status,
+        Some(kind) => {
+            eprintln!("readelf: Error: Unable to write output: {}", kind);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Read input file contents.
+///
+/// # Parameters
+/// - `path` - given file path, `-` stands for standard input.
+///
+/// Regular files are memory mapped, standard input is read into memory.
+///
+/// # Returns
+/// - File contents - in case of success.
+/// - Error message - otherwise.
+fn read_file(path: &str) -> Result<Mmap, String> {
+    if path == STDIN {
+        let mut bytes = Vec::new();
+
+        return match std::io::stdin().read_to_end(&mut bytes) {
+            Ok(_)  => Ok(Mmap::from(bytes)),
+            Err(_) => Err(format!("Input file '{}' is not readable", STDIN_NAME)),
+        };
+    }
+
+    match std::fs::metadata(path) {
+        Ok(meta) if !meta.is_file() => return Err(format!("'{}' is not an ordinary file", path)),
+        Ok(_)                       => {}
+        Err(err) if err.kind() == ErrorKind::NotFound => return Err(format!("'{}': No such file", path)),
+        Err(err)                    => return Err(format!("'{}': {}", path, err)),
+    }
+
+    Mmap::open(path).map_err(|_| format!("Input file '{}' is not readable", path))
+}
+
+/// Display information about input file.
+///
+/// # Parameters
+/// - `args`      - given command line arguments.
+/// - `path`      - given file path, `-` stands for standard input.
+/// - `show_name` - given flag whether to precede output with file name.
+/// - `documents` - given JSON documents of displayed objects to append to.
+///
+/// # Returns
+/// - `ExitCode::FAILURE` - if file is not readable or malformed.
+/// - `ExitCode::SUCCESS` - otherwise.
+fn process_file(args: &args::Args, path: &str, show_name: bool, documents: &mut Vec<Json>) -> ExitCode {
+    let bytes = match read_file(path) {
+        Ok(bytes) => bytes,
+        Err(err)  => {
+            eprintln!("readelf: Error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let name = match path {
+        STDIN => STDIN_NAME,
+        _     => path,
+    };
+
+    if bytes.len() < ARMAG.len() {
+        eprintln!("readelf: Error: {}: Failed to read file's magic number", name);
+        return ExitCode::FAILURE;
+    }
+
+    if Archive::is_archive(&bytes) {
+        return process_archive(args, name, &bytes, documents);
+    }
+
+    if args.archive_index {
+        eprintln!("readelf: Error: File {} is not an archive so its index cannot be displayed.", name);
+    }
+
+    if show_name && args.output == OutputStyle::Text {
+        outln!();
+        outln!("File: {}", name);
+    }
+
+    match ElfFile::parse(&bytes) {
+        Ok(file) => process_object(args, name, file, documents),
+        Err(err) => {
+            eprintln!("readelf: Error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Display information about every member of static archive.
+///
+/// # Parameters
+/// - `args`      - given command line arguments.
+/// - `path`      - given archive path.
+/// - `data`      - given archive contents.
+/// - `documents` - given JSON documents of displayed objects to append to.
+///
+/// # Returns
+/// - `ExitCode::FAILURE` - if archive or any of its members is malformed.
+/// - `ExitCode::SUCCESS` - otherwise.
+fn process_archive(args: &args::Args, path: &str, data: &[u8], documents: &mut Vec<Json>) -> ExitCode {
+    let archive = match Archive::parse(data) {
+        Ok(archive) => archive,
+        Err(err)    => {
+            eprintln!("readelf: Error: {}: {}", path, err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut status = ExitCode::SUCCESS;
+
+    if args.archive_index {
+        match archive.symbol_index() {
+            Some(index) => {
+                let valid = match args.output {
+                    OutputStyle::Text => print_archive_index(path, &archive, index),
+                    OutputStyle::Json => {
+                        let (document, valid) = archive_index_json(path, &archive, index);
+                        documents.push(document);
+                        valid
+                    }
+                };
+
+                if !valid {
+                    status = ExitCode::FAILURE;
+                }
+                check_archive_index(path, &archive, index);
+            }
+            None => {
+                if args.output == OutputStyle::Text && archive.members().next().is_some() {
+                    outln!("{} has no archive index", path);
+                }
+                eprintln!("readelf: Error: {}: unable to dump the index as none was found", path);
+            }
+        }
+
+        if !args.has_object_option() {
+            return status;
+        }
+    }
+
+    for member in archive.members() {
+        let member = match member {
+            Ok(member) => member,
+            Err(err)   => {
+                eprintln!("readelf: Error: {}: {}", path, err);
+                return ExitCode::FAILURE;
+            }
+        };
+
+        let name = qualified_name(path, &archive, &member);
+
+        let contents;
+        let data = match archive.is_thin() {
+            false => member.data,
+            true  => {
+                let path = member.path(Path::new(&path));
+
+                match Mmap::open(&path) {
+                    Ok(bytes) => {
+                        contents = bytes;
+                        &contents[..]
+                    }
+                    Err(err) => {
+                        eprintln!("readelf: Warning: {}: member file '{}' cannot be read: {}", name, path.display(), err);
+                        status = ExitCode::FAILURE;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        if data.len() < EI_NIDENT {
+            eprintln!("readelf: Error: {}: Failed to read file header", name);
+            status = ExitCode::FAILURE;
+            continue;
+        }
+
+        if args.output == OutputStyle::Text {
+            outln!();
+            outln!("File: {}", name);
+        }
+
+        let result = match ElfFile::parse(data) {
+            Ok(file) => process_object(args, &name, file, documents),
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                ExitCode::FAILURE
+            }
+        };
+
+        if result != ExitCode::SUCCESS {
+            status = ExitCode::FAILURE;
+        }
+
+        if output::error().is_some() {
+            break;
+        }
+    }
+
+    status
+}
+
+/// Display information about ELF object.
+///
+/// # Parameters
+/// - `args`      - given command line arguments.
+/// - `name`      - given file name.
+/// - `file`      - given ELF file.
+/// - `documents` - given JSON documents of displayed objects to append to.
+///
+/// # Returns
+/// - `ExitCode::FAILURE` - if version ceilings check failed.
+/// - `ExitCode::SUCCESS` - otherwise.
+fn process_object(args: &args::Args, name: &str, file: ElfFile, documents: &mut Vec<Json>) -> ExitCode {
+    let mut elf_parser = ElfParser::new(file);
+    elf_parser.set_demangle(args.demangle);
+    elf_parser.set_decompress(args.decompress);
+
+    let mut status = ExitCode::SUCCESS;
+
+    if args.output == OutputStyle::Json {
+        if !args.max_versions.is_empty() && !elf_parser.check_version_ceilings(&args.max_versions) {
+            status = ExitCode::FAILURE;
+        }
+
+        documents.push(elf_parser.to_json(name, &args.json_views()));
+        return status;
+    }
+
+    if args.file_header {
+        elf_parser.print_header();
+    }
+
+    if args.section_headers {
+        elf_parser.print_section_headers(args.section_details, !args.file_header);
+    }
+
+    if args.section_groups {
+        elf_parser.print_section_groups();
+    }
+
+    if args.dynamic {
+        elf_parser.print_dynamic();
+    }
+
+    if args.relocs {
+        elf_parser.print_relocs();
+    }
+
+    if args.dyn_syms {
+        elf_parser.print_dyn_syms();
+    }
+
+    if args.histogram {
+        elf_parser.print_histogram();
+    }
+
+    if args.version_info {
+        elf_parser.print_version_info();
+    }
+
+    if args.version_requirements {
+        elf_parser.print_version_requirements();
+    }
+
+    if !args.max_versions.is_empty() && !elf_parser.check_version_ceilings(&args.max_versions) {
+        status = ExitCode::FAILURE;
+    }
+
+    if !args.dumps.is_empty() {
+        elf_parser.print_section_dumps(&args.dumps);
+    }
+
+    if args.notes {
+        elf_parser.print_notes();
+    }
+
+    status
+}
@@ -0,0 +1,172 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Memory mapped file module.
+//!
+//! Files are mapped read-only, so pages are read from disk only when parser
+//! touches them. Platforms without `mmap` read the whole file instead.
+
+use std::fs::File;
+use std::io;
+use std::ops::Deref;
+use std::path::Path;
+
+#[cfg(unix)]
+mod sys {
+    use std::ffi::{c_int, c_void};
+
+    #[cfg(target_pointer_width = "64")]
+    pub type off_t = i64;
+    #[cfg(not(target_pointer_width = "64"))]
+    pub type off_t = i32;
+
+    /// Pages may be read.
+    pub const PROT_READ: c_int = 0x1;
+    /// Changes are private.
+    pub const MAP_PRIVATE: c_int = 0x2;
+    /// Value returned by `mmap` on failure.
+    pub const MAP_FAILED: *mut c_void = usize::MAX as *mut c_void;
+
+    unsafe extern "C" {
+        pub fn mmap(addr: *mut c_void, len: usize, prot: c_int, flags: c_int, fd: c_int, off: off_t) -> *mut c_void;
+        pub fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    }
+}
+
+/// File contents storage.
+#[derive(Debug)]
+enum Backing {
+    /// Read-only memory mapping.
+    #[cfg(unix)]
+    Mapped {
+        /// Mapping address.
+        ptr: *mut std::ffi::c_void,
+        /// Mapping size.
+        len: usize,
+    },
+    /// Contents read into memory.
+    Owned(Vec<u8>),
+}
+
+/// Read-only file contents, memory mapped when possible.
+///
+/// Mapped file must not be modified while it is mapped, otherwise contents
+/// may change under the parser.
+#[derive(Debug)]
+pub struct Mmap {
+    /// File contents storage.
+    backing: Backing,
+}
+
+impl Mmap {
+    /// Map file into memory.
+    ///
+    /// # Parameters
+    /// - `path` - given file path.
+    ///
+    /// # Returns
+    /// - File contents - in case of success.
+    /// - `io::Error` - otherwise.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Mmap> {
+        let file = File::open(path)?;
+        Self::map(&file)
+    }
+
+    /// Map opened file into memory.
+    ///
+    /// Empty files and files which can not be mapped, e.g. pipes, are read
+    /// into memory instead.
+    ///
+    /// # Parameters
+    /// - `file` - given opened file.
+    ///
+    /// # Returns
+    /// - File contents - in case of success.
+    /// - `io::Error` - otherwise.
+    #[cfg(unix)]
+    pub fn map(file: &File) -> io::Result<Mmap> {
+        use std::os::unix::io::AsRawFd;
+
+        let len = usize::try_from(file.metadata()?.len()).map_err(|_| io::Error::from(io::ErrorKind::OutOfMemory))?;
+
+        if len == 0 {
+            return Self::read(file);
+        }
+
+        let ptr = unsafe {
+            sys::mmap(std::ptr::null_mut(), len, sys::PROT_READ, sys::MAP_PRIVATE, file.as_raw_fd(), 0)
+        };
+
+        if ptr == sys::MAP_FAILED {
+            return Self::read(file);
+        }
+
+        Ok(Mmap { backing: Backing::Mapped { ptr, len } })
+    }
+
+    /// Read opened file into memory.
+    ///
+    /// # Parameters
+    /// - `file` - given opened file.
+    ///
+    /// # Returns
+    /// - File contents - in case of success.
+    /// - `io::Error` - otherwise.
+    #[cfg(not(unix))]
+    pub fn map(file: &File) -> io::Result<Mmap> {
+        Self::read(file)
+    }
+
+    /// Read whole file into memory.
+    fn read(mut file: &File) -> io::Result<Mmap> {
+        let mut bytes = Vec::new();
+        io::Read::read_to_end(&mut file, &mut bytes)?;
+        Ok(Mmap::from(bytes))
+    }
+}
+
+impl From<Vec<u8>> for Mmap {
+    fn from(bytes: Vec<u8>) -> Self {
+        Mmap { backing: Backing::Owned(bytes) }
+    }
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match &self.backing {
+            #[cfg(unix)]
+            Backing::Mapped { ptr, len } => unsafe { std::slice::from_raw_parts(*ptr as *const u8, *len) },
+            Backing::Owned(bytes)        => bytes,
+        }
+    }
+}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Drop for Mmap {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Backing::Mapped { ptr, len } = self.backing {
+            unsafe { sys::munmap(ptr, len) };
+        }
+    }
+}
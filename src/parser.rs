@@ -16,44 +16,116 @@
 
 //! ELF parser module.
 
+/// Write formatted text to standard output, see `output` module.
+#[macro_export]
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::parser::output::write(format_args!($($arg)*))
+    };
+}
+
+/// Write formatted line to standard output, see `output` module.
+#[macro_export]
+macro_rules! outln {
+    () => {
+        $crate::parser::output::write(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::parser::output::write(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub mod archive;
+pub mod dump;
+pub mod dynamic;
+pub mod groups;
+pub mod histogram;
+pub mod json;
+pub mod notes;
+pub mod output;
+pub mod relocs;
+pub mod sections;
+pub mod symbols;
+pub mod version;
+
+use crate::demangle;
 use crate::elf::elfhdr::*;
+use crate::elf::file::ElfFile;
 
-pub struct ElfParser {
-    hdr: Elf32_Ehdr,
+pub struct ElfParser<'a> {
+    file: ElfFile<'a>,
+    /// Whether symbol names are demangled.
+    demangle: bool,
+    /// Whether compressed sections are decompressed before dumping.
+    decompress: bool,
 }
 
-impl ElfParser {
-    pub fn new(hdr: Elf32_Ehdr) -> ElfParser {
-        ElfParser { hdr }
+impl<'a> ElfParser<'a> {
+    pub fn new(file: ElfFile<'a>) -> ElfParser<'a> {
+        ElfParser { file, demangle: false, decompress: false }
+    }
+
+    /// Set whether symbol names are demangled.
+    ///
+    /// # Parameters
+    /// - `demangle` - given demangle flag.
+    pub fn set_demangle(&mut self, demangle: bool) {
+        self.demangle = demangle;
+    }
+
+    /// Set whether compressed sections are decompressed before dumping.
+    ///
+    /// # Parameters
+    /// - `decompress` - given decompress flag.
+    pub fn set_decompress(&mut self, decompress: bool) {
+        self.decompress = decompress;
+    }
+
+    /// Get symbol name to display, demangled if requested.
+    ///
+    /// # Parameters
+    /// - `name` - given symbol name.
+    fn display_name(&self, name: &str) -> String {
+        match self.demangle {
+            true  => demangle::demangle(name).unwrap_or_else(|| name.to_string()),
+            false => name.to_string(),
+        }
+    }
+
+    /// Get parsed ELF file.
+    pub fn file(&self) -> &ElfFile<'a> {
+        &self.file
     }
 
     /// Print ELF header content.
     pub fn print_header(&self) {
-        println!("ELF Header:");
-        println!("  Magic:   {}",                                   self.get_magic());
-        println!("  Class:                             {}",         self.get_class());
-        println!("  Date:                              {}",         self.get_data());
-        println!("  Version:                           {}",         self.get_version());
-        println!("  Type:                              {}",         self.get_type());
-        println!("  Machine:                           {}",         self.get_machine());
-        println!("  Version:                           {:#x}",      self.hdr.e_version as u32);
-        println!("  Entry point address:               {:#x}",      self.hdr.e_entry as u32);
-        println!("  Start of program headers:          {} (bytes)", self.hdr.e_phoff as u32);
-        println!("  Start of section headers:          {} (bytes)", self.hdr.e_shoff as u32);
-        println!("  Flags:                             {:#x}",      self.hdr.e_flags as u32);
-        println!("  Size of this header:               {} (bytes)", self.hdr.e_ehsize as u16);
-        println!("  Size of program headers:           {} (bytes)", self.hdr.e_phentsize as u16);
-        println!("  Number of program headers:         {}",         self.hdr.e_phnum as u16);
-        println!("  Size of section headers:           {} (bytes)", self.hdr.e_shentsize as u16);
-        println!("  Number of section headers:         {}",         self.hdr.e_shnum as u16);
-        println!("  Section header string table index: {}",         self.hdr.e_shstrndx as u16);
+        let hdr = self.file.header();
+
+        outln!("ELF Header:");
+        outln!("  Magic:   {}",                                   self.get_magic());
+        outln!("  Class:                             {}",         self.get_class());
+        outln!("  Date:                              {}",         self.get_data());
+        outln!("  Version:                           {}",         self.get_version());
+        outln!("  Type:                              {}",         self.get_type());
+        outln!("  Machine:                           {}",         self.get_machine());
+        outln!("  Version:                           {:#x}",      { hdr.e_version });
+        outln!("  Entry point address:               {:#x}",      { hdr.e_entry });
+        outln!("  Start of program headers:          {} (bytes)", { hdr.e_phoff });
+        outln!("  Start of section headers:          {} (bytes)", { hdr.e_shoff });
+        outln!("  Flags:                             {:#x}",      { hdr.e_flags });
+        outln!("  Size of this header:               {} (bytes)", { hdr.e_ehsize });
+        outln!("  Size of program headers:           {} (bytes)", { hdr.e_phentsize });
+        outln!("  Number of program headers:         {}",         { hdr.e_phnum });
+        outln!("  Size of section headers:           {} (bytes)", { hdr.e_shentsize });
+        outln!("  Number of section headers:         {}",         { hdr.e_shnum });
+        outln!("  Section header string table index: {}",         { hdr.e_shstrndx });
     }
 
     /// Get ELF header identificator string representation.
     pub fn get_magic(&self) -> String {
         let mut s = String::with_capacity(56);
 
-        for i in self.hdr.e_ident {
+        for i in self.file.header().e_ident {
             s.push_str(format!("{:02x} ", i).as_str());
         }
         s
@@ -61,7 +133,7 @@ impl ElfParser {
 
     /// Get ELF header class string representation.
     pub fn get_class(&self) -> &str {
-        match self.hdr.e_ident[EI_CLASS] {
+        match self.file.header().e_ident[EI_CLASS] {
             ELFCLASS32   => "ELF32",
             ELFCLASS64   => "ELF64",
             ELFCLASSNONE => "None",
@@ -71,7 +143,7 @@ impl ElfParser {
 
     /// Get ELF header data string representation.
     pub fn get_data(&self) -> &str {
-        match self.hdr.e_ident[EI_DATA] {
+        match self.file.header().e_ident[EI_DATA] {
             ELFDATA2LSB => "Little endian",
             ELFDATA2MSB => "Big endian",
             ELFDATANONE => "None",
@@ -81,7 +153,7 @@ impl ElfParser {
 
     /// Get ELF header version string representation.
     pub fn get_version(&self) -> &str {
-        match self.hdr.e_ident[EI_VERSION] {
+        match self.file.header().e_ident[EI_VERSION] {
             EV_CURRENT  => "1, (current)",
             EV_NONE     => "0, (invalid)",
             _           => "?, (unknown)"
@@ -89,31 +161,48 @@ impl ElfParser {
     }
 
     /// Get ELF header type string representation.
-    pub fn get_type(&self) -> &str {
-        match self.hdr.e_type {
-            ET_REL    => "REL (Relocatable file)",
-            ET_EXEC   => "EXEC (Executable file)",
-            ET_DYN    => "DYN (Shared object file)",
-            ET_CORE   => "CORE (Core file)",
-            ET_LOPROC => "LOPROC (Processor-specific)",
-            ET_HIPROC => "HIPROC (Processor-specific)",
-            ET_NONE   => "NONE (No file type)",
-            _         => "? (Unknown)"
-        }
+    pub fn get_type(&self) -> String {
+        self.file.header().file_type().to_string()
     }
 
     /// Get ELF header machine string representation.
-    pub fn get_machine(&self) -> &str {
-        match self.hdr.e_machine {
-            EM_NONE	 => "No machine",
-            EM_M32	 => "AT&T WE 32100",
-            EM_SPARC => "SUN SPARC",
-            EM_386	 => "Intel 80386",
-            EM_68K	 => "Motorola m68k family",
-            EM_88K	 => "Motorola m88k family",
-            EM_860	 => "Intel 80860",
-            EM_MIPS	 => "MIPS R3000 big-endian",
-            _        => "?"
-        }
+    pub fn get_machine(&self) -> String {
+        self.file.header().machine().to_string()
+    }
+}
+
+/// Truncate symbol name to fit into given width.
+///
+/// Names longer than `width` are cut and marked with `[...]` suffix.
+///
+/// # Parameters
+/// - `width` - given maximum width.
+/// - `name`  - given symbol name.
+///
+/// # Returns
+/// Name that fits into `width` characters.
+pub fn truncate_symbol(width: usize, name: &str) -> String {
+    fit_symbol(width, name, name)
+}
+
+/// Fit displayed form of symbol name into given width.
+///
+/// Whether the name is marked with `[...]` is decided by length of the raw
+/// name, as GNU readelf does, so demangled name may be cut without the mark.
+///
+/// # Parameters
+/// - `width` - given maximum width.
+/// - `name`  - given raw symbol name.
+/// - `shown` - given displayed (e.g. demangled) symbol name.
+///
+/// # Returns
+/// Displayed name cut to `width` characters.
+pub fn fit_symbol(width: usize, name: &str, shown: &str) -> String {
+    if name.chars().count() <= width {
+        return shown.chars().take(width).collect();
     }
-}
\ No newline at end of file
+
+    let mut s: String = shown.chars().take(width.saturating_sub(5)).collect();
+    s.push_str("[...]");
+    s
+}
@@ -0,0 +1,205 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Archive symbol index display module.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use crate::archive::{Archive, Member, SymbolIndex};
+use crate::elf::file::ElfFile;
+use crate::elf::sechdr::*;
+use crate::elf::symtbl::*;
+use crate::mmap::Mmap;
+
+/// Print archive symbol index.
+///
+/// # Parameters
+/// - `name`    - given archive file name.
+/// - `archive` - given archive.
+/// - `index`   - given archive symbol index.
+///
+/// # Returns
+/// - `false` - if index is malformed.
+/// - `true`  - otherwise.
+pub fn print_archive_index(name: &str, archive: &Archive, index: &SymbolIndex) -> bool {
+    outln!(
+        "Index of archive {}: ({} entries, {:#x} bytes in the symbol table)",
+        name, index.len(), index.names_size()
+    );
+
+    let mut previous = None;
+    let mut count    = 0;
+
+    for sym in index.symbols() {
+        if previous != Some(sym.offset) {
+            match archive.member_at(sym.offset) {
+                Ok(member) => outln!(
+                    "Contents of binary {} at offset {:#x}",
+                    qualified_name(name, archive, &member), sym.offset
+                ),
+                Err(err)   => eprintln!("readelf: Error: {}: {}", name, err),
+            }
+            previous = Some(sym.offset);
+        }
+
+        outln!("\t{}", String::from_utf8_lossy(sym.name));
+        count += 1;
+    }
+
+    check_index_size(name, index, count)
+}
+
+/// Check that whole archive symbol index was read and its names table has no
+/// bytes left.
+///
+/// # Parameters
+/// - `name`  - given archive file name.
+/// - `index` - given archive symbol index.
+/// - `count` - given number of symbols read from the index.
+///
+/// # Returns
+/// - `false` - if index is malformed, the error is reported.
+/// - `true`  - otherwise.
+pub(crate) fn check_index_size(name: &str, index: &SymbolIndex, count: usize) -> bool {
+    if count < index.len() {
+        eprintln!("readelf: Error: {}: end of the symbol table reached before the end of the index", name);
+        return false;
+    }
+
+    match index.unused_names_size() {
+        0 => true,
+        1 => {
+            eprintln!(
+                "readelf: Error: {}: 1 byte remains in the symbol table, but without corresponding entries in the index table",
+                name
+            );
+            false
+        }
+        n => {
+            eprintln!(
+                "readelf: Error: {}: {} bytes remain in the symbol table, but without corresponding entries in the index table",
+                name, n
+            );
+            false
+        }
+    }
+}
+
+/// Check that archive symbol index agrees with global symbols defined by
+/// archive members.
+///
+/// Mismatches are reported as warnings, members which are not ELF files or
+/// missing thin archive members are ignored.
+///
+/// # Parameters
+/// - `name`    - given archive file name.
+/// - `archive` - given archive.
+/// - `index`   - given archive symbol index.
+pub fn check_archive_index(name: &str, archive: &Archive, index: &SymbolIndex) {
+    let mut indexed: BTreeMap<u64, BTreeSet<String>> = BTreeMap::new();
+
+    for sym in index.symbols() {
+        indexed
+            .entry(sym.offset)
+            .or_default()
+            .insert(String::from_utf8_lossy(sym.name).into_owned());
+    }
+
+    for member in archive.members() {
+        let Ok(member) = member else {
+            break;
+        };
+
+        let contents = match archive.is_thin() {
+            true  => Mmap::open(member.path(Path::new(name))).ok(),
+            false => None,
+        };
+
+        let data = contents.as_deref().unwrap_or(member.data);
+
+        let Ok(file) = ElfFile::parse(data) else {
+            continue;
+        };
+
+        let defined = defined_globals(&file);
+        let listed  = indexed.remove(&(member.offset as u64)).unwrap_or_default();
+
+        for sym in listed.difference(&defined) {
+            eprintln!(
+                "readelf: Warning: {}: symbol '{}' is in the archive index but not defined by the member",
+                qualified_name(name, archive, &member), sym
+            );
+        }
+
+        for sym in defined.difference(&listed) {
+            eprintln!(
+                "readelf: Warning: {}: symbol '{}' is defined by the member but missing from the archive index",
+                qualified_name(name, archive, &member), sym
+            );
+        }
+    }
+
+    for offset in indexed.keys() {
+        eprintln!(
+            "readelf: Warning: {}: archive index refers to offset {:#x} which is not a member",
+            name, offset
+        );
+    }
+}
+
+/// Get name of archive member to display.
+///
+/// # Parameters
+/// - `name`    - given archive file name.
+/// - `archive` - given archive.
+/// - `member`  - given archive member.
+///
+/// # Returns
+/// `lib.a(member.o)` for regular archives or `lib.a[member.o]` for thin ones.
+pub fn qualified_name(name: &str, archive: &Archive, member: &Member) -> String {
+    match archive.is_thin() {
+        true  => format!("{}[{}]", name, member.name),
+        false => format!("{}({})", name, member.name),
+    }
+}
+
+/// Get names of global symbols defined in symbol tables of ELF file.
+///
+/// # Parameters
+/// - `file` - given ELF file.
+fn defined_globals(file: &ElfFile) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    let symtabs   = file.section_headers().iter().filter(|s| s.sh_type == SHT_SYMTAB);
+
+    for shdr in symtabs {
+        for index in 0..file.symbol_count(shdr) {
+            let Ok(sym) = file.symbol(shdr, index) else {
+                break;
+            };
+
+            if elf32_st_bind(sym.st_info) == STB_LOCAL || sym.st_shndx as u32 == SHN_UNDEF {
+                continue;
+            }
+
+            if let Ok(name) = file.symbol_name(shdr, &sym) {
+                names.insert(name.to_string());
+            }
+        }
+    }
+
+    names
+}
@@ -0,0 +1,465 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Section contents dump display module.
+
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+use std::io::{self, Write};
+
+use crate::elf::file::ElfError;
+use crate::elf::reloc::elf64_r_type;
+use crate::elf::relocate::{relocate_data, Skipped};
+use crate::elf::sechdr::*;
+use crate::elf::strtab::StringIter;
+use crate::parser::ElfParser;
+
+/// Kind of section contents dump.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DumpKind {
+    /// Section bytes as they are in the file.
+    Hex,
+    /// Section bytes with relocations applied.
+    Relocated,
+    /// Printable strings of section.
+    String,
+}
+
+impl DumpKind {
+    /// Dump kinds in order they are printed for a single section.
+    const ORDER: [DumpKind; 3] = [DumpKind::Hex, DumpKind::Relocated, DumpKind::String];
+}
+
+/// Section contents dump request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DumpRequest {
+    /// Kind of dump.
+    pub kind: DumpKind,
+    /// Section name or index.
+    pub section: String,
+}
+
+impl ElfParser<'_> {
+    /// Print requested section contents dumps.
+    ///
+    /// Dumps are printed in order of sections rather than in order of
+    /// requests, as GNU readelf does. Request by name applies to all
+    /// sections with that name.
+    ///
+    /// # Parameters
+    /// - `requests` - given dump requests.
+    pub fn print_section_dumps(&self, requests: &[DumpRequest]) {
+        let (dumps, missing) = self.resolve_dumps(requests);
+
+        for (index, kind) in dumps {
+            self.print_section_dump(index, kind);
+        }
+
+        report_missing_dumps(&missing);
+    }
+
+    /// Match dump requests against sections.
+    ///
+    /// Requests of sections which do not exist by name are reported.
+    ///
+    /// # Parameters
+    /// - `requests` - given dump requests.
+    ///
+    /// # Returns
+    /// Section indexes with kinds of dumps in order they are printed, and
+    /// requested section indexes which do not exist.
+    pub(crate) fn resolve_dumps(&self, requests: &[DumpRequest]) -> (Vec<(usize, DumpKind)>, BTreeSet<u64>) {
+        let shdrs       = self.file.section_headers();
+        let mut kinds   = vec![Vec::new(); shdrs.len()];
+        let mut missing = BTreeSet::new();
+
+        // Named requests are matched from the last one given.
+        for request in requests.iter().rev() {
+            if parse_index(&request.section).is_some() {
+                continue;
+            }
+
+            let mut found = false;
+
+            for (index, shdr) in shdrs.iter().enumerate() {
+                if self.file.section_name(shdr).is_ok_and(|name| name == request.section) {
+                    kinds[index].push(request.kind);
+                    found = true;
+                }
+            }
+
+            if !found {
+                eprintln!(
+                    "readelf: Warning: Section '{}' was not dumped because it does not exist",
+                    request.section
+                );
+            }
+        }
+
+        for request in requests {
+            match parse_index(&request.section) {
+                Some(index) if index < shdrs.len() as u64 => kinds[index as usize].push(request.kind),
+                Some(index) => {
+                    missing.insert(index);
+                }
+                None => {}
+            }
+        }
+
+        let dumps = kinds
+            .iter()
+            .enumerate()
+            .flat_map(|(index, kinds)| {
+                DumpKind::ORDER
+                    .into_iter()
+                    .filter(|kind| kinds.contains(kind))
+                    .map(move |kind| (index, kind))
+            })
+            .collect();
+
+        (dumps, missing)
+    }
+
+    /// Print section contents dump.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `kind`  - given kind of dump.
+    fn print_section_dump(&self, index: usize, kind: DumpKind) {
+        let shdr = self.file.section_headers()[index];
+        let name = self.file.section_name(&shdr).unwrap_or("<corrupt>");
+
+        if shdr.sh_size == 0 || shdr.sh_type == SHT_NOBITS {
+            outln!("Section '{}' has no data to dump.", name);
+            return;
+        }
+
+        match kind {
+            DumpKind::Hex       => self.print_hex_section(index, &shdr, name),
+            DumpKind::Relocated => self.print_relocated_section(index, &shdr, name),
+            DumpKind::String    => self.print_string_section(index, &shdr, name),
+        }
+    }
+
+    /// Print section contents as they are in the file.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `shdr`  - given section header.
+    /// - `name`  - given section name.
+    fn print_hex_section(&self, index: usize, shdr: &Elf64_Shdr, name: &str) {
+        let data = match self.file.raw_section_data(shdr) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return;
+            }
+        };
+
+        outln!();
+        outln!("Hex dump of section '{}':", name);
+
+        let Some(data) = self.dump_data(shdr, data, name) else {
+            return;
+        };
+
+        // The user might expect relocations against the section to be applied.
+        if self.has_relocations(index) {
+            outln!(
+                " NOTE: This section has relocations against it, but these have NOT been applied to this dump."
+            );
+        }
+
+        print_hex_dump(shdr.sh_addr, &data);
+        outln!();
+    }
+
+    /// Print section contents with relocations applied.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `shdr`  - given section header.
+    /// - `name`  - given section name.
+    fn print_relocated_section(&self, index: usize, shdr: &Elf64_Shdr, name: &str) {
+        // Decompressed contents can only be relocated after the header is printed.
+        let relocated = match self.decompress {
+            true  => self.file.raw_section_data(shdr).map(|_| None),
+            false => self
+                .file
+                .raw_section_data(shdr)
+                .and_then(|data| relocate_data(&self.file, index, data.to_vec()))
+                .map(Some),
+        };
+
+        let relocated = match relocated {
+            Ok(relocated) => relocated,
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return;
+            }
+        };
+
+        outln!();
+        outln!("Hex dump of section '{}':", name);
+
+        let relocated = match relocated {
+            Some(relocated) => relocated,
+            None => {
+                let data = self.file.raw_section_data(shdr).unwrap_or_default();
+
+                let Some(data) = self.dump_data(shdr, data, name) else {
+                    return;
+                };
+
+                match relocate_data(&self.file, index, data.into_owned()) {
+                    Ok(relocated) => relocated,
+                    Err(err) => {
+                        eprintln!("readelf: Error: {}", err);
+                        return;
+                    }
+                }
+            }
+        };
+
+        report_skipped_relocs(&relocated.skipped, name);
+
+        print_hex_dump(shdr.sh_addr, &relocated.data);
+        outln!();
+    }
+
+    /// Print printable strings of section with their offsets.
+    ///
+    /// Unprintable characters before a string are skipped, control characters
+    /// inside of it are shown as `^X` and new line ends the line, next part
+    /// of the string is printed on a continuation line.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `shdr`  - given section header.
+    /// - `name`  - given section name.
+    fn print_string_section(&self, index: usize, shdr: &Elf64_Shdr, name: &str) {
+        let data = match self.file.raw_section_data(shdr) {
+            Ok(data) => data,
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return;
+            }
+        };
+
+        outln!();
+        outln!("String dump of section '{}':", name);
+
+        let Some(data) = self.dump_data(shdr, data, name) else {
+            return;
+        };
+
+        if self.has_relocations(index) {
+            outln!(
+                "  Note: This section has relocations against it, but these have NOT been applied to this dump."
+            );
+        }
+
+        // Strings may contain any bytes, so they are not printed as `str`.
+        let mut out        = Vec::new();
+        let mut continuing = false;
+
+        for string in StringIter::new(&*data) {
+            let mut offset = string.offset;
+            let mut bytes  = &*string.bytes;
+
+            while let Some(start) = bytes.iter().position(|&b| is_print(b)) {
+                offset += start;
+                bytes   = &bytes[start..];
+
+                match continuing {
+                    true  => out.extend_from_slice(b"            "),
+                    false => out.extend_from_slice(format!("  [{:6x}]  ", offset).as_bytes()),
+                }
+
+                continuing = false;
+
+                let len = bytes.iter().position(|&b| b == b'\n').unwrap_or(bytes.len());
+
+                for &b in &bytes[..len] {
+                    match b {
+                        0x00..=0x1f | 0x7f => out.extend_from_slice(&[b'^', b.wrapping_add(0x40)]),
+                        _                  => out.push(b),
+                    }
+                }
+
+                if len < bytes.len() {
+                    out.extend_from_slice(b"\\n\n");
+                    continuing = len + 1 < bytes.len();
+                    offset    += len + 1;
+                    bytes      = &bytes[len + 1..];
+                } else {
+                    out.push(b'\n');
+                    break;
+                }
+            }
+        }
+
+        if out.is_empty() {
+            out.extend_from_slice(b"  No strings found in this section.");
+        }
+
+        out.push(b'\n');
+        let _ = io::stdout().write_all(&out);
+    }
+
+    /// Get section contents to dump, decompressed if requested.
+    ///
+    /// Errors are reported the way GNU readelf does, after the dump header.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    /// - `data` - given section contents as they are in the file.
+    /// - `name` - given section name.
+    ///
+    /// # Returns
+    /// - Section contents - in case of success.
+    /// - `None` - if section can not be decompressed.
+    pub(crate) fn dump_data<'d>(&'d self, shdr: &Elf64_Shdr, data: &'d [u8], name: &str) -> Option<Cow<'d, [u8]>> {
+        if !self.decompress {
+            return Some(Cow::Borrowed(data));
+        }
+
+        match self.file.section_data(shdr) {
+            Ok(data) => Some(data),
+            Err(ElfError::UnsupportedCompression(typ)) => {
+                eprintln!("readelf: Warning: section '{}' has unsupported compress type: {}", name, typ);
+                None
+            }
+            Err(ElfError::Malformed(err)) => {
+                eprintln!("readelf: Error: {}", err);
+                None
+            }
+            Err(_) => {
+                eprintln!("readelf: Error: Unable to decompress section {}", name);
+                None
+            }
+        }
+    }
+
+    /// Check whether section has non-empty relocation section against it.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    fn has_relocations(&self, index: usize) -> bool {
+        let shnum = self.file.section_headers().len();
+
+        self.file.section_headers().iter().any(|relsec| {
+            matches!(relsec.sh_type, SHT_REL | SHT_RELA)
+                && relsec.sh_info as usize == index
+                && relsec.sh_size != 0
+                && (relsec.sh_link as usize) < shnum
+        })
+    }
+}
+
+/// Report requested section indexes which do not exist.
+///
+/// # Parameters
+/// - `missing` - given section indexes.
+pub(crate) fn report_missing_dumps(missing: &BTreeSet<u64>) {
+    for index in missing {
+        eprintln!("readelf: Warning: Section {} was not dumped because it does not exist!", index);
+    }
+}
+
+/// Report relocations which were not applied to section contents.
+///
+/// Unsupported type is reported once per run of relocations of that type.
+///
+/// # Parameters
+/// - `skipped` - given skipped relocations.
+/// - `name`    - given section name.
+pub(crate) fn report_skipped_relocs(skipped: &[Skipped], name: &str) {
+    let mut prev_unsupported = None;
+
+    for skipped in skipped {
+        let rela = skipped.rela;
+        let typ  = elf64_r_type(rela.r_info);
+
+        if skipped.unsupported {
+            if prev_unsupported != Some(typ) {
+                eprintln!(
+                    "readelf: Warning: unable to apply unsupported reloc type {} to section {}",
+                    typ, name
+                );
+            }
+            prev_unsupported = Some(typ);
+        } else {
+            eprintln!(
+                "readelf: Warning: skipping invalid relocation offset {:#x} in section {}",
+                { rela.r_offset }, name
+            );
+        }
+    }
+}
+
+/// Check whether character is printable ASCII.
+fn is_print(b: u8) -> bool {
+    (0x20..0x7f).contains(&b)
+}
+
+/// Parse section index the way `strtoul` with base 0 does.
+///
+/// # Parameters
+/// - `section` - given section name or index.
+///
+/// # Returns
+/// - Section index - if whole string is a decimal, octal or hexadecimal number.
+/// - `None` - otherwise.
+fn parse_index(section: &str) -> Option<u64> {
+    if let Some(hex) = section.strip_prefix("0x").or_else(|| section.strip_prefix("0X")) {
+        return u64::from_str_radix(hex, 16).ok();
+    }
+
+    match section {
+        ""                      => Some(0),
+        s if s.starts_with('0') => u64::from_str_radix(s, 8).ok(),
+        s                       => s.parse().ok(),
+    }
+}
+
+/// Print bytes in four words plus ASCII layout.
+///
+/// # Parameters
+/// - `addr` - given address of the first byte.
+/// - `data` - given bytes to print.
+pub fn print_hex_dump(addr: u64, data: &[u8]) {
+    for (i, chunk) in data.chunks(16).enumerate() {
+        let mut line = format!("  0x{:08x} ", addr.wrapping_add(i as u64 * 16));
+
+        for j in 0..16 {
+            match chunk.get(j) {
+                Some(b) => line.push_str(&format!("{:02x}", b)),
+                None    => line.push_str("  "),
+            }
+
+            if j % 4 == 3 {
+                line.push(' ');
+            }
+        }
+
+        for &b in chunk {
+            line.push(if b.is_ascii_graphic() || b == b' ' { b as char } else { '.' });
+        }
+
+        outln!("{}", line);
+    }
+}
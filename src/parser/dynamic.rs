@@ -0,0 +1,280 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Dynamic section display module.
+
+use crate::elf::dynsec::*;
+use crate::elf::elfhdr::{Machine, EM_AARCH64, EM_MIPS, EM_PPC, EM_PPC64, EM_RISCV};
+use crate::elf::file::cstr;
+use crate::parser::ElfParser;
+
+impl ElfParser<'_> {
+    /// Print dynamic section entries.
+    pub fn print_dynamic(&self) {
+        let (Some((offset, _)), Ok(entries)) = (self.file.dynamic_location(), self.file.dynamic())
+        else {
+            outln!();
+            outln!("There is no dynamic section in this file.");
+            return;
+        };
+
+        let strtab  = self.file.dynamic_strtab(&entries);
+        let is_64   = self.file.is_64();
+        let machine = self.file.header().machine();
+
+        outln!();
+        outln!(
+            "Dynamic section at offset {:#x} contains {} {}:",
+            offset, entries.len(), if entries.len() == 1 { "entry" } else { "entries" }
+        );
+        outln!("  Tag        Type                         Name/Value");
+
+        for entry in &entries {
+            let tag  = entry.d_tag;
+            let name = get_dynamic_type(machine, entry.tag());
+
+            // Type column is padded the same way as `printf("%*s")` does.
+            let width = match is_64 {
+                true  => 19 - name.len() as isize,
+                false => 27 - name.len() as isize,
+            };
+
+            let tag = match is_64 {
+                true  => format!("0x{:016x}", tag),
+                false => format!("0x{:08x}", tag as u32),
+            };
+
+            outln!(
+                " {} ({}){}{}",
+                tag, name, " ".repeat(width.unsigned_abs().max(1)), self.dynamic_value(entry, strtab)
+            );
+        }
+    }
+
+    /// Get dynamic entry value string representation.
+    ///
+    /// # Parameters
+    /// - `entry`  - given dynamic entry.
+    /// - `strtab` - given dynamic string table.
+    pub(crate) fn dynamic_value(&self, entry: &Elf64_Dyn, strtab: Option<&[u8]>) -> String {
+        let val    = entry.val();
+        let string = || {
+            strtab
+                .and_then(|t| cstr(t, val as usize).ok())
+                .map_or_else(|| format!("<string table index: {:3}>", val), str::to_string)
+        };
+
+        let Ok(tag) = u32::try_from(entry.d_tag) else {
+            return format!("{:#x}", val);
+        };
+
+        let machine = self.file.header().e_machine;
+
+        match tag {
+            DT_NEEDED    => format!("Shared library: [{}]", string()),
+            DT_SONAME    => format!("Library soname: [{}]", string()),
+            DT_RPATH     => format!("Library rpath: [{}]", string()),
+            DT_RUNPATH   => format!("Library runpath: [{}]", string()),
+            DT_AUXILIARY => format!("Auxiliary library: [{}]", string()),
+            DT_FILTER    => format!("Filter library: [{}]", string()),
+            DT_CONFIG    => format!("Configuration file: [{}]", string()),
+            DT_DEPAUDIT  => format!("Dependency audit library: [{}]", string()),
+            DT_AUDIT     => format!("Audit library: [{}]", string()),
+            DT_PLTREL    => get_dynamic_type(self.file.header().machine(), DynamicTag::from(val as i64)),
+            DT_FLAGS     => get_dynamic_flags(val),
+            DT_FLAGS_1   => format!("Flags:{}", get_dynamic_flags_1(val)),
+            DT_POSFLAG_1 => format!("Flags:{}", get_flag_list(val, &POSFLAG_1_NAMES)),
+            DT_FEATURE_1 => format!("Flags:{}", get_flag_list(val, &FEATURE_1_NAMES)),
+            DT_BIND_NOW  => String::new(),
+            DT_PLTRELSZ | DT_RELASZ | DT_RELAENT | DT_STRSZ | DT_SYMENT | DT_RELSZ
+            | DT_RELENT | DT_RELRSZ | DT_RELRENT | DT_PLTPADSZ | DT_MOVEENT | DT_MOVESZ
+            | DT_PREINIT_ARRAYSZ | DT_INIT_ARRAYSZ | DT_FINI_ARRAYSZ | DT_GNU_CONFLICTSZ
+            | DT_GNU_LIBLISTSZ | DT_SYMINSZ | DT_SYMINENT => format!("{} (bytes)", val),
+            DT_VERDEFNUM | DT_VERNEEDNUM | DT_RELACOUNT | DT_RELCOUNT => val.to_string(),
+            DT_MIPS_LOCAL_GOTNO | DT_MIPS_SYMTABNO | DT_MIPS_UNREFEXTNO | DT_MIPS_GOTSYM
+                if machine == EM_MIPS => val.to_string(),
+            _ => format!("{:#x}", val),
+        }
+    }
+}
+
+/// Get dynamic entry tag string representation.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+/// - `tag`     - given dynamic entry tag.
+pub fn get_dynamic_type(machine: Machine, tag: DynamicTag) -> String {
+    if let DynamicTag::Unknown(raw) = tag
+        && let Ok(tag32) = u32::try_from(raw)
+        && let Some(name) = get_arch_dynamic_type(machine.into(), tag32)
+    {
+        return name.to_string();
+    }
+
+    tag.to_string()
+}
+
+/// Get processor-specific dynamic entry tag string representation.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+/// - `tag`     - given dynamic entry tag.
+fn get_arch_dynamic_type(machine: u16, tag: u32) -> Option<&'static str> {
+    Some(match (machine, tag) {
+        (EM_AARCH64, DT_AARCH64_BTI_PLT)     => "AARCH64_BTI_PLT",
+        (EM_AARCH64, DT_AARCH64_PAC_PLT)     => "AARCH64_PAC_PLT",
+        (EM_AARCH64, DT_AARCH64_VARIANT_PCS) => "AARCH64_VARIANT_PCS",
+        (EM_RISCV, DT_RISCV_VARIANT_CC)      => "RISCV_VARIANT_CC",
+        (EM_MIPS, DT_MIPS_RLD_VERSION)       => "MIPS_RLD_VERSION",
+        (EM_MIPS, DT_MIPS_FLAGS)             => "MIPS_FLAGS",
+        (EM_MIPS, DT_MIPS_BASE_ADDRESS)      => "MIPS_BASE_ADDRESS",
+        (EM_MIPS, DT_MIPS_LOCAL_GOTNO)       => "MIPS_LOCAL_GOTNO",
+        (EM_MIPS, DT_MIPS_SYMTABNO)          => "MIPS_SYMTABNO",
+        (EM_MIPS, DT_MIPS_UNREFEXTNO)        => "MIPS_UNREFEXTNO",
+        (EM_MIPS, DT_MIPS_GOTSYM)            => "MIPS_GOTSYM",
+        (EM_MIPS, DT_MIPS_RLD_MAP)           => "MIPS_RLD_MAP",
+        (EM_MIPS, DT_MIPS_RLD_MAP_REL)       => "MIPS_RLD_MAP_REL",
+        (EM_PPC, DT_PPC_GOT)                 => "PPC_GOT",
+        (EM_PPC, DT_PPC_OPT)                 => "PPC_OPT",
+        (EM_PPC64, DT_PPC64_GLINK)           => "PPC64_GLINK",
+        (EM_PPC64, DT_PPC64_OPD)             => "PPC64_OPD",
+        (EM_PPC64, DT_PPC64_OPDSZ)           => "PPC64_OPDSZ",
+        (EM_PPC64, DT_PPC64_OPT)             => "PPC64_OPT",
+        _ => return None,
+    })
+}
+
+/// `DT_FLAGS` values names.
+const FLAGS_NAMES: [(u64, &str); 5] = [
+    (DF_ORIGIN,     "ORIGIN"),
+    (DF_SYMBOLIC,   "SYMBOLIC"),
+    (DF_TEXTREL,    "TEXTREL"),
+    (DF_BIND_NOW,   "BIND_NOW"),
+    (DF_STATIC_TLS, "STATIC_TLS"),
+];
+
+/// `DT_FLAGS_1` values names.
+const FLAGS_1_NAMES: [(u64, &str); 31] = [
+    (DF_1_NOW,        "NOW"),
+    (DF_1_GLOBAL,     "GLOBAL"),
+    (DF_1_GROUP,      "GROUP"),
+    (DF_1_NODELETE,   "NODELETE"),
+    (DF_1_LOADFLTR,   "LOADFLTR"),
+    (DF_1_INITFIRST,  "INITFIRST"),
+    (DF_1_NOOPEN,     "NOOPEN"),
+    (DF_1_ORIGIN,     "ORIGIN"),
+    (DF_1_DIRECT,     "DIRECT"),
+    (DF_1_TRANS,      "TRANS"),
+    (DF_1_INTERPOSE,  "INTERPOSE"),
+    (DF_1_NODEFLIB,   "NODEFLIB"),
+    (DF_1_NODUMP,     "NODUMP"),
+    (DF_1_CONFALT,    "CONFALT"),
+    (DF_1_ENDFILTEE,  "ENDFILTEE"),
+    (DF_1_DISPRELDNE, "DISPRELDNE"),
+    (DF_1_DISPRELPND, "DISPRELPND"),
+    (DF_1_NODIRECT,   "NODIRECT"),
+    (DF_1_IGNMULDEF,  "IGNMULDEF"),
+    (DF_1_NOKSYMS,    "NOKSYMS"),
+    (DF_1_NOHDR,      "NOHDR"),
+    (DF_1_EDITED,     "EDITED"),
+    (DF_1_NORELOC,    "NORELOC"),
+    (DF_1_SYMINTPOSE, "SYMINTPOSE"),
+    (DF_1_GLOBAUDIT,  "GLOBAUDIT"),
+    (DF_1_SINGLETON,  "SINGLETON"),
+    (DF_1_STUB,       "STUB"),
+    (DF_1_PIE,        "PIE"),
+    (DF_1_KMOD,       "KMOD"),
+    (DF_1_WEAKFILTER, "WEAKFILTER"),
+    (DF_1_NOCOMMON,   "NOCOMMON"),
+];
+
+/// `DT_POSFLAG_1` values names.
+const POSFLAG_1_NAMES: [(u64, &str); 2] = [
+    (DF_P1_LAZY,      "LAZY"),
+    (DF_P1_GROUPPERM, "GROUPPERM"),
+];
+
+/// `DT_FEATURE_1` values names.
+const FEATURE_1_NAMES: [(u64, &str); 2] = [
+    (DTF_1_PARINIT, "PARINIT"),
+    (DTF_1_CONFEXP, "CONFEXP"),
+];
+
+/// Get list of flags names, each preceded by a space.
+///
+/// Unknown bits are appended as a single hexadecimal number, zero value is
+/// shown as `None`.
+///
+/// # Parameters
+/// - `val`   - given flags value.
+/// - `names` - given flags names.
+fn get_flag_list(mut val: u64, names: &[(u64, &str)]) -> String {
+    if val == 0 {
+        return " None".to_string();
+    }
+
+    let mut s = String::new();
+
+    for &(flag, name) in names {
+        if val & flag != 0 {
+            s.push(' ');
+            s.push_str(name);
+            val &= !flag;
+        }
+    }
+
+    if val != 0 {
+        s.push_str(&format!(" {:x}", val));
+    }
+    s
+}
+
+/// Get `DT_FLAGS` value string representation.
+///
+/// # Parameters
+/// - `val` - given flags value.
+///
+/// # Returns
+/// Space-separated flags names, such as `BIND_NOW STATIC_TLS`. Each unknown
+/// bit is shown as `unknown`.
+pub fn get_dynamic_flags(mut val: u64) -> String {
+    let mut names = Vec::new();
+
+    while val != 0 {
+        let flag = val & val.wrapping_neg();
+        val &= !flag;
+
+        names.push(
+            FLAGS_NAMES
+                .iter()
+                .find(|&&(f, _)| f == flag)
+                .map_or("unknown", |&(_, name)| name),
+        );
+    }
+
+    names.join(" ")
+}
+
+/// Get `DT_FLAGS_1` value string representation.
+///
+/// # Parameters
+/// - `val` - given flags value.
+///
+/// # Returns
+/// Flags names each preceded by a space, such as ` NOW PIE`.
+pub fn get_dynamic_flags_1(val: u64) -> String {
+    get_flag_list(val, &FLAGS_1_NAMES)
+}
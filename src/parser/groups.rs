@@ -0,0 +1,201 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Section groups display module.
+
+use crate::elf::group::*;
+use crate::elf::sechdr::*;
+use crate::elf::symtbl::*;
+use crate::parser::ElfParser;
+
+/// Maximum number of reported errors of each kind about group members.
+const MAX_GROUP_ERRORS: usize = 10;
+
+impl ElfParser<'_> {
+    /// Print section groups with their signatures and member sections.
+    pub fn print_section_groups(&self) {
+        let shdrs = self.file.section_headers();
+
+        if !shdrs.iter().any(|shdr| shdr.sh_type == SHT_GROUP) {
+            outln!("\nThere are no section groups in this file.");
+            return;
+        }
+
+        // Group containing each section, sections may belong to one group only.
+        let mut owners     = vec![None; shdrs.len()];
+        let mut big_errors = 0;
+        let mut dup_errors = 0;
+        let mut warned_0   = false;
+
+        for (index, shdr) in shdrs.iter().enumerate() {
+            if shdr.sh_type != SHT_GROUP {
+                continue;
+            }
+
+            let name = self.file.section_name(shdr).unwrap_or("<corrupt>");
+
+            let Some(signature) = self.group_signature(shdr, name) else {
+                continue;
+            };
+
+            if shdr.sh_entsize > shdr.sh_size {
+                eprintln!(
+                    "readelf: Error: Section {} has sh_entsize ({:#x}) which is larger than its size ({:#x})",
+                    name, { shdr.sh_entsize }, { shdr.sh_size }
+                );
+                continue;
+            }
+
+            let group = match self.file.section_group(shdr) {
+                Ok(group) => group,
+                Err(err) => {
+                    eprintln!("readelf: Error: {}", err);
+                    continue;
+                }
+            };
+
+            outln!();
+            outln!(
+                "{}group section [{:5}] `{}' [{}] contains {} sections:",
+                get_group_flags(group.flags), index, name, signature, group.len()
+            );
+            outln!("   [Index]    Name");
+
+            for member in group.members() {
+                let member = member as usize;
+
+                if member >= shdrs.len() {
+                    big_errors += 1;
+
+                    if big_errors <= MAX_GROUP_ERRORS {
+                        eprintln!(
+                            "readelf: Error: section [{:5}] in group section [{:5}] > maximum section [{:5}]",
+                            member, index, shdrs.len() - 1
+                        );
+                    }
+
+                    if big_errors == MAX_GROUP_ERRORS {
+                        eprintln!(
+                            "readelf: Warning: Further error messages about overlarge group section indices suppressed"
+                        );
+                    }
+                    continue;
+                }
+
+                if let Some(owner) = owners[member] {
+                    if member != 0 {
+                        dup_errors += 1;
+
+                        if dup_errors <= MAX_GROUP_ERRORS {
+                            eprintln!(
+                                "readelf: Error: section [{:5}] in group section [{:5}] already in group section [{:5}]",
+                                member, index, owner
+                            );
+                        }
+
+                        if dup_errors == MAX_GROUP_ERRORS {
+                            eprintln!(
+                                "readelf: Warning: Further error messages about already contained group sections suppressed"
+                            );
+                        }
+                        continue;
+                    }
+
+                    // Intel C/C++ compiler may put section 0 in a group.
+                    if !warned_0 {
+                        eprintln!("readelf: Error: section 0 in group section [{:5}]", owner);
+                        warned_0 = true;
+                    }
+                }
+
+                owners[member] = Some(index);
+
+                let name = self.file.section_name(&shdrs[member]).unwrap_or("<corrupt>");
+                outln!("   [{:5}]   {}", member, name);
+            }
+        }
+    }
+
+    /// Get signature of section group.
+    ///
+    /// Signature is the name of the symbol referred by `sh_info`, or the name
+    /// of the section if the symbol is a section symbol.
+    ///
+    /// # Parameters
+    /// - `shdr` - given group section header.
+    /// - `name` - given group section name.
+    ///
+    /// # Returns
+    /// - Group signature - in case of success.
+    /// - `None` - if group refers to invalid symbol, the error is reported.
+    pub(crate) fn group_signature(&self, shdr: &Elf64_Shdr, name: &str) -> Option<String> {
+        let symtab = self
+            .file
+            .section_header(shdr.sh_link as usize)
+            .ok()
+            .filter(|symtab| symtab.sh_type == SHT_SYMTAB);
+
+        let Some(symtab) = symtab else {
+            eprintln!("readelf: Error: Bad sh_link in group section `{}'", name);
+            return None;
+        };
+
+        let sym = match self.file.symbol(symtab, shdr.sh_info as usize) {
+            Ok(sym) => sym,
+            Err(_)  => {
+                eprintln!("readelf: Error: Bad sh_info in group section `{}'", name);
+                return None;
+            }
+        };
+
+        if elf32_st_type(sym.st_info) != STT_SECTION {
+            return Some(self.file.symbol_name(symtab, &sym).unwrap_or("<corrupt>").to_string());
+        }
+
+        match sym.st_shndx as usize {
+            0 => None,
+            i => self.file.section_header(i).ok(),
+        }
+        .map(|shdr| self.file.section_name(shdr).unwrap_or("<corrupt>").to_string())
+        .or_else(|| {
+            eprintln!("readelf: Error: Bad sh_info in group section `{}'", name);
+            None
+        })
+    }
+}
+
+/// Get section group flags string representation.
+///
+/// # Parameters
+/// - `flags` - given group flags.
+///
+/// # Returns
+/// Flags followed by a space, or empty string if there are no flags.
+pub fn get_group_flags(flags: u32) -> String {
+    match flags {
+        0          => return String::new(),
+        GRP_COMDAT => return "COMDAT ".to_string(),
+        _          => {}
+    }
+
+    format!(
+        "[0x{:x}: {}{}{}]",
+        flags,
+        if flags & GRP_MASKOS != 0 { "<OS specific>" } else { "" },
+        if flags & GRP_MASKPROC != 0 { "<PROC specific>" } else { "" },
+        if flags & !(GRP_COMDAT | GRP_MASKOS | GRP_MASKPROC) != 0 { "<unknown>" } else { "" },
+    )
+}
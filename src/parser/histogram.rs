@@ -0,0 +1,216 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Hash table bucket list length histogram display module.
+
+use crate::elf::dynsec::{DynamicTag, Elf64_Dyn};
+use crate::elf::file::ElfFile;
+use crate::elf::hash::{GnuHash, SysvHash};
+use crate::elf::sechdr::SHT_GNU_HASH;
+use crate::parser::ElfParser;
+
+impl ElfParser<'_> {
+    /// Print histogram of bucket list lengths of symbol hash tables.
+    pub fn print_histogram(&self) {
+        let entries = self.file.dynamic().unwrap_or_default();
+
+        match self.file.sysv_hash(&entries) {
+            Ok(Some(hash)) => print_sysv_histogram(&hash),
+            Ok(None)       => {}
+            Err(err)       => eprintln!("readelf: Error: Unable to read hash table: {}", err),
+        }
+
+        match self.file.gnu_hash(&entries) {
+            Ok(Some(hash)) => {
+                let name = self.gnu_hash_name(&entries);
+
+                print_gnu_histogram(&hash, name);
+                self.check_gnu_hash_reachability(&entries, name);
+            }
+            Ok(None)       => {}
+            Err(err)       => eprintln!("readelf: Error: Unable to read GNU hash table: {}", err),
+        }
+    }
+
+    /// Get name of GNU hash table section.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// Name of section at `DT_GNU_HASH` address, or of the first
+    /// `SHT_GNU_HASH` section if there is no such entry, `.gnu.hash` if the
+    /// section is not found.
+    pub(crate) fn gnu_hash_name(&self, entries: &[Elf64_Dyn]) -> &str {
+        let addr = ElfFile::dynamic_value(entries, DynamicTag::GnuHash);
+
+        self.file
+            .section_headers()
+            .iter()
+            .find(|s| match addr {
+                Some(addr) => s.sh_addr == addr,
+                None       => s.sh_type == SHT_GNU_HASH,
+            })
+            .and_then(|s| self.file.section_name(s).ok())
+            .unwrap_or(".gnu.hash")
+    }
+
+    /// Warn about exported dynamic symbols that can not be found through GNU
+    /// hash table.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `name`    - given name of hash table section.
+    pub(crate) fn check_gnu_hash_reachability(&self, entries: &[Elf64_Dyn], name: &str) {
+        let unreachable = match self.file.unreachable_gnu_hash_symbols() {
+            Ok(unreachable) => unreachable,
+            Err(err)        => {
+                eprintln!("readelf: Warning: Unable to verify {}: {}", name, err);
+                return;
+            }
+        };
+
+        for index in unreachable {
+            let sym_name = self.file.dynamic_symbol_name(entries, index).unwrap_or("<corrupt>");
+            eprintln!(
+                "readelf: Warning: Symbol '{}' (index {}) is not reachable through {}",
+                sym_name, index, name
+            );
+        }
+    }
+}
+
+/// Print histogram of SysV hash table.
+///
+/// # Parameters
+/// - `hash` - given hash table.
+fn print_sysv_histogram(hash: &SysvHash) {
+    let nbucket = hash.nbucket();
+    let lengths = sysv_bucket_lengths(hash);
+
+    outln!();
+    outln!(
+        "Histogram for bucket list length (total of {} {}):",
+        nbucket, if nbucket == 1 { "bucket" } else { "buckets" }
+    );
+    print_bucket_histogram(&lengths);
+}
+
+/// Print histogram of GNU hash table.
+///
+/// # Parameters
+/// - `hash` - given hash table.
+/// - `name` - given name of hash table section.
+fn print_gnu_histogram(hash: &GnuHash, name: &str) {
+    let nbuckets = hash.nbuckets();
+    let lengths  = gnu_bucket_lengths(hash);
+
+    outln!();
+    outln!(
+        "Histogram for `{}' bucket list length (total of {} {}):",
+        name, nbuckets, if nbuckets == 1 { "bucket" } else { "buckets" }
+    );
+    print_bucket_histogram(&lengths);
+}
+
+/// Print histogram table of bucket list lengths.
+///
+/// # Parameters
+/// - `lengths` - given length of each bucket list.
+fn print_bucket_histogram(lengths: &[u64]) {
+    outln!(" Length  Number     % of total  Coverage");
+
+    let counts = bucket_length_counts(lengths);
+
+    if counts.is_empty() {
+        return;
+    }
+
+    let nsyms = lengths.iter().sum::<u64>();
+    let total = lengths.len() as f64;
+    outln!("      0  {:<10} ({:5.1}%)", counts[0], counts[0] as f64 * 100.0 / total);
+
+    let mut nzero_counts = 0;
+
+    for (i, &count) in counts.iter().enumerate().skip(1) {
+        nzero_counts += count * i as u64;
+        outln!(
+            "{:7}  {:<10} ({:5.1}%)    {:5.1}%",
+            i, count, count as f64 * 100.0 / total, nzero_counts as f64 * 100.0 / nsyms as f64
+        );
+    }
+}
+
+/// Get length of each bucket list of SysV hash table.
+///
+/// A chain visiting a symbol twice is reported as corrupt and cut there.
+///
+/// # Parameters
+/// - `hash` - given hash table.
+pub(crate) fn sysv_bucket_lengths(hash: &SysvHash) -> Vec<u64> {
+    let mut lengths = vec![0u64; hash.nbucket() as usize];
+    let mut visited = vec![false; hash.nchain() as usize];
+
+    for (bucket, length) in lengths.iter_mut().enumerate() {
+        let mut index = hash.bucket(bucket as u32);
+
+        while index != 0 {
+            *length += 1;
+
+            if visited.get(index as usize) != Some(&false) {
+                eprintln!("readelf: Error: histogram chain is corrupt");
+                break;
+            }
+
+            visited[index as usize] = true;
+            index = hash.chain(index);
+        }
+    }
+
+    lengths
+}
+
+/// Get length of each bucket list of GNU hash table.
+///
+/// # Parameters
+/// - `hash` - given hash table.
+pub(crate) fn gnu_bucket_lengths(hash: &GnuHash) -> Vec<u64> {
+    (0..hash.nbuckets())
+        .map(|bucket| hash.chain_iter(bucket).count() as u64)
+        .collect()
+}
+
+/// Get number of buckets of each bucket list length.
+///
+/// # Parameters
+/// - `lengths` - given length of each bucket list.
+///
+/// # Returns
+/// Number of buckets indexed by list length, up to the longest list, empty
+/// if there are no buckets.
+pub(crate) fn bucket_length_counts(lengths: &[u64]) -> Vec<u64> {
+    let Some(&maxlength) = lengths.iter().max() else {
+        return Vec::new();
+    };
+
+    let mut counts = vec![0u64; maxlength as usize + 1];
+
+    for &length in lengths {
+        counts[length as usize] += 1;
+    }
+
+    counts
+}
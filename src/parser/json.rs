@@ -0,0 +1,1198 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! JSON output module.
+//!
+//! `--output=json` prints an array with an object for each ELF file, archive
+//! members included. Objects hold `file` and a member for each view selected
+//! by display options: `-h` header, `-S` and `-t` sections, `-g` groups,
+//! `--dyn-syms` symbols of dynamic symbol tables, `-r` relocations,
+//! `-d` dynamic, `-V` versions, `--version-requirements`
+//! version_requirements, `-I` histograms, `-x`, `-p` and `-R` dumps and `-n`
+//! notes. If no display option is given, objects have every member below
+//! but dumps; segments and symbols of static symbol tables are only included
+//! this way. Members are never removed or renamed, new ones may be
+//! added. Version ceilings given by `--max-version` are checked as in text
+//! mode, with errors printed to standard error.
+//!
+//! With `-c` an object `{"file": string, "archive_index": [...]}` precedes
+//! objects of members of each archive having a symbol index.
+//!
+//! Numbers are printed as decimal integers. Enumerated values are objects
+//! `{"value": number, "name": string}` with the raw value and its name as
+//! displayed by GNU readelf; names of unknown values follow GNU readelf as
+//! well, e.g. `LOOS+0x1`. Names are `null` if they can not be read.
+//!
+//! ```text
+//! {
+//!   "file": string,
+//!   "header": {
+//!     "class", "data": string, "version", "osabi": number,
+//!     "type", "machine": enum,
+//!     "entry", "flags", "program_headers_offset", "section_headers_offset",
+//!     "header_size", "program_header_size", "program_header_count",
+//!     "section_header_size", "section_header_count",
+//!     "section_name_table_index": number
+//!   },
+//!   "sections": [{
+//!     "index": number, "name": string, "type": enum,
+//!     "flags": enum (name holds `-S` key letters),
+//!     "address", "offset", "size", "entry_size", "link", "info",
+//!     "alignment": number
+//!   }],
+//!   "segments": [{
+//!     "index": number, "type": enum, "flags": enum (name is e.g. "R E"),
+//!     "offset", "virtual_address", "physical_address", "file_size",
+//!     "memory_size", "alignment": number
+//!   }],
+//!   "groups": [{
+//!     "section": string, "section_index": number, "signature": string,
+//!     "flags": enum (name is e.g. "COMDAT"),
+//!     "members": [{"index": number, "name": string}]
+//!   }],
+//!   "symbols": [{
+//!     "section": string, "section_index": number,
+//!     "entries": [{
+//!       "index": number, "name": string, "value", "size": number,
+//!       "type", "binding", "visibility", "section_index": enum,
+//!       "other": number,
+//!       "version": null | {"name": string, "default": bool}
+//!     }]
+//!   }],
+//!   "relocations": [{
+//!     "section": string, "section_index": number, "type": enum,
+//!     "entries": [{
+//!       "offset", "info": number, "type": enum (name may be null),
+//!       "symbol": null | {"index": number, "name": string, "value": number},
+//!       "addend": null | number
+//!     }]
+//!   }],
+//!   "dynamic": [{"tag": enum, "value": number, "display": string}],
+//!   "versions": [{
+//!     "section": string, "section_index": number, "type": enum,
+//!     "link": number, "entries": [...]
+//!   }],
+//!   "version_requirements": [{
+//!     "file": string,
+//!     "versions": [{"name": string, "symbols": [string]}]
+//!   }],
+//!   "histograms": [{
+//!     "table": "sysv" | "gnu", "section": null | string,
+//!     "buckets", "symbols": number, "counts": [number]
+//!   }],
+//!   "dumps": [{
+//!     "section": string, "section_index": number,
+//!     "kind": "hex" | "relocated" | "string", "address": number,
+//!     "data": null | string,
+//!     "strings": null | [{"offset": number, "string": string}]
+//!   }],
+//!   "notes": [{
+//!     "section": null | string, "offset", "size": number,
+//!     "entries": [{"owner": string, "type": enum, "data": string}]
+//!   }]
+//! }
+//! ```
+//!
+//! Entries of `SHT_RELR` sections hold `offset` only. Note data is printed
+//! as hexadecimal string. Notes are taken from sections, or from segments if
+//! there are no note sections, in which case `section` is `null`.
+//!
+//! Entries of versions depend on the section type:
+//!
+//! ```text
+//! SHT_GNU_verdef:  {"offset", "revision": number, "flags": enum,
+//!                   "index": number, "names": [string]}
+//! SHT_GNU_verneed: {"offset", "revision": number, "file": string,
+//!                   "versions": [{"offset": number, "name": string,
+//!                                 "flags": enum, "index": number}]}
+//! SHT_GNU_versym:  {"index", "version": number, "hidden": bool,
+//!                   "name": null | string}
+//! ```
+//!
+//! Names of a version definition are the version name followed by names of
+//! its parents. Histogram `counts` holds the number of buckets of each list
+//! length, starting from zero; `section` is given for GNU hash tables only.
+//! Dumps hold `data` as hexadecimal string, or `strings` for string dumps
+//! with non-empty strings of the section; either is `null` if the section
+//! has no data or it can not be read.
+
+use std::fmt;
+
+use crate::archive::{Archive, SymbolIndex};
+use crate::elf::dynsec::Elf64_Dyn;
+use crate::elf::elfhdr::*;
+use crate::elf::file::{cstr, read, Result};
+use crate::elf::note::NoteIter;
+use crate::elf::progtbl::*;
+use crate::elf::reloc::{elf32_r_info, elf64_r_sym, elf64_r_type};
+use crate::elf::relocate::relocate_data;
+use crate::elf::sechdr::*;
+use crate::elf::strtab::StringIter;
+use crate::elf::symtbl::*;
+use crate::elf::version::*;
+use crate::parser::archive::{check_index_size, qualified_name};
+use crate::parser::dump::{report_missing_dumps, report_skipped_relocs, DumpKind, DumpRequest};
+use crate::parser::dynamic::get_dynamic_type;
+use crate::parser::groups::get_group_flags;
+use crate::parser::histogram::{bucket_length_counts, gnu_bucket_lengths, sysv_bucket_lengths};
+use crate::parser::notes::{get_gnu_note_type, hex_bytes};
+use crate::parser::relocs::get_reloc_type;
+use crate::parser::sections::get_section_type_name;
+use crate::parser::symbols::*;
+use crate::parser::version::{get_ver_flags, highest_versions};
+use crate::parser::ElfParser;
+
+/// JSON value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    /// `null`.
+    Null,
+    /// `true` or `false`.
+    Bool(bool),
+    /// Unsigned integer.
+    Unsigned(u64),
+    /// Signed integer.
+    Signed(i64),
+    /// String.
+    String(String),
+    /// Array.
+    Array(Vec<Json>),
+    /// Object with members in insertion order.
+    Object(Vec<(&'static str, Json)>),
+}
+
+impl Json {
+    /// Write value indented by given number of levels.
+    ///
+    /// # Parameters
+    /// - `f`     - given formatter.
+    /// - `level` - given indentation level of the value.
+    fn write(&self, f: &mut fmt::Formatter<'_>, level: usize) -> fmt::Result {
+        let indent = "  ".repeat(level + 1);
+
+        match self {
+            Json::Null        => write!(f, "null"),
+            Json::Bool(b)     => write!(f, "{}", b),
+            Json::Unsigned(n) => write!(f, "{}", n),
+            Json::Signed(n)   => write!(f, "{}", n),
+            Json::String(s)   => write_string(f, s),
+            Json::Array(items) if items.is_empty() => write!(f, "[]"),
+            Json::Object(members) if members.is_empty() => write!(f, "{{}}"),
+            Json::Array(items) => {
+                writeln!(f, "[")?;
+
+                for (i, item) in items.iter().enumerate() {
+                    write!(f, "{}", indent)?;
+                    item.write(f, level + 1)?;
+                    writeln!(f, "{}", if i + 1 < items.len() { "," } else { "" })?;
+                }
+                write!(f, "{}]", &indent[2..])
+            }
+            Json::Object(members) => {
+                writeln!(f, "{{")?;
+
+                for (i, (key, value)) in members.iter().enumerate() {
+                    write!(f, "{}", indent)?;
+                    write_string(f, key)?;
+                    write!(f, ": ")?;
+                    value.write(f, level + 1)?;
+                    writeln!(f, "{}", if i + 1 < members.len() { "," } else { "" })?;
+                }
+                write!(f, "{}}}", &indent[2..])
+            }
+        }
+    }
+}
+
+impl fmt::Display for Json {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<u8> for Json {
+    fn from(value: u8) -> Self {
+        Json::Unsigned(value as u64)
+    }
+}
+
+impl From<u16> for Json {
+    fn from(value: u16) -> Self {
+        Json::Unsigned(value as u64)
+    }
+}
+
+impl From<u32> for Json {
+    fn from(value: u32) -> Self {
+        Json::Unsigned(value as u64)
+    }
+}
+
+impl From<u64> for Json {
+    fn from(value: u64) -> Self {
+        Json::Unsigned(value)
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Json::Unsigned(value as u64)
+    }
+}
+
+impl From<i64> for Json {
+    fn from(value: i64) -> Self {
+        Json::Signed(value)
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+impl<T: Into<Json>> From<Option<T>> for Json {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Json::Null, Into::into)
+    }
+}
+
+/// Views of ELF file included in its JSON representation.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JsonViews {
+    /// ELF header.
+    pub header: bool,
+    /// Section headers.
+    pub sections: bool,
+    /// Program headers.
+    pub segments: bool,
+    /// Section groups.
+    pub groups: bool,
+    /// All symbol tables.
+    pub symbols: bool,
+    /// Dynamic symbol tables, included by `symbols` as well.
+    pub dyn_syms: bool,
+    /// Relocation sections.
+    pub relocations: bool,
+    /// Dynamic section entries.
+    pub dynamic: bool,
+    /// Symbol versioning sections.
+    pub versions: bool,
+    /// Highest versions required from needed libraries.
+    pub version_requirements: bool,
+    /// Histograms of hash table bucket list lengths.
+    pub histograms: bool,
+    /// Notes.
+    pub notes: bool,
+    /// Section contents dump requests.
+    pub dumps: Vec<DumpRequest>,
+}
+
+impl JsonViews {
+    /// Get every view except section contents dumps, which have to be
+    /// requested for each section.
+    pub fn all() -> Self {
+        JsonViews {
+            header:               true,
+            sections:             true,
+            segments:             true,
+            groups:               true,
+            symbols:              true,
+            dyn_syms:             false,
+            relocations:          true,
+            dynamic:              true,
+            versions:             true,
+            version_requirements: true,
+            histograms:           true,
+            notes:                true,
+            dumps:                Vec::new(),
+        }
+    }
+}
+
+impl ElfParser<'_> {
+    /// Get JSON representation of ELF file.
+    ///
+    /// # Parameters
+    /// - `name`  - given file name.
+    /// - `views` - given views to include.
+    pub fn to_json(&self, name: &str, views: &JsonViews) -> Json {
+        let mut members = vec![("file", name.into())];
+
+        if views.header {
+            members.push(("header", self.json_header()));
+        }
+
+        if views.sections {
+            members.push(("sections", self.json_sections()));
+        }
+
+        if views.segments {
+            members.push(("segments", self.json_segments()));
+        }
+
+        if views.groups {
+            members.push(("groups", self.json_groups()));
+        }
+
+        if views.symbols || views.dyn_syms {
+            members.push(("symbols", self.json_symbols(!views.symbols)));
+        }
+
+        if views.relocations {
+            members.push(("relocations", self.json_relocations()));
+        }
+
+        if views.dynamic {
+            members.push(("dynamic", self.json_dynamic()));
+        }
+
+        if views.versions {
+            members.push(("versions", self.json_versions()));
+        }
+
+        if views.version_requirements {
+            members.push(("version_requirements", self.json_version_requirements()));
+        }
+
+        if views.histograms {
+            members.push(("histograms", self.json_histograms()));
+        }
+
+        if !views.dumps.is_empty() {
+            members.push(("dumps", self.json_dumps(&views.dumps)));
+        }
+
+        if views.notes {
+            members.push(("notes", self.json_notes()));
+        }
+
+        Json::Object(members)
+    }
+
+    /// Get JSON representation of ELF header.
+    fn json_header(&self) -> Json {
+        let hdr = self.file.header();
+
+        Json::Object(vec![
+            ("class",                    self.get_class().into()),
+            ("data",                     self.get_data().into()),
+            ("version",                  hdr.e_ident[EI_VERSION].into()),
+            ("osabi",                    hdr.e_ident[EI_OSABI].into()),
+            ("type",                     named(hdr.e_type, hdr.file_type().to_string())),
+            ("machine",                  named(hdr.e_machine, hdr.machine().to_string())),
+            ("entry",                    { hdr.e_entry }.into()),
+            ("flags",                    { hdr.e_flags }.into()),
+            ("program_headers_offset",   { hdr.e_phoff }.into()),
+            ("section_headers_offset",   { hdr.e_shoff }.into()),
+            ("header_size",              { hdr.e_ehsize }.into()),
+            ("program_header_size",      { hdr.e_phentsize }.into()),
+            ("program_header_count",     { hdr.e_phnum }.into()),
+            ("section_header_size",      { hdr.e_shentsize }.into()),
+            ("section_header_count",     { hdr.e_shnum }.into()),
+            ("section_name_table_index", { hdr.e_shstrndx }.into()),
+        ])
+    }
+
+    /// Get JSON representation of section headers.
+    fn json_sections(&self) -> Json {
+        let machine  = self.file.header().machine();
+        let sections = self.file.section_headers().iter().enumerate().map(|(index, shdr)| {
+            Json::Object(vec![
+                ("index",      index.into()),
+                ("name",       self.file.section_name(shdr).ok().into()),
+                ("type",       named(shdr.sh_type, get_section_type_name(machine, shdr.section_type()))),
+                ("flags",      named(shdr.sh_flags, self.get_section_flags(shdr.sh_flags, false))),
+                ("address",    { shdr.sh_addr }.into()),
+                ("offset",     { shdr.sh_offset }.into()),
+                ("size",       { shdr.sh_size }.into()),
+                ("entry_size", { shdr.sh_entsize }.into()),
+                ("link",       { shdr.sh_link }.into()),
+                ("info",       { shdr.sh_info }.into()),
+                ("alignment",  { shdr.sh_addralign }.into()),
+            ])
+        });
+
+        Json::Array(sections.collect())
+    }
+
+    /// Get JSON representation of program headers.
+    fn json_segments(&self) -> Json {
+        let segments = self.file.program_headers().iter().enumerate().map(|(index, phdr)| {
+            Json::Object(vec![
+                ("index",            index.into()),
+                ("type",             named(phdr.p_type, phdr.segment_type().to_string())),
+                ("flags",            named(phdr.p_flags, segment_flags(phdr.p_flags))),
+                ("offset",           { phdr.p_offset }.into()),
+                ("virtual_address",  { phdr.p_vaddr }.into()),
+                ("physical_address", { phdr.p_paddr }.into()),
+                ("file_size",        { phdr.p_filesz }.into()),
+                ("memory_size",      { phdr.p_memsz }.into()),
+                ("alignment",        { phdr.p_align }.into()),
+            ])
+        });
+
+        Json::Array(segments.collect())
+    }
+
+    /// Get JSON representation of section groups.
+    ///
+    /// Groups with invalid signature symbol are reported and skipped.
+    fn json_groups(&self) -> Json {
+        let shdrs       = self.file.section_headers();
+        let mut results = Vec::new();
+
+        for (index, shdr) in shdrs.iter().enumerate() {
+            if shdr.sh_type != SHT_GROUP {
+                continue;
+            }
+
+            let name = self.file.section_name(shdr).ok();
+
+            let Some(signature) = self.group_signature(shdr, name.unwrap_or("<corrupt>")) else {
+                continue;
+            };
+
+            let group = match self.file.section_group(shdr) {
+                Ok(group) => group,
+                Err(err)  => {
+                    eprintln!("readelf: Error: {}", err);
+                    continue;
+                }
+            };
+
+            let members = group.members().map(|member| {
+                let name = shdrs.get(member as usize).and_then(|shdr| self.file.section_name(shdr).ok());
+
+                Json::Object(vec![
+                    ("index", member.into()),
+                    ("name",  name.into()),
+                ])
+            });
+
+            results.push(Json::Object(vec![
+                ("section",       name.into()),
+                ("section_index", index.into()),
+                ("signature",     signature.into()),
+                ("flags",         named(group.flags, get_group_flags(group.flags).trim_end())),
+                ("members",       Json::Array(members.collect())),
+            ]));
+        }
+
+        Json::Array(results)
+    }
+
+    /// Get JSON representation of symbol tables.
+    ///
+    /// # Parameters
+    /// - `dynamic_only` - given flag whether to include dynamic symbol tables
+    ///   only.
+    fn json_symbols(&self, dynamic_only: bool) -> Json {
+        let mut tables = Vec::new();
+
+        for (index, shdr) in self.file.section_headers().iter().enumerate() {
+            let included = match shdr.sh_type {
+                SHT_DYNSYM => true,
+                SHT_SYMTAB => !dynamic_only,
+                _          => false,
+            };
+
+            if !included {
+                continue;
+            }
+
+            tables.push(Json::Object(vec![
+                ("section",       self.file.section_name(shdr).ok().into()),
+                ("section_index", index.into()),
+                ("entries",       self.json_symbol_table(shdr)),
+            ]));
+        }
+
+        Json::Array(tables)
+    }
+
+    /// Get JSON representation of symbol table entries.
+    ///
+    /// # Parameters
+    /// - `shdr` - given symbol table section header.
+    fn json_symbol_table(&self, shdr: &Elf64_Shdr) -> Json {
+        let hdr     = self.file.header();
+        let osabi   = hdr.e_ident[EI_OSABI];
+        let shnum   = self.file.section_headers().len();
+        let entries = match shdr.sh_type {
+            SHT_DYNSYM => self.file.dynamic().unwrap_or_default(),
+            _          => Vec::new(),
+        };
+
+        let mut symbols = Vec::new();
+
+        for index in 0..self.file.symbol_count(shdr) {
+            let sym = match self.file.symbol(shdr, index) {
+                Ok(sym)  => sym,
+                Err(err) => {
+                    eprintln!("readelf: Error: {}", err);
+                    break;
+                }
+            };
+
+            let bind    = elf32_st_bind(sym.st_info);
+            let typ     = elf32_st_type(sym.st_info);
+            let vis     = elf64_st_visibility(sym.st_other);
+            let shndx   = sym.st_shndx;
+            let name    = self.symbol_display_name(shdr, &sym);
+            // Version indexes are only valid for dynamic symbols.
+            let version = match shdr.sh_type {
+                SHT_DYNSYM => self.file.symbol_version(&entries, index, &sym).ok().flatten(),
+                _          => None,
+            };
+            let version = version.map(|version| {
+                Json::Object(vec![
+                    ("name",    version.name().into()),
+                    ("default", version.is_default().into()),
+                ])
+            });
+
+            symbols.push(Json::Object(vec![
+                ("index",         index.into()),
+                ("name",          self.display_name(&name).into()),
+                ("value",         { sym.st_value }.into()),
+                ("size",          { sym.st_size }.into()),
+                ("type",          named(typ, get_symbol_type(osabi, sym.symbol_type()))),
+                ("binding",       named(bind, get_symbol_binding(osabi, bind))),
+                ("visibility",    named(vis, get_symbol_visibility(vis))),
+                ("section_index", named(shndx, get_symbol_index_type(shnum, shndx as u32).trim())),
+                ("other",         { sym.st_other }.into()),
+                ("version",       version.into()),
+            ]));
+        }
+
+        Json::Array(symbols)
+    }
+
+    /// Get JSON representation of relocation sections.
+    fn json_relocations(&self) -> Json {
+        let machine     = self.file.header().machine();
+        let mut results = Vec::new();
+
+        for (index, shdr) in self.file.section_headers().iter().enumerate() {
+            let is_reloc = matches!(
+                shdr.sh_type,
+                SHT_REL | SHT_RELA | SHT_RELR | SHT_ANDROID_REL | SHT_ANDROID_RELA
+            );
+
+            if !is_reloc {
+                continue;
+            }
+
+            let entries = match shdr.sh_type {
+                SHT_RELR => self.json_relr(shdr),
+                _        => self.json_rel(shdr),
+            };
+
+            results.push(Json::Object(vec![
+                ("section",       self.file.section_name(shdr).ok().into()),
+                ("section_index", index.into()),
+                ("type",          named(shdr.sh_type, get_section_type_name(machine, shdr.section_type()))),
+                ("entries",       entries),
+            ]));
+        }
+
+        Json::Array(results)
+    }
+
+    /// Get JSON representation of addresses relocated by packed relative
+    /// relocation section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given `SHT_RELR` section header.
+    fn json_relr(&self, shdr: &Elf64_Shdr) -> Json {
+        match self.file.relr(shdr) {
+            Ok(relr) => Json::Array(relr.map(|addr| Json::Object(vec![("offset", addr.into())])).collect()),
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                Json::Array(Vec::new())
+            }
+        }
+    }
+
+    /// Get JSON representation of relocation entries.
+    ///
+    /// # Parameters
+    /// - `shdr` - given relocation section header.
+    fn json_rel(&self, shdr: &Elf64_Shdr) -> Json {
+        let is_rela = matches!(shdr.sh_type, SHT_RELA | SHT_ANDROID_RELA);
+        let is_64   = self.file.is_64();
+        let machine = self.file.header().machine();
+
+        let rels = match self.file.relocations(shdr) {
+            Ok(rels) => rels,
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return Json::Array(Vec::new());
+            }
+        };
+
+        let symtab = self
+            .file
+            .section_header(shdr.sh_link as usize)
+            .ok()
+            .filter(|s| matches!(s.sh_type, SHT_SYMTAB | SHT_DYNSYM))
+            .copied();
+
+        let entries = rels.iter().map(|rel| {
+            let typ    = elf64_r_type(rel.r_info);
+            let sym    = elf64_r_sym(rel.r_info);
+            let info   = match is_64 {
+                true  => rel.r_info,
+                false => elf32_r_info(sym, typ as u8) as u64,
+            };
+            let symbol = match sym {
+                0 => Json::Null,
+                _ => {
+                    let symbol = symtab.and_then(|t| Some((t, self.file.symbol(&t, sym as usize).ok()?)));
+                    let name   = symbol.map(|(symtab, symbol)| self.display_name(&self.reloc_symbol_name(&symtab, &symbol)));
+
+                    Json::Object(vec![
+                        ("index", sym.into()),
+                        ("name",  name.into()),
+                        ("value", symbol.map(|(_, symbol)| symbol.st_value).into()),
+                    ])
+                }
+            };
+
+            Json::Object(vec![
+                ("offset", { rel.r_offset }.into()),
+                ("info",   info.into()),
+                ("type",   named(typ, get_reloc_type(machine, typ))),
+                ("symbol", symbol),
+                ("addend", is_rela.then_some(rel.r_addend).into()),
+            ])
+        });
+
+        Json::Array(entries.collect())
+    }
+
+    /// Get JSON representation of dynamic section entries.
+    fn json_dynamic(&self) -> Json {
+        let (Some(_), Ok(entries)) = (self.file.dynamic_location(), self.file.dynamic()) else {
+            return Json::Array(Vec::new());
+        };
+
+        let strtab  = self.file.dynamic_strtab(&entries);
+        let machine = self.file.header().machine();
+
+        let entries = entries.iter().map(|entry| {
+            Json::Object(vec![
+                ("tag",     named(entry.d_tag, get_dynamic_type(machine, entry.tag()))),
+                ("value",   entry.val().into()),
+                ("display", self.dynamic_value(entry, strtab).into()),
+            ])
+        });
+
+        Json::Array(entries.collect())
+    }
+
+    /// Get JSON representation of symbol versioning sections.
+    fn json_versions(&self) -> Json {
+        let entries     = self.file.dynamic().unwrap_or_default();
+        let machine     = self.file.header().machine();
+        let mut results = Vec::new();
+
+        for (index, shdr) in self.file.section_headers().iter().enumerate() {
+            let data = match shdr.sh_type {
+                SHT_GNU_VERDEF | SHT_GNU_VERNEED | SHT_GNU_VERSYM => self.file.section_data(shdr),
+                _ => continue,
+            };
+
+            let data = data.unwrap_or_else(|err| {
+                eprintln!("readelf: Error: {}", err);
+                Default::default()
+            });
+
+            let versions = match shdr.sh_type {
+                SHT_GNU_VERDEF  => self.json_verdef(shdr, &data, &entries),
+                SHT_GNU_VERNEED => self.json_verneed(shdr, &data, &entries),
+                _               => self.json_versym(shdr, &data, &entries),
+            };
+
+            results.push(Json::Object(vec![
+                ("section",       self.file.section_name(shdr).ok().into()),
+                ("section_index", index.into()),
+                ("type",          named(shdr.sh_type, get_section_type_name(machine, shdr.section_type()))),
+                ("link",          { shdr.sh_link }.into()),
+                ("entries",       versions),
+            ]));
+        }
+
+        Json::Array(results)
+    }
+
+    /// Get JSON representation of version definitions.
+    ///
+    /// # Parameters
+    /// - `shdr`    - given version definition section header.
+    /// - `data`    - given section contents.
+    /// - `entries` - given dynamic entries.
+    fn json_verdef(&self, shdr: &Elf64_Shdr, data: &[u8], entries: &[Elf64_Dyn]) -> Json {
+        let strtab = self.file.dynamic_strtab(entries);
+        let name   = |offset: u32| strtab.and_then(|t| cstr(t, offset as usize).ok());
+
+        let defs = VerdefIter::new(data, 0).take(shdr.sh_info as usize).map(|(offset, def)| {
+            let names = match offset.checked_add(def.vd_aux as usize) {
+                Some(aux) => VerdauxIter::new(data, aux)
+                    .take(def.vd_cnt as usize)
+                    .map(|(_, aux)| name(aux.vda_name).into())
+                    .collect(),
+                None      => Vec::new(),
+            };
+
+            Json::Object(vec![
+                ("offset",   offset.into()),
+                ("revision", { def.vd_version }.into()),
+                ("flags",    named(def.vd_flags, get_ver_flags(def.vd_flags))),
+                ("index",    { def.vd_ndx }.into()),
+                ("names",    Json::Array(names)),
+            ])
+        });
+
+        Json::Array(defs.collect())
+    }
+
+    /// Get JSON representation of needed versions.
+    ///
+    /// # Parameters
+    /// - `shdr`    - given needed versions section header.
+    /// - `data`    - given section contents.
+    /// - `entries` - given dynamic entries.
+    fn json_verneed(&self, shdr: &Elf64_Shdr, data: &[u8], entries: &[Elf64_Dyn]) -> Json {
+        let strtab = self.file.dynamic_strtab(entries);
+        let name   = |offset: u32| strtab.and_then(|t| cstr(t, offset as usize).ok());
+
+        let needs = VerneedIter::new(data, 0).take(shdr.sh_info as usize).map(|(offset, need)| {
+            let versions = match offset.checked_add(need.vn_aux as usize) {
+                Some(aux) => VernauxIter::new(data, aux)
+                    .take(need.vn_cnt as usize)
+                    .map(|(offset, aux)| {
+                        Json::Object(vec![
+                            ("offset", offset.into()),
+                            ("name",   name(aux.vna_name).into()),
+                            ("flags",  named(aux.vna_flags, get_ver_flags(aux.vna_flags))),
+                            ("index",  { aux.vna_other }.into()),
+                        ])
+                    })
+                    .collect(),
+                None      => Vec::new(),
+            };
+
+            Json::Object(vec![
+                ("offset",   offset.into()),
+                ("revision", { need.vn_version }.into()),
+                ("file",     name(need.vn_file).into()),
+                ("versions", Json::Array(versions)),
+            ])
+        });
+
+        Json::Array(needs.collect())
+    }
+
+    /// Get JSON representation of version symbols.
+    ///
+    /// # Parameters
+    /// - `shdr`    - given version symbols section header.
+    /// - `data`    - given section contents.
+    /// - `entries` - given dynamic entries.
+    fn json_versym(&self, shdr: &Elf64_Shdr, data: &[u8], entries: &[Elf64_Dyn]) -> Json {
+        let strtab = self
+            .file
+            .section_header(shdr.sh_link as usize)
+            .and_then(|symtab| self.file.section_header(symtab.sh_link as usize))
+            .and_then(|strtab| self.file.section_data(strtab))
+            .ok();
+
+        let symbols = data.chunks_exact(2).enumerate().map(|(index, bytes)| {
+            let value: u16 = read(bytes, 0).unwrap_or(0);
+            let name       = match value {
+                VER_NDX_LOCAL  => Some("*local*"),
+                VER_NDX_GLOBAL => Some("*global*"),
+                _              => strtab.as_ref().and_then(|strtab| self.versym_name(entries, value, strtab)),
+            };
+
+            Json::Object(vec![
+                ("index",   index.into()),
+                ("version", (value & VERSYM_VERSION).into()),
+                ("hidden",  (value & VERSYM_HIDDEN != 0).into()),
+                ("name",    name.into()),
+            ])
+        });
+
+        Json::Array(symbols.collect())
+    }
+
+    /// Get JSON representation of the highest versions needed from each
+    /// needed library.
+    fn json_version_requirements(&self) -> Json {
+        let entries = self.file.dynamic().unwrap_or_default();
+        let needs   = match self.file.version_needs(&entries) {
+            Ok(needs) => needs,
+            Err(err)  => {
+                eprintln!("readelf: Error: Unable to read needed versions: {}", err);
+                return Json::Array(Vec::new());
+            }
+        };
+
+        let mut files = self.file.needed_libraries(&entries);
+
+        for need in &needs {
+            if !files.contains(&need.file) {
+                files.push(need.file);
+            }
+        }
+
+        let files = files.into_iter().map(|file| {
+            let versions = needs
+                .iter()
+                .find(|need| need.file == file)
+                .map(|need| highest_versions(&need.versions))
+                .unwrap_or_default();
+
+            let versions = versions.into_iter().map(|version| {
+                Json::Object(vec![
+                    ("name",    version.name.into()),
+                    ("symbols", Json::Array(version.symbols.iter().map(|&s| s.into()).collect())),
+                ])
+            });
+
+            Json::Object(vec![
+                ("file",     file.into()),
+                ("versions", Json::Array(versions.collect())),
+            ])
+        });
+
+        Json::Array(files.collect())
+    }
+
+    /// Get JSON representation of histograms of hash table bucket list
+    /// lengths.
+    fn json_histograms(&self) -> Json {
+        let entries     = self.file.dynamic().unwrap_or_default();
+        let mut results = Vec::new();
+
+        match self.file.sysv_hash(&entries) {
+            Ok(Some(hash)) => results.push(json_histogram("sysv", None, &sysv_bucket_lengths(&hash))),
+            Ok(None)       => {}
+            Err(err)       => eprintln!("readelf: Error: Unable to read hash table: {}", err),
+        }
+
+        match self.file.gnu_hash(&entries) {
+            Ok(Some(hash)) => {
+                let name = self.gnu_hash_name(&entries);
+
+                results.push(json_histogram("gnu", Some(name), &gnu_bucket_lengths(&hash)));
+                self.check_gnu_hash_reachability(&entries, name);
+            }
+            Ok(None)       => {}
+            Err(err)       => eprintln!("readelf: Error: Unable to read GNU hash table: {}", err),
+        }
+
+        Json::Array(results)
+    }
+
+    /// Get JSON representation of requested section contents dumps.
+    ///
+    /// # Parameters
+    /// - `requests` - given dump requests.
+    fn json_dumps(&self, requests: &[DumpRequest]) -> Json {
+        let (dumps, missing) = self.resolve_dumps(requests);
+        let dumps            = dumps.into_iter().map(|(index, kind)| self.json_dump(index, kind)).collect();
+
+        report_missing_dumps(&missing);
+        Json::Array(dumps)
+    }
+
+    /// Get JSON representation of section contents dump.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `kind`  - given kind of dump.
+    fn json_dump(&self, index: usize, kind: DumpKind) -> Json {
+        let shdr = self.file.section_headers()[index];
+        let name = self.file.section_name(&shdr).ok();
+        let data = self.dump_contents(index, &shdr, name.unwrap_or("<corrupt>"), kind);
+
+        let (kind, contents) = match kind {
+            DumpKind::Hex       => ("hex", ("data", data.map(|data| hex_bytes(&data, "")).into())),
+            DumpKind::Relocated => ("relocated", ("data", data.map(|data| hex_bytes(&data, "")).into())),
+            DumpKind::String    => ("string", ("strings", data.map(|data| json_strings(&data)).into())),
+        };
+
+        Json::Object(vec![
+            ("section",       name.into()),
+            ("section_index", index.into()),
+            ("kind",          kind.into()),
+            ("address",       { shdr.sh_addr }.into()),
+            contents,
+        ])
+    }
+
+    /// Get section contents to dump, decompressed if requested and relocated
+    /// for relocated dumps.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `shdr`  - given section header.
+    /// - `name`  - given section name.
+    /// - `kind`  - given kind of dump.
+    ///
+    /// # Returns
+    /// - Section contents - in case of success.
+    /// - `None` - if section has no data, or it can not be read, the error is
+    ///   reported.
+    fn dump_contents(&self, index: usize, shdr: &Elf64_Shdr, name: &str, kind: DumpKind) -> Option<Vec<u8>> {
+        if shdr.sh_size == 0 || shdr.sh_type == SHT_NOBITS {
+            return None;
+        }
+
+        let data = match self.file.raw_section_data(shdr) {
+            Ok(data) => self.dump_data(shdr, data, name)?,
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return None;
+            }
+        };
+
+        if kind != DumpKind::Relocated {
+            return Some(data.into_owned());
+        }
+
+        match relocate_data(&self.file, index, data.into_owned()) {
+            Ok(relocated) => {
+                report_skipped_relocs(&relocated.skipped, name);
+                Some(relocated.data)
+            }
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                None
+            }
+        }
+    }
+
+    /// Get JSON representation of notes.
+    fn json_notes(&self) -> Json {
+        let hdr         = self.file.header();
+        let mut results = Vec::new();
+
+        if hdr.e_type != ET_CORE {
+            for shdr in self.file.section_headers().iter().filter(|s| s.sh_type == SHT_NOTE) {
+                results.push(Json::Object(vec![
+                    ("section", self.file.section_name(shdr).ok().into()),
+                    ("offset",  { shdr.sh_offset }.into()),
+                    ("size",    { shdr.sh_size }.into()),
+                    ("entries", self.json_note_list(self.file.section_notes(shdr))),
+                ]));
+            }
+        }
+
+        if !results.is_empty() {
+            return Json::Array(results);
+        }
+
+        for phdr in self.file.program_headers().iter().filter(|p| p.p_type == PT_NOTE) {
+            results.push(Json::Object(vec![
+                ("section", Json::Null),
+                ("offset",  { phdr.p_offset }.into()),
+                ("size",    { phdr.p_filesz }.into()),
+                ("entries", self.json_note_list(self.file.segment_notes(phdr))),
+            ]));
+        }
+
+        Json::Array(results)
+    }
+
+    /// Get JSON representation of notes of a single section or segment.
+    ///
+    /// # Parameters
+    /// - `notes` - given notes iterator.
+    fn json_note_list(&self, notes: Result<NoteIter>) -> Json {
+        let notes = match notes {
+            Ok(notes) => notes,
+            Err(err)  => {
+                eprintln!("readelf: Warning: {}", err);
+                return Json::Array(Vec::new());
+            }
+        };
+
+        let mut entries = Vec::new();
+
+        for note in notes {
+            let note = match note {
+                Ok(note) => note,
+                Err(err) => {
+                    eprintln!("readelf: Warning: {}", err);
+                    continue;
+                }
+            };
+
+            let typ = match note.is_gnu() {
+                true  => get_gnu_note_type(note.n_type),
+                false => self.get_note_type(note.n_type),
+            };
+
+            entries.push(Json::Object(vec![
+                ("owner", String::from_utf8_lossy(note.name).into_owned().into()),
+                ("type",  named(note.n_type, typ)),
+                ("data",  hex_bytes(note.desc, "").into()),
+            ]));
+        }
+
+        Json::Array(entries)
+    }
+}
+
+/// Get JSON representation of enumerated value.
+///
+/// # Parameters
+/// - `value` - given raw value.
+/// - `name`  - given value name.
+fn named(value: impl Into<Json>, name: impl Into<Json>) -> Json {
+    Json::Object(vec![("value", value.into()), ("name", name.into())])
+}
+
+/// Get JSON representation of hash table bucket list lengths histogram.
+///
+/// # Parameters
+/// - `table`   - given kind of hash table.
+/// - `section` - given name of hash table section.
+/// - `lengths` - given length of each bucket list.
+fn json_histogram(table: &str, section: Option<&str>, lengths: &[u64]) -> Json {
+    let counts = bucket_length_counts(lengths).into_iter().map(Json::from);
+
+    Json::Object(vec![
+        ("table",   table.into()),
+        ("section", section.into()),
+        ("buckets", lengths.len().into()),
+        ("symbols", lengths.iter().sum::<u64>().into()),
+        ("counts",  Json::Array(counts.collect())),
+    ])
+}
+
+/// Get JSON representation of non-empty strings of section.
+///
+/// # Parameters
+/// - `data` - given section contents.
+fn json_strings(data: &[u8]) -> Json {
+    let strings = StringIter::new(data).filter(|string| !string.bytes.is_empty()).map(|string| {
+        Json::Object(vec![
+            ("offset", string.offset.into()),
+            ("string", String::from_utf8_lossy(&string.bytes).into_owned().into()),
+        ])
+    });
+
+    Json::Array(strings.collect())
+}
+
+/// Get JSON representation of archive symbol index.
+///
+/// Symbols are grouped by archive member defining them, in order of the
+/// index, as `-c` displays them.
+///
+/// # Parameters
+/// - `name`    - given archive file name.
+/// - `archive` - given archive.
+/// - `index`   - given archive symbol index.
+///
+/// # Returns
+/// JSON document of the archive, and `false` if index is malformed.
+pub fn archive_index_json(name: &str, archive: &Archive, index: &SymbolIndex) -> (Json, bool) {
+    let mut members: Vec<(u64, Option<String>, Vec<Json>)> = Vec::new();
+    let mut count = 0;
+
+    for sym in index.symbols() {
+        if members.last().is_none_or(|(offset, _, _)| *offset != sym.offset) {
+            let member = match archive.member_at(sym.offset) {
+                Ok(member) => Some(qualified_name(name, archive, &member)),
+                Err(err)   => {
+                    eprintln!("readelf: Error: {}: {}", name, err);
+                    None
+                }
+            };
+            members.push((sym.offset, member, Vec::new()));
+        }
+
+        if let Some((_, _, symbols)) = members.last_mut() {
+            symbols.push(String::from_utf8_lossy(sym.name).into_owned().into());
+        }
+        count += 1;
+    }
+
+    let members = members.into_iter().map(|(offset, member, symbols)| {
+        Json::Object(vec![
+            ("member",  member.into()),
+            ("offset",  offset.into()),
+            ("symbols", Json::Array(symbols)),
+        ])
+    });
+
+    let document = Json::Object(vec![
+        ("file",          name.into()),
+        ("archive_index", Json::Array(members.collect())),
+    ]);
+
+    (document, check_index_size(name, index, count))
+}
+
+/// Get segment flags string representation, e.g. `R E`.
+///
+/// # Parameters
+/// - `flags` - given segment flags.
+fn segment_flags(flags: u32) -> String {
+    let flag = |mask, c| if flags & mask != 0 { c } else { ' ' };
+    [flag(PF_R, 'R'), flag(PF_W, 'W'), flag(PF_X, 'E')].iter().collect()
+}
+
+/// Write JSON string literal.
+///
+/// # Parameters
+/// - `f` - given formatter.
+/// - `s` - given string.
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"'          => write!(f, "\\\"")?,
+            '\\'         => write!(f, "\\\\")?,
+            '\n'         => write!(f, "\\n")?,
+            '\r'         => write!(f, "\\r")?,
+            '\t'         => write!(f, "\\t")?,
+            c if c < ' ' => write!(f, "\\u{:04x}", c as u32)?,
+            c            => write!(f, "{}", c)?,
+        }
+    }
+    write!(f, "\"")
+}
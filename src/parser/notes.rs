@@ -0,0 +1,720 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Notes display module.
+
+use crate::elf::coredump::*;
+use crate::elf::elfhdr::{EM_386, EM_AARCH64, EM_IAMCU, EM_X86_64, ET_CORE};
+use crate::elf::file::{read, Result};
+use crate::elf::note::*;
+use crate::elf::progtbl::PT_NOTE;
+use crate::elf::sechdr::SHT_NOTE;
+use crate::parser::{truncate_symbol, ElfParser};
+
+impl ElfParser<'_> {
+    /// Print notes.
+    ///
+    /// Notes of `SHT_NOTE` sections are printed, or notes of `PT_NOTE`
+    /// segments if there are no such sections. Core files are always
+    /// displayed by segments.
+    pub fn print_notes(&self) {
+        let hdr = self.file.header();
+
+        if hdr.e_type == ET_CORE && self.file.program_headers().is_empty() {
+            outln!("No notes found file.");
+            return;
+        }
+
+        let mut found = false;
+
+        if hdr.e_type != ET_CORE {
+            for shdr in self.file.section_headers().iter().filter(|s| s.sh_type == SHT_NOTE) {
+                found = true;
+
+                if shdr.sh_size == 0 {
+                    continue;
+                }
+
+                let name = self.file.section_name(shdr).unwrap_or("<corrupt>");
+                outln!();
+                outln!("Displaying notes found in: {}", name);
+                self.print_note_list(self.file.section_notes(shdr));
+            }
+        }
+
+        if found {
+            return;
+        }
+
+        for phdr in self.file.program_headers().iter().filter(|p| p.p_type == PT_NOTE) {
+            if phdr.p_filesz == 0 {
+                continue;
+            }
+
+            outln!();
+            outln!(
+                "Displaying notes found at file offset 0x{:08x} with length 0x{:08x}:",
+                { phdr.p_offset }, { phdr.p_filesz }
+            );
+            self.print_note_list(self.file.segment_notes(phdr));
+        }
+    }
+
+    /// Print notes of a single section or segment.
+    ///
+    /// # Parameters
+    /// - `notes` - given notes iterator.
+    fn print_note_list(&self, notes: Result<NoteIter>) {
+        outln!("  {:<20} {:<10}\tDescription", "Owner", "Data size");
+
+        let notes = match notes {
+            Ok(notes) => notes,
+            Err(err) => {
+                eprintln!("readelf: Warning: {}", err);
+                return;
+            }
+        };
+
+        for note in notes {
+            match note {
+                Ok(note) => self.print_note(&note),
+                Err(err) => eprintln!("readelf: Warning: {}", err),
+            }
+        }
+    }
+
+    /// Print note.
+    ///
+    /// # Parameters
+    /// - `note` - given note.
+    fn print_note(&self, note: &Note) {
+        let name = match note.name.is_empty() {
+            true  => "(NONE)".into(),
+            false => String::from_utf8_lossy(note.name),
+        };
+        let name = truncate_symbol(20, &name);
+
+        let typ = match note.is_gnu() {
+            true  => get_gnu_note_type(note.n_type),
+            false => self.get_note_type(note.n_type),
+        };
+
+        outln!("  {:<20} 0x{:08x}\t{}", name, note.desc.len(), typ);
+
+        if note.is_gnu() {
+            self.print_gnu_note(note);
+        } else if note.name.starts_with(b"CORE") {
+            self.print_core_note(note);
+        } else if !note.desc.is_empty() {
+            outln!("   description data: {}", hex_bytes(note.desc, " "));
+        }
+    }
+
+    /// Get generic note type string representation.
+    ///
+    /// # Parameters
+    /// - `n_type` - given note type.
+    pub(crate) fn get_note_type(&self, n_type: u32) -> String {
+        if self.file.header().e_type != ET_CORE {
+            let name = match n_type {
+                NT_VERSION                  => "NT_VERSION (version)",
+                NT_ARCH                     => "NT_ARCH (architecture)",
+                NT_GNU_BUILD_ATTRIBUTE_OPEN => "OPEN",
+                NT_GNU_BUILD_ATTRIBUTE_FUNC => "func",
+                NT_GO_BUILDID               => "GO BUILDID",
+                FDO_PACKAGING_METADATA      => "FDO_PACKAGING_METADATA",
+                _ => return format!("Unknown note type: (0x{:08x})", n_type),
+            };
+            return name.to_string();
+        }
+
+        let name = match n_type {
+            NT_AUXV                 => "NT_AUXV (auxiliary vector)",
+            NT_PRSTATUS             => "NT_PRSTATUS (prstatus structure)",
+            NT_FPREGSET             => "NT_FPREGSET (floating point registers)",
+            NT_PRPSINFO             => "NT_PRPSINFO (prpsinfo structure)",
+            NT_TASKSTRUCT           => "NT_TASKSTRUCT (task structure)",
+            NT_GDB_TDESC            => "NT_GDB_TDESC (GDB XML target description)",
+            NT_PRXFPREG             => "NT_PRXFPREG (user_xfpregs structure)",
+            NT_386_TLS              => "NT_386_TLS (x86 TLS information)",
+            NT_386_IOPERM           => "NT_386_IOPERM (x86 I/O permissions)",
+            NT_X86_XSTATE           => "NT_X86_XSTATE (x86 XSAVE extended state)",
+            NT_X86_CET              => "NT_X86_CET (x86 CET state)",
+            NT_ARM_VFP              => "NT_ARM_VFP (arm VFP registers)",
+            NT_ARM_TLS              => "NT_ARM_TLS (AArch TLS registers)",
+            NT_ARM_HW_BREAK         => "NT_ARM_HW_BREAK (AArch hardware breakpoint registers)",
+            NT_ARM_HW_WATCH         => "NT_ARM_HW_WATCH (AArch hardware watchpoint registers)",
+            NT_ARM_SYSTEM_CALL      => "NT_ARM_SYSTEM_CALL (AArch system call number)",
+            NT_ARM_SVE              => "NT_ARM_SVE (AArch SVE registers)",
+            NT_ARM_PAC_MASK         => "NT_ARM_PAC_MASK (AArch pointer authentication code masks)",
+            NT_ARM_PACA_KEYS        => "NT_ARM_PACA_KEYS (ARM pointer authentication address key)",
+            NT_ARM_PACG_KEYS        => "NT_ARM_PACG_KEYS (ARM pointer authentication generic key)",
+            NT_ARM_TAGGED_ADDR_CTRL => "NT_ARM_TAGGED_ADDR_CTRL (AArch tagged address control)",
+            NT_ARM_PAC_ENABLED_KEYS => {
+                "NT_ARM_PAC_ENABLED_KEYS (AArch64 pointer authentication enabled keys)"
+            }
+            NT_PSTATUS              => "NT_PSTATUS (pstatus structure)",
+            NT_FPREGS               => "NT_FPREGS (floating point registers)",
+            NT_PSINFO               => "NT_PSINFO (psinfo structure)",
+            NT_LWPSTATUS            => "NT_LWPSTATUS (lwpstatus_t structure)",
+            NT_LWPSINFO             => "NT_LWPSINFO (lwpsinfo_t structure)",
+            NT_WIN32PSTATUS         => "NT_WIN32PSTATUS (win32_pstatus structure)",
+            NT_SIGINFO              => "NT_SIGINFO (siginfo_t data)",
+            NT_FILE                 => "NT_FILE (mapped files)",
+            _ => return format!("Unknown note type: (0x{:08x})", n_type),
+        };
+
+        name.to_string()
+    }
+
+    /// Print core file note descriptor.
+    ///
+    /// # Parameters
+    /// - `note` - given note owned by `CORE`.
+    fn print_core_note(&self, note: &Note) {
+        let is_64 = self.file.is_64();
+
+        let result = match note.n_type {
+            NT_FILE     => FileNote::parse(note.desc, is_64).map(|f| self.print_file_note(&f)),
+            NT_PRSTATUS => PrStatus::parse(note.desc, is_64).map(|s| self.print_prstatus(&s)),
+            NT_PRPSINFO => PrPsInfo::parse(note.desc, is_64).map(|i| print_prpsinfo(&i)),
+            NT_SIGINFO  => SigInfo::parse(note.desc, is_64).map(|i| self.print_siginfo(&i)),
+            NT_AUXV     => {
+                self.print_auxv(&parse_auxv(note.desc, is_64));
+                Ok(())
+            }
+            _ => Ok(()),
+        };
+
+        if let Err(err) = result {
+            eprintln!("readelf: Error:     {}", err);
+        }
+    }
+
+    /// Format address as zero-padded hexadecimal number of the file class width.
+    ///
+    /// # Parameters
+    /// - `addr` - given address.
+    fn full_hex(&self, addr: u64) -> String {
+        match self.file.is_64() {
+            true  => format!("0x{:016x}", addr),
+            false => format!("0x{:08x}", addr),
+        }
+    }
+
+    /// Print `NT_FILE` note.
+    ///
+    /// # Parameters
+    /// - `note` - given mapped files table.
+    fn print_file_note(&self, note: &FileNote) {
+        let width = 2 * self.file.word_size();
+
+        outln!("    Page size: {}", note.page_size);
+        outln!(
+            "    {:>w1$}{:>w2$}{:>w2$}",
+            "Start", "End", "Page Offset", w1 = 2 + width, w2 = 4 + width
+        );
+
+        for file in &note.files {
+            outln!(
+                "    {}  {}  {}",
+                self.full_hex(file.start), self.full_hex(file.end), self.full_hex(file.page_offset)
+            );
+            outln!("        {}", file.name);
+        }
+    }
+
+    /// Print `NT_PRSTATUS` note.
+    ///
+    /// # Parameters
+    /// - `status` - given thread status.
+    fn print_prstatus(&self, status: &PrStatus) {
+        outln!(
+            "    Signal: {} ({}), code: {}, errno: {}, pending: {:#x}, held: {:#x}",
+            status.cursig, get_signal_name(status.cursig as i32), status.code, status.errno,
+            status.sigpend, status.sighold
+        );
+        outln!(
+            "    PID: {}, PPID: {}, PGRP: {}, SID: {}",
+            status.pid, status.ppid, status.pgrp, status.sid
+        );
+        outln!(
+            "    User time: {}.{:06}, System time: {}.{:06}",
+            status.utime.sec, status.utime.usec, status.stime.sec, status.stime.usec
+        );
+
+        let Some(names) = register_names(self.file.header().e_machine) else {
+            return;
+        };
+
+        outln!("    Registers:");
+
+        let regs: Vec<String> = names
+            .iter()
+            .zip(&status.regs)
+            .map(|(name, &value)| format!("{:<8} {}", name, self.full_hex(value)))
+            .collect();
+
+        for line in regs.chunks(3) {
+            outln!("      {}", line.join("  "));
+        }
+    }
+
+    /// Print `NT_SIGINFO` note.
+    ///
+    /// # Parameters
+    /// - `info` - given signal information.
+    fn print_siginfo(&self, info: &SigInfo) {
+        outln!(
+            "    Signal: {} ({}), code: {}, errno: {}",
+            info.signo, get_signal_name(info.signo), info.code, info.errno
+        );
+
+        if info.is_user() {
+            outln!("    Sender PID: {}, UID: {}", info.pid, info.uid);
+        } else if matches!(info.signo, SIGILL | SIGFPE | SIGSEGV | SIGBUS) {
+            outln!("    Fault address: {}", self.full_hex(info.addr));
+        }
+    }
+
+    /// Print `NT_AUXV` note.
+    ///
+    /// # Parameters
+    /// - `auxv` - given auxiliary vector entries.
+    fn print_auxv(&self, auxv: &[(u64, u64)]) {
+        for &(typ, value) in auxv {
+            let value = match typ {
+                AT_PHENT | AT_PHNUM | AT_PAGESZ | AT_UID | AT_EUID | AT_GID | AT_EGID
+                | AT_CLKTCK | AT_SECURE | AT_RSEQ_FEATURE_SIZE | AT_RSEQ_ALIGN
+                | AT_MINSIGSTKSZ => value.to_string(),
+                _ => self.full_hex(value),
+            };
+
+            outln!("    {:<20} {}", get_auxv_type(typ), value);
+        }
+    }
+
+    /// Print GNU note descriptor.
+    ///
+    /// # Parameters
+    /// - `note` - given GNU note.
+    fn print_gnu_note(&self, note: &Note) {
+        match note.n_type {
+            NT_GNU_BUILD_ID => outln!("    Build ID: {}", hex_bytes(note.desc, "")),
+            NT_GNU_ABI_TAG => {
+                let (Some(os), Some(major), Some(minor), Some(subminor)) = (
+                    note.desc_word(0), note.desc_word(1), note.desc_word(2), note.desc_word(3)
+                ) else {
+                    outln!("    <corrupt GNU_ABI_TAG>");
+                    return;
+                };
+
+                let os = match os {
+                    GNU_ABI_TAG_LINUX    => "Linux",
+                    GNU_ABI_TAG_HURD     => "Hurd",
+                    GNU_ABI_TAG_SOLARIS  => "Solaris",
+                    GNU_ABI_TAG_FREEBSD  => "FreeBSD",
+                    GNU_ABI_TAG_NETBSD   => "NetBSD",
+                    GNU_ABI_TAG_SYLLABLE => "Syllable",
+                    GNU_ABI_TAG_NACL     => "NaCl",
+                    _                    => "Unknown",
+                };
+
+                outln!("    OS: {}, ABI: {}.{}.{}", os, major, minor, subminor);
+            }
+            NT_GNU_GOLD_VERSION => {
+                let len = note.desc.iter().position(|&b| b == 0).unwrap_or(note.desc.len());
+                outln!("    Version: {}", String::from_utf8_lossy(&note.desc[..len]));
+            }
+            NT_GNU_HWCAP => {
+                out!("      Hardware Capabilities: ");
+
+                let (Some(count), Some(mask)) = (note.desc_word(0), note.desc_word(1)) else {
+                    outln!();
+                    eprintln!("readelf: Error: <corrupt GNU_HWCAP>");
+                    return;
+                };
+
+                outln!("num entries: {}, enabled mask: {:x}", count, mask);
+            }
+            NT_GNU_PROPERTY_TYPE_0 => self.print_gnu_properties(note.desc),
+            _ => outln!("    Description data: {}", hex_bytes(note.desc, " ")),
+        }
+    }
+
+    /// Print properties of `NT_GNU_PROPERTY_TYPE_0` note.
+    ///
+    /// # Parameters
+    /// - `desc` - given note descriptor.
+    fn print_gnu_properties(&self, desc: &[u8]) {
+        out!("      Properties: ");
+
+        if desc.len() < 8 || !desc.len().is_multiple_of(self.file.word_size()) {
+            outln!("<corrupt GNU_PROPERTY_TYPE, size = {:#x}>", desc.len());
+            return;
+        }
+
+        let mut properties = Vec::new();
+        let mut iter       = GnuPropertyIter::new(desc, self.file.is_64());
+
+        while let Some(property) = iter.next() {
+            let Ok(property) = property else {
+                // Malformed property is followed by an empty line.
+                let header = (read::<u32>(desc, iter.offset()), read::<u32>(desc, iter.offset() + 4));
+
+                properties.push(match header {
+                    (Ok(pr_type), Ok(datasz)) => format!(
+                        "<corrupt type ({}) datasz: {}>\n", alt_hex(pr_type as u64), alt_hex(datasz as u64)
+                    ),
+                    _ => format!("<corrupt descsz: {:#x}>\n", desc.len()),
+                });
+                break;
+            };
+
+            properties.push(self.get_gnu_property(&property));
+        }
+
+        outln!("{}", properties.join("\n\t"));
+    }
+
+    /// Get GNU program property string representation.
+    ///
+    /// # Parameters
+    /// - `property` - given program property.
+    fn get_gnu_property(&self, property: &GnuProperty) -> String {
+        let pr_type = property.pr_type;
+        let machine = self.file.header().e_machine;
+        let corrupt = format!("<corrupt length: {}> ", alt_hex(property.data.len() as u64));
+        let value   = |decode: Decoder| property.word().map_or(corrupt.clone(), decode);
+
+        if (GNU_PROPERTY_LOPROC..=GNU_PROPERTY_HIPROC).contains(&pr_type) {
+            if matches!(machine, EM_386 | EM_X86_64 | EM_IAMCU) {
+                let label: Option<(&str, Decoder)> = match pr_type {
+                    GNU_PROPERTY_X86_COMPAT_ISA_1_USED   => Some(("x86 ISA used", decode_x86_compat_isa)),
+                    GNU_PROPERTY_X86_COMPAT_ISA_1_NEEDED => Some(("x86 ISA needed", decode_x86_compat_isa)),
+                    GNU_PROPERTY_X86_COMPAT_2_ISA_1_USED => Some(("x86 ISA used", decode_x86_compat_2_isa)),
+                    GNU_PROPERTY_X86_COMPAT_2_ISA_1_NEEDED => {
+                        Some(("x86 ISA needed", decode_x86_compat_2_isa))
+                    }
+                    GNU_PROPERTY_X86_ISA_1_USED       => Some(("x86 ISA used", decode_x86_isa)),
+                    GNU_PROPERTY_X86_ISA_1_NEEDED     => Some(("x86 ISA needed", decode_x86_isa)),
+                    GNU_PROPERTY_X86_FEATURE_1_AND    => Some(("x86 feature", decode_x86_feature_1)),
+                    GNU_PROPERTY_X86_FEATURE_2_USED   => Some(("x86 feature used", decode_x86_feature_2)),
+                    GNU_PROPERTY_X86_FEATURE_2_NEEDED => Some(("x86 feature needed", decode_x86_feature_2)),
+                    _ => None,
+                };
+
+                if let Some((label, decode)) = label {
+                    return format!("{}: {}", label, value(decode));
+                }
+            } else if machine == EM_AARCH64 && pr_type == GNU_PROPERTY_AARCH64_FEATURE_1_AND {
+                return format!("AArch64 feature: {}", value(decode_aarch64_feature_1));
+            }
+        } else {
+            match pr_type {
+                GNU_PROPERTY_STACK_SIZE => {
+                    let size = match property.data.len() == self.file.word_size() {
+                        true  => alt_hex(le_value(property.data)),
+                        false => corrupt,
+                    };
+                    return format!("stack size: {}", size);
+                }
+                GNU_PROPERTY_NO_COPY_ON_PROTECTED => {
+                    let corrupt = if property.data.is_empty() { "" } else { &corrupt };
+                    return format!("no copy on protected {}", corrupt);
+                }
+                GNU_PROPERTY_UINT32_AND_LO..=GNU_PROPERTY_UINT32_AND_HI => {
+                    let value = value(|w| alt_hex(w as u64));
+                    return format!("UINT32_AND ({:#x}): {}", pr_type, value);
+                }
+                GNU_PROPERTY_UINT32_OR_LO..=GNU_PROPERTY_UINT32_OR_HI => {
+                    if pr_type == GNU_PROPERTY_1_NEEDED {
+                        return format!("1_needed: {}", value(decode_1_needed));
+                    }
+
+                    let value = value(|w| alt_hex(w as u64));
+                    return format!("UINT32_OR ({:#x}): {}", pr_type, value);
+                }
+                _ => {}
+            }
+        }
+
+        let kind = if pr_type < GNU_PROPERTY_LOPROC {
+            "unknown"
+        } else if pr_type < GNU_PROPERTY_LOUSER {
+            "processor-specific"
+        } else {
+            "application-specific"
+        };
+
+        format!("<{} type {} data: {}>", kind, alt_hex(pr_type as u64), hex_bytes(property.data, " "))
+    }
+}
+
+/// Print `NT_PRPSINFO` note.
+///
+/// # Parameters
+/// - `info` - given process information.
+fn print_prpsinfo(info: &PrPsInfo) {
+    outln!(
+        "    Name: {}, State: {} ({}), Zombie: {}, Nice: {}, Flags: {:#x}",
+        String::from_utf8_lossy(info.fname), info.state, info.sname as char, info.zomb, info.nice,
+        info.flag
+    );
+    outln!(
+        "    PID: {}, PPID: {}, PGRP: {}, SID: {}, UID: {}, GID: {}",
+        info.pid, info.ppid, info.pgrp, info.sid, info.uid, info.gid
+    );
+    outln!("    Command line: {}", String::from_utf8_lossy(info.psargs).trim_end());
+}
+
+// Signals that report the faulting address.
+
+/// Illegal instruction.
+const SIGILL: i32 = 4;
+/// Bus error.
+const SIGBUS: i32 = 7;
+/// Arithmetic exception.
+const SIGFPE: i32 = 8;
+/// Invalid memory reference.
+const SIGSEGV: i32 = 11;
+
+/// Get Linux signal name.
+///
+/// # Parameters
+/// - `signo` - given signal number.
+fn get_signal_name(signo: i32) -> &'static str {
+    const NAMES: [&str; 32] = [
+        "none", "SIGHUP", "SIGINT", "SIGQUIT", "SIGILL", "SIGTRAP", "SIGABRT", "SIGBUS",
+        "SIGFPE", "SIGKILL", "SIGUSR1", "SIGSEGV", "SIGUSR2", "SIGPIPE", "SIGALRM", "SIGTERM",
+        "SIGSTKFLT", "SIGCHLD", "SIGCONT", "SIGSTOP", "SIGTSTP", "SIGTTIN", "SIGTTOU", "SIGURG",
+        "SIGXCPU", "SIGXFSZ", "SIGVTALRM", "SIGPROF", "SIGWINCH", "SIGIO", "SIGPWR", "SIGSYS",
+    ];
+
+    usize::try_from(signo).ok().and_then(|i| NAMES.get(i)).copied().unwrap_or("unknown")
+}
+
+/// Get auxiliary vector entry type string representation.
+///
+/// # Parameters
+/// - `typ` - given entry type.
+fn get_auxv_type(typ: u64) -> String {
+    let name = match typ {
+        AT_IGNORE            => "AT_IGNORE",
+        AT_EXECFD            => "AT_EXECFD",
+        AT_PHDR              => "AT_PHDR",
+        AT_PHENT             => "AT_PHENT",
+        AT_PHNUM             => "AT_PHNUM",
+        AT_PAGESZ            => "AT_PAGESZ",
+        AT_BASE              => "AT_BASE",
+        AT_FLAGS             => "AT_FLAGS",
+        AT_ENTRY             => "AT_ENTRY",
+        AT_NOTELF            => "AT_NOTELF",
+        AT_UID               => "AT_UID",
+        AT_EUID              => "AT_EUID",
+        AT_GID               => "AT_GID",
+        AT_EGID              => "AT_EGID",
+        AT_PLATFORM          => "AT_PLATFORM",
+        AT_HWCAP             => "AT_HWCAP",
+        AT_CLKTCK            => "AT_CLKTCK",
+        AT_SECURE            => "AT_SECURE",
+        AT_BASE_PLATFORM     => "AT_BASE_PLATFORM",
+        AT_RANDOM            => "AT_RANDOM",
+        AT_HWCAP2            => "AT_HWCAP2",
+        AT_RSEQ_FEATURE_SIZE => "AT_RSEQ_FEATURE_SIZE",
+        AT_RSEQ_ALIGN        => "AT_RSEQ_ALIGN",
+        AT_HWCAP3            => "AT_HWCAP3",
+        AT_HWCAP4            => "AT_HWCAP4",
+        AT_EXECFN            => "AT_EXECFN",
+        AT_SYSINFO           => "AT_SYSINFO",
+        AT_SYSINFO_EHDR      => "AT_SYSINFO_EHDR",
+        AT_MINSIGSTKSZ       => "AT_MINSIGSTKSZ",
+        _ => return format!("<unknown: {}>", typ),
+    };
+
+    name.to_string()
+}
+
+/// Bitmask decoder.
+type Decoder = fn(u32) -> String;
+
+/// Get GNU note type string representation.
+///
+/// # Parameters
+/// - `n_type` - given note type.
+pub(crate) fn get_gnu_note_type(n_type: u32) -> String {
+    let name = match n_type {
+        NT_GNU_ABI_TAG              => "NT_GNU_ABI_TAG (ABI version tag)",
+        NT_GNU_HWCAP                => "NT_GNU_HWCAP (DSO-supplied software HWCAP info)",
+        NT_GNU_BUILD_ID             => "NT_GNU_BUILD_ID (unique build ID bitstring)",
+        NT_GNU_GOLD_VERSION         => "NT_GNU_GOLD_VERSION (gold version)",
+        NT_GNU_PROPERTY_TYPE_0      => "NT_GNU_PROPERTY_TYPE_0",
+        NT_GNU_BUILD_ATTRIBUTE_OPEN => "NT_GNU_BUILD_ATTRIBUTE_OPEN",
+        NT_GNU_BUILD_ATTRIBUTE_FUNC => "NT_GNU_BUILD_ATTRIBUTE_FUNC",
+        _ => return format!("Unknown note type: (0x{:08x})", n_type),
+    };
+
+    name.to_string()
+}
+
+/// Format bytes as hexadecimal numbers.
+///
+/// # Parameters
+/// - `bytes` - given bytes.
+/// - `sep`   - given separator printed after each byte.
+pub(crate) fn hex_bytes(bytes: &[u8], sep: &str) -> String {
+    bytes.iter().map(|b| format!("{:02x}{}", b, sep)).collect()
+}
+
+/// Format number like `printf("%#x")` does, which prints zero without prefix.
+fn alt_hex(value: u64) -> String {
+    match value {
+        0 => "0".to_string(),
+        _ => format!("{:#x}", value),
+    }
+}
+
+/// Read little-endian number of any size up to 8 bytes.
+fn le_value(bytes: &[u8]) -> u64 {
+    bytes.iter().rev().fold(0, |value, &b| (value << 8) | b as u64)
+}
+
+/// Get list of bit names separated by commas.
+///
+/// # Parameters
+/// - `bitmask` - given bitmask.
+/// - `names`   - given bit names.
+/// - `none`    - given string to show for zero bitmask.
+fn decode_bits(mut bitmask: u32, names: &[(u32, &str)], none: &str) -> String {
+    if bitmask == 0 {
+        return none.to_string();
+    }
+
+    let mut list = Vec::new();
+
+    while bitmask != 0 {
+        let bit = bitmask & bitmask.wrapping_neg();
+        bitmask &= !bit;
+
+        list.push(match names.iter().find(|&&(b, _)| b == bit) {
+            Some(&(_, name)) => name.to_string(),
+            None             => format!("<unknown: {:x}>", bit),
+        });
+    }
+
+    list.join(", ")
+}
+
+/// Decode x86 ISA level bitmask.
+fn decode_x86_isa(bitmask: u32) -> String {
+    let names = [
+        (GNU_PROPERTY_X86_ISA_1_BASELINE, "x86-64-baseline"),
+        (GNU_PROPERTY_X86_ISA_1_V2,       "x86-64-v2"),
+        (GNU_PROPERTY_X86_ISA_1_V3,       "x86-64-v3"),
+        (GNU_PROPERTY_X86_ISA_1_V4,       "x86-64-v4"),
+    ];
+
+    decode_bits(bitmask, &names, "<None>")
+}
+
+/// Decode obsolete x86 ISA bitmask.
+fn decode_x86_compat_isa(bitmask: u32) -> String {
+    let names = [
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_486,      "i486"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_586,      "586"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_686,      "686"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_SSE,      "SSE"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_SSE2,     "SSE2"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_SSE3,     "SSE3"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_SSSE3,    "SSSE3"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_SSE4_1,   "SSE4_1"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_SSE4_2,   "SSE4_2"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX,      "AVX"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX2,     "AVX2"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512F,  "AVX512F"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512CD, "AVX512CD"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512ER, "AVX512ER"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512PF, "AVX512PF"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512VL, "AVX512VL"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512DQ, "AVX512DQ"),
+        (GNU_PROPERTY_X86_COMPAT_ISA_1_AVX512BW, "AVX512BW"),
+    ];
+
+    decode_bits(bitmask, &names, "<None>")
+}
+
+/// Decode obsolete x86 ISA bitmask, second version.
+///
+/// Bits are numbered in the order of names.
+fn decode_x86_compat_2_isa(bitmask: u32) -> String {
+    let names = [
+        "CMOV", "SSE", "SSE2", "SSE3", "SSSE3", "SSE4_1", "SSE4_2", "AVX", "AVX2", "FMA", "AVX512F", "AVX512CD", "AVX512ER", "AVX512PF", "AVX512VL", "AVX512DQ", "AVX512BW",
+        "AVX512_4FMAPS", "AVX512_4VNNIW", "AVX512_BITALG", "AVX512_IFMA", "AVX512_VBMI",
+        "AVX512_VBMI2", "AVX512_VNNI", "AVX512_BF16",
+    ];
+    let names: Vec<(u32, &str)> = names.iter().enumerate().map(|(i, &n)| (1 << i, n)).collect();
+
+    decode_bits(bitmask, &names, "<None>")
+}
+
+/// Decode x86 control-flow enforcement features bitmask.
+fn decode_x86_feature_1(bitmask: u32) -> String {
+    let names = [
+        (GNU_PROPERTY_X86_FEATURE_1_IBT,     "IBT"),
+        (GNU_PROPERTY_X86_FEATURE_1_SHSTK,   "SHSTK"),
+        (GNU_PROPERTY_X86_FEATURE_1_LAM_U48, "LAM_U48"),
+        (GNU_PROPERTY_X86_FEATURE_1_LAM_U57, "LAM_U57"),
+    ];
+
+    decode_bits(bitmask, &names, "<None>")
+}
+
+/// Decode x86 used or needed features bitmask.
+fn decode_x86_feature_2(bitmask: u32) -> String {
+    let names = [
+        (GNU_PROPERTY_X86_FEATURE_2_X86,      "x86"),
+        (GNU_PROPERTY_X86_FEATURE_2_X87,      "x87"),
+        (GNU_PROPERTY_X86_FEATURE_2_MMX,      "MMX"),
+        (GNU_PROPERTY_X86_FEATURE_2_XMM,      "XMM"),
+        (GNU_PROPERTY_X86_FEATURE_2_YMM,      "YMM"),
+        (GNU_PROPERTY_X86_FEATURE_2_ZMM,      "ZMM"),
+        (GNU_PROPERTY_X86_FEATURE_2_FXSR,     "FXSR"),
+        (GNU_PROPERTY_X86_FEATURE_2_XSAVE,    "XSAVE"),
+        (GNU_PROPERTY_X86_FEATURE_2_XSAVEOPT, "XSAVEOPT"),
+        (GNU_PROPERTY_X86_FEATURE_2_XSAVEC,   "XSAVEC"),
+        (GNU_PROPERTY_X86_FEATURE_2_TMM,      "TMM"),
+        (GNU_PROPERTY_X86_FEATURE_2_MASK,     "MASK"),
+    ];
+
+    decode_bits(bitmask, &names, "<None>")
+}
+
+/// Decode AArch64 branch protection features bitmask.
+fn decode_aarch64_feature_1(bitmask: u32) -> String {
+    let names = [
+        (GNU_PROPERTY_AARCH64_FEATURE_1_BTI, "BTI"),
+        (GNU_PROPERTY_AARCH64_FEATURE_1_PAC, "PAC"),
+    ];
+
+    decode_bits(bitmask, &names, "")
+}
+
+/// Decode run-time features bitmask.
+fn decode_1_needed(bitmask: u32) -> String {
+    let names = [(GNU_PROPERTY_1_NEEDED_INDIRECT_EXTERN_ACCESS, "indirect external access")];
+
+    decode_bits(bitmask, &names, "<None>")
+}
@@ -0,0 +1,72 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Standard output module.
+//!
+//! Displayed information is written to locked standard output by `out!` and
+//! `outln!` macros. Unlike `print!` they do not panic if writing fails, e.g.
+//! when the reader of a pipe exits early: the first error is kept and the
+//! rest of the output is dropped, so the caller can stop and report it.
+
+use std::fmt;
+use std::io::{self, ErrorKind, Write};
+use std::sync::Mutex;
+
+/// First error of writing to standard output.
+static ERROR: Mutex<Option<ErrorKind>> = Mutex::new(None);
+
+/// Write formatted text to standard output.
+///
+/// Nothing is written once writing failed.
+///
+/// # Parameters
+/// - `args` - given formatted text.
+pub fn write(args: fmt::Arguments) {
+    let mut error = ERROR.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if error.is_none()
+        && let Err(err) = io::stdout().lock().write_fmt(args)
+    {
+        *error = Some(err.kind());
+    }
+}
+
+/// Flush standard output.
+///
+/// # Returns
+/// - Kind of the first error of writing to standard output - if writing
+///   or flushing failed.
+/// - `None` - otherwise.
+pub fn flush() -> Option<ErrorKind> {
+    let mut error = ERROR.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    if error.is_none()
+        && let Err(err) = io::stdout().lock().flush()
+    {
+        *error = Some(err.kind());
+    }
+
+    *error
+}
+
+/// Get kind of the first error of writing to standard output.
+///
+/// # Returns
+/// - Error kind - if writing failed.
+/// - `None` - otherwise.
+pub fn error() -> Option<ErrorKind> {
+    *ERROR.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
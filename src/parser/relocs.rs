@@ -0,0 +1,300 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Relocations display module.
+
+use crate::elf::elfhdr::Machine;
+use crate::elf::reloc::*;
+use crate::elf::sechdr::*;
+use crate::elf::symtbl::{elf32_st_type, Elf64_Sym, STT_SECTION};
+use crate::parser::{fit_symbol, ElfParser};
+
+impl ElfParser<'_> {
+    /// Print contents of relocation sections.
+    pub fn print_relocs(&self) {
+        let mut found = false;
+
+        for shdr in self.file.section_headers() {
+            let is_reloc = matches!(
+                shdr.sh_type,
+                SHT_REL | SHT_RELA | SHT_RELR | SHT_ANDROID_REL | SHT_ANDROID_RELA
+            );
+
+            if !is_reloc || shdr.sh_size == 0 {
+                continue;
+            }
+
+            found = true;
+
+            let name  = self.file.section_name(shdr).unwrap_or("<corrupt>");
+            let count = match shdr.sh_type {
+                SHT_RELR           => self.file.relr_count(shdr),
+                SHT_REL | SHT_RELA => self.file.relocation_table(shdr).map_or(0, |r| r.len()),
+                _                  => self.file.relocations(shdr).map_or(0, |r| r.len()),
+            };
+
+            outln!();
+            outln!(
+                "Relocation section '{}' at offset {:#x} contains {} {}:",
+                name, { shdr.sh_offset }, count, if count == 1 { "entry" } else { "entries" }
+            );
+
+            match shdr.sh_type {
+                SHT_RELR => self.print_relr(shdr),
+                _        => self.print_rel(shdr),
+            }
+        }
+
+        if !found {
+            outln!();
+            outln!("There are no relocations in this file.");
+        }
+    }
+
+    /// Print addresses relocated by packed relative relocation section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given `SHT_RELR` section header.
+    fn print_relr(&self, shdr: &Elf64_Shdr) {
+        let addrs: Vec<u64> = match self.file.relr(shdr) {
+            Ok(relr) => relr.collect(),
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return;
+            }
+        };
+
+        outln!(
+            "  {} {}",
+            addrs.len(), if addrs.len() == 1 { "offset" } else { "offsets" }
+        );
+
+        for addr in addrs {
+            match self.file.is_64() {
+                true  => outln!("{:016x}", addr),
+                false => outln!("{:08x}", addr),
+            }
+        }
+    }
+
+    /// Print relocation entries.
+    ///
+    /// # Parameters
+    /// - `shdr` - given relocation section header.
+    fn print_rel(&self, shdr: &Elf64_Shdr) {
+        let is_rela = matches!(shdr.sh_type, SHT_RELA | SHT_ANDROID_RELA);
+        let is_64   = self.file.is_64();
+
+        let rels = match self.file.relocations(shdr) {
+            Ok(rels) => rels,
+            Err(err) => {
+                eprintln!("readelf: Error: {}", err);
+                return;
+            }
+        };
+
+        let symtab = self
+            .file
+            .section_header(shdr.sh_link as usize)
+            .ok()
+            .filter(|s| matches!(s.sh_type, SHT_SYMTAB | SHT_DYNSYM))
+            .copied();
+
+        match (is_64, is_rela) {
+            (false, false) => outln!(" Offset     Info    Type            Sym.Value  Sym. Name"),
+            (false, true)  => outln!(" Offset     Info    Type            Sym.Value  Sym. Name + Addend"),
+            (true, false)  => outln!("  Offset          Info           Type           Sym. Value    Sym. Name"),
+            (true, true)   => outln!("  Offset          Info           Type           Sym. Value    Sym. Name + Addend"),
+        }
+
+        let machine = self.file.header().machine();
+        let entries = match symtab {
+            Some(symtab) if symtab.sh_type == SHT_DYNSYM => self.file.dynamic().unwrap_or_default(),
+            _                                            => Vec::new(),
+        };
+
+        for rel in rels {
+            let (offset, info, addend) = (rel.r_offset, rel.r_info, rel.r_addend);
+            let typ = elf64_r_type(info);
+            let sym = elf64_r_sym(info);
+
+            let mut line = match is_64 {
+                true  => format!("{:012x}  {:012x} ", offset, info),
+                false => format!("{:08x}  {:08x} ", offset, elf32_r_info(sym, typ as u8)),
+            };
+
+            match get_reloc_type(machine, typ) {
+                Some(name) => line.push_str(&format!("{:<17.17}", name)),
+                None       => line.push_str(&format!("unrecognized: {:<7x}", typ)),
+            }
+
+            if sym != 0 {
+                let symbol = symtab.and_then(|t| Some((t, self.file.symbol(&t, sym as usize).ok()?)));
+
+                match symbol {
+                    Some((symtab, symbol)) => {
+                        let value = symbol.st_value;
+
+                        match is_64 {
+                            true  => line.push_str(&format!(" {:016x} ", value)),
+                            false => line.push_str(&format!(" {:08x}   ", value)),
+                        }
+
+                        let name = self.reloc_symbol_name(&symtab, &symbol);
+                        line.push_str(&fit_symbol(22, &name, &self.display_name(&name)));
+
+                        let version = self.file.symbol_version(&entries, sym as usize, &symbol);
+
+                        if let Ok(Some(version)) = version {
+                            match version.is_default() {
+                                true  => line.push_str(&format!("@@{}", version.name())),
+                                false => line.push_str(&format!("@{}", version.name())),
+                            }
+                        }
+                    }
+                    None => line.push_str(&format!(" <invalid symbol index {}>", sym)),
+                }
+
+                if is_rela {
+                    match addend < 0 {
+                        true  => line.push_str(&format!(" - {:x}", addend.unsigned_abs())),
+                        false => line.push_str(&format!(" + {:x}", addend)),
+                    }
+                }
+            } else if is_rela {
+                line.push_str(&" ".repeat(if is_64 { 20 } else { 12 }));
+
+                match addend < 0 {
+                    true  => line.push_str(&format!("-{:x}", addend.unsigned_abs())),
+                    false => line.push_str(&format!("{:x}", addend)),
+                }
+            }
+
+            outln!("{}", line);
+        }
+    }
+
+    /// Get name of the symbol referenced by relocation.
+    ///
+    /// Section symbols have no names, so the section name is used instead.
+    ///
+    /// # Parameters
+    /// - `symtab` - given symbol table section header.
+    /// - `sym`    - given symbol.
+    pub(crate) fn reloc_symbol_name(&self, symtab: &Elf64_Shdr, sym: &Elf64_Sym) -> String {
+        if sym.st_name != 0 {
+            return self.file.symbol_name(symtab, sym).unwrap_or("<corrupt>").to_string();
+        }
+
+        if elf32_st_type(sym.st_info) != STT_SECTION {
+            return "<null>".to_string();
+        }
+
+        match sym.st_shndx as u32 {
+            SHN_ABS    => "ABS".to_string(),
+            SHN_COMMON => "COMMON".to_string(),
+            shndx => match self.file.section_header(shndx as usize) {
+                Ok(shdr) => self.file.section_name(shdr).unwrap_or("<corrupt>").to_string(),
+                Err(_)   => format!("<section 0x{:x}>", shndx),
+            },
+        }
+    }
+}
+
+/// Get relocation type string representation.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+/// - `typ`     - given relocation type, its meaning depends on the machine.
+///
+/// # Returns
+/// - Relocation type name - if type is known.
+/// - `None` - otherwise.
+pub fn get_reloc_type(machine: Machine, typ: u32) -> Option<&'static str> {
+    let names: &[&str] = match machine {
+        Machine::I386    => return Reloc386::from(typ).name(),
+        Machine::X86_64  => &R_X86_64_NAMES,
+        Machine::Arm     => return get_arm_reloc_type(typ),
+        Machine::Aarch64 => return get_aarch64_reloc_type(typ),
+        _                => return None,
+    };
+
+    names.get(typ as usize).copied().filter(|name| !name.is_empty())
+}
+
+/// AMD x86-64 relocation type names.
+const R_X86_64_NAMES: [&str; 43] = [
+    "R_X86_64_NONE", "R_X86_64_64", "R_X86_64_PC32", "R_X86_64_GOT32",
+    "R_X86_64_PLT32", "R_X86_64_COPY", "R_X86_64_GLOB_DAT",
+    "R_X86_64_JUMP_SLOT", "R_X86_64_RELATIVE", "R_X86_64_GOTPCREL",
+    "R_X86_64_32", "R_X86_64_32S", "R_X86_64_16", "R_X86_64_PC16",
+    "R_X86_64_8", "R_X86_64_PC8", "R_X86_64_DTPMOD64", "R_X86_64_DTPOFF64",
+    "R_X86_64_TPOFF64", "R_X86_64_TLSGD", "R_X86_64_TLSLD",
+    "R_X86_64_DTPOFF32", "R_X86_64_GOTTPOFF", "R_X86_64_TPOFF32",
+    "R_X86_64_PC64", "R_X86_64_GOTOFF64", "R_X86_64_GOTPC32",
+    "R_X86_64_GOT64", "R_X86_64_GOTPCREL64", "R_X86_64_GOTPC64",
+    "R_X86_64_GOTPLT64", "R_X86_64_PLTOFF64", "R_X86_64_SIZE32",
+    "R_X86_64_SIZE64", "R_X86_64_GOTPC32_TLSDESC", "R_X86_64_TLSDESC_CALL",
+    "R_X86_64_TLSDESC", "R_X86_64_IRELATIVE", "R_X86_64_RELATIVE64",
+    "R_X86_64_PC32_BND", "R_X86_64_PLT32_BND", "R_X86_64_GOTPCRELX",
+    "R_X86_64_REX_GOTPCRELX",
+];
+
+/// Get ARM relocation type string representation.
+///
+/// Only types that may appear in shared objects are known.
+fn get_arm_reloc_type(typ: u32) -> Option<&'static str> {
+    Some(match typ {
+        0   => "R_ARM_NONE",
+        2   => "R_ARM_ABS32",
+        3   => "R_ARM_REL32",
+        13  => "R_ARM_TLS_DESC",
+        17  => "R_ARM_TLS_DTPMOD32",
+        18  => "R_ARM_TLS_DTPOFF32",
+        19  => "R_ARM_TLS_TPOFF32",
+        20  => "R_ARM_COPY",
+        21  => "R_ARM_GLOB_DAT",
+        22  => "R_ARM_JUMP_SLOT",
+        23  => "R_ARM_RELATIVE",
+        160 => "R_ARM_IRELATIVE",
+        _   => return None,
+    })
+}
+
+/// Get AArch64 relocation type string representation.
+///
+/// Only types that may appear in shared objects are known.
+fn get_aarch64_reloc_type(typ: u32) -> Option<&'static str> {
+    Some(match typ {
+        0    => "R_AARCH64_NONE",
+        257  => "R_AARCH64_ABS64",
+        258  => "R_AARCH64_ABS32",
+        259  => "R_AARCH64_ABS16",
+        260  => "R_AARCH64_PREL64",
+        261  => "R_AARCH64_PREL32",
+        262  => "R_AARCH64_PREL16",
+        1024 => "R_AARCH64_COPY",
+        1025 => "R_AARCH64_GLOB_DAT",
+        1026 => "R_AARCH64_JUMP_SLOT",
+        1027 => "R_AARCH64_RELATIVE",
+        1028 => "R_AARCH64_TLS_DTPMOD64",
+        1029 => "R_AARCH64_TLS_DTPREL64",
+        1030 => "R_AARCH64_TLS_TPREL64",
+        1031 => "R_AARCH64_TLSDESC",
+        1032 => "R_AARCH64_IRELATIVE",
+        _    => return None,
+    })
+}
@@ -0,0 +1,456 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Section headers display module.
+
+use crate::elf::elfhdr::*;
+use crate::elf::file::ElfError;
+use crate::elf::sechdr::*;
+use crate::parser::{fit_symbol, ElfParser};
+
+impl ElfParser<'_> {
+    /// Print section headers.
+    ///
+    /// # Parameters
+    /// - `details`    - given flag to print flags and compression headers in
+    ///   full, as `--section-details` does.
+    /// - `show_count` - given flag to print number and offset of headers first.
+    pub fn print_section_headers(&self, details: bool, show_count: bool) {
+        let hdr   = self.file.header();
+        let shdrs = self.file.section_headers();
+        let shnum = shdrs.len();
+
+        if shnum == 0 {
+            match hdr.e_shoff {
+                0 => outln!("\nThere are no sections in this file."),
+                _ => eprintln!(
+                    "readelf: Warning: possibly corrupt ELF file header - it has a non-zero \
+                     section header offset, but no section headers"
+                ),
+            }
+            return;
+        }
+
+        if show_count {
+            match shnum {
+                1 => outln!("There is {} section header, starting at offset {:#x}:", shnum, { hdr.e_shoff }),
+                _ => outln!("There are {} section headers, starting at offset {:#x}:", shnum, { hdr.e_shoff }),
+            }
+        }
+
+        outln!();
+        outln!("{}", if shnum > 1 { "Section Headers:" } else { "Section Header:" });
+
+        match (self.file.is_64(), details) {
+            (false, true) => {
+                outln!("  [Nr] Name");
+                outln!("       Type            Addr     Off    Size   ES   Lk Inf Al");
+            }
+            (false, false) => {
+                outln!("  [Nr] Name              Type            Addr     Off    Size   ES Flg Lk Inf Al");
+            }
+            (true, true) => {
+                outln!("  [Nr] Name");
+                outln!("       Type              Address          Offset            Link");
+                outln!("       Size              EntSize          Info              Align");
+            }
+            (true, false) => {
+                outln!("  [Nr] Name              Type             Address           Offset");
+                outln!("       Size              EntSize          Flags  Link  Info  Align");
+            }
+        }
+
+        if details {
+            outln!("       Flags");
+        }
+
+        for (index, shdr) in shdrs.iter().enumerate() {
+            self.check_section_header(index, shdr);
+            self.print_section_header(index, shdr, details);
+        }
+
+        if !details {
+            self.print_flags_key();
+        }
+    }
+
+    /// Print single section header row.
+    ///
+    /// # Parameters
+    /// - `index`   - given section index.
+    /// - `shdr`    - given section header.
+    /// - `details` - given flag to print flags and compression header in full.
+    fn print_section_header(&self, index: usize, shdr: &Elf64_Shdr, details: bool) {
+        let hdr   = self.file.header();
+        let shnum = self.file.section_headers().len();
+        let name  = self.file.section_name(shdr).unwrap_or("<corrupt>");
+        let typ   = get_section_type_name(hdr.machine(), shdr.section_type());
+
+        let (addr, offset, size, entsize) = (shdr.sh_addr, shdr.sh_offset, shdr.sh_size, shdr.sh_entsize);
+        let (link, info, align)           = (shdr.sh_link, shdr.sh_info, shdr.sh_addralign);
+
+        let mut line = format!("  [{:2}] ", index);
+
+        match details {
+            true  => line.push_str(&format!("{}\n      ", name)),
+            false => line.push_str(&format!("{:<17}", fit_symbol(17, name, name))),
+        }
+
+        line.push_str(&format!(" {:<15.15} ", typ));
+
+        let flags = self.get_section_flags(shdr.sh_flags, details);
+
+        if !self.file.is_64() {
+            line.push_str(&format!("{:08x} {:06x} {:06x} {:02x}", addr, offset, size, entsize));
+
+            match details {
+                true  => line.push_str("  "),
+                false => line.push_str(&format!(" {:>3} ", flags)),
+            }
+
+            // Solaris uses out of range values to order sections.
+            let special = match hdr.e_machine {
+                EM_386 | EM_IAMCU | EM_X86_64 | EM_L1OM | EM_K1OM | EM_OLD_SPARCV9
+                | EM_SPARC32PLUS | EM_SPARCV9 | EM_SPARC => match link {
+                    SHN_BEFORE => Some("BEFORE"),
+                    SHN_AFTER  => Some("AFTER"),
+                    _          => None,
+                },
+                _ => None,
+            };
+
+            match special {
+                Some(special) if details && link as usize >= shnum => {
+                    line.push_str(&format!("<{}> ", special));
+                }
+                _ => line.push_str(&format!("{:2} ", link)),
+            }
+
+            line.push_str(&format!("{:3} {:2}", info, align));
+            outln!("{}", line);
+
+            if link as usize >= shnum && special.is_none() {
+                eprintln!(
+                    "readelf: Warning: section {}: sh_link value of {} is larger than the number of sections",
+                    index, link
+                );
+            }
+        } else if details {
+            line.push_str(&format!(" {:016x}  {:016x}  {}\n       ", addr, offset, link));
+            line.push_str(&format!("{:016x} {:016x}  {:<16}  {}", size, entsize, info, align));
+            outln!("{}", line);
+        } else {
+            line.push_str(&format!(" {:016x}  {:08x}\n       ", addr, offset));
+            line.push_str(&format!("{:016x}  {:016x} {:>3} ", size, entsize, flags));
+            line.push_str(&format!("     {:2}   {:3}     {}", link, info, align));
+            outln!("{}", line);
+        }
+
+        if details {
+            outln!("       {}", flags);
+
+            if shdr.sh_flags & SHF_COMPRESSED as u64 != 0 {
+                self.print_compression_header(shdr);
+            }
+        }
+    }
+
+    /// Print compression header of `SHF_COMPRESSED` section.
+    ///
+    /// # Parameters
+    /// - `shdr` - given section header.
+    fn print_compression_header(&self, shdr: &Elf64_Shdr) {
+        // GNU readelf reads 24 bytes regardless of the header size.
+        let chdr = match shdr.sh_offset.checked_add(24) {
+            Some(end) if end <= self.file.size() => self.file.compression_header(shdr),
+            _ => Err(ElfError::Truncated),
+        };
+
+        let Ok(chdr) = chdr else {
+            eprintln!("readelf: Error: Reading 24 bytes extends past end of file for compression header");
+            return;
+        };
+
+        let (size, align) = (chdr.ch_size, chdr.ch_addralign);
+
+        let typ = match chdr.ch_type {
+            ELFCOMPRESS_ZLIB => "ZLIB".to_string(),
+            ELFCOMPRESS_ZSTD => "ZSTD".to_string(),
+            typ              => format!("[<unknown>: 0x{:x}]", typ),
+        };
+
+        match self.file.is_64() {
+            true  => outln!("       {}, {:016x}, {}", typ, size, align),
+            false => outln!("       {}, {:08x}, {}", typ, size, align),
+        }
+    }
+
+    /// Check section header fields for consistency and warn about problems.
+    ///
+    /// # Parameters
+    /// - `index` - given section index.
+    /// - `shdr`  - given section header.
+    fn check_section_header(&self, index: usize, shdr: &Elf64_Shdr) {
+        let hdr   = self.file.header();
+        let shdrs = self.file.section_headers();
+        let typ   = shdr.sh_type;
+        let link  = shdr.sh_link;
+        let info  = shdr.sh_info;
+        let flags = shdr.sh_flags;
+
+        // Type of section referred by link or info field.
+        let linked = |i: u32| match i {
+            0 => None,
+            i => shdrs.get(i as usize).map(|s| s.sh_type),
+        };
+
+        // Dynamic relocations may have no symbol table and relocate segments.
+        let is_dynamic = matches!(hdr.e_type, ET_EXEC | ET_DYN);
+
+        // Relocated section must hold data.
+        let relocatable = |i: u32| match linked(i) {
+            Some(SHT_PROGBITS | SHT_NOBITS | SHT_NOTE | SHT_INIT_ARRAY | SHT_FINI_ARRAY | SHT_PREINIT_ARRAY) => true,
+            Some(t) => t >= SHT_LOOS,
+            None    => false,
+        };
+
+        match typ {
+            SHT_REL | SHT_RELR | SHT_RELA if link == 0 && is_dynamic => {}
+            SHT_REL | SHT_RELR | SHT_RELA | SHT_SYMTAB_SHNDX | SHT_GROUP | SHT_HASH
+            | SHT_GNU_HASH | SHT_GNU_VERSYM if !matches!(linked(link), Some(SHT_SYMTAB | SHT_DYNSYM)) => {
+                eprintln!(
+                    "readelf: Warning: [{:2}]: Link field ({}) should index a symtab section.",
+                    index, link
+                );
+            }
+            SHT_DYNAMIC | SHT_SYMTAB | SHT_DYNSYM | SHT_GNU_VERNEED | SHT_GNU_VERDEF
+            | SHT_GNU_LIBLIST if linked(link) != Some(SHT_STRTAB) => {
+                eprintln!(
+                    "readelf: Warning: [{:2}]: Link field ({}) should index a string section.",
+                    index, link
+                );
+            }
+            SHT_INIT_ARRAY | SHT_FINI_ARRAY | SHT_PREINIT_ARRAY if link != 0 => {
+                eprintln!("readelf: Warning: [{:2}]: Unexpected value ({}) in link field.", index, link);
+            }
+            _ => {}
+        }
+
+        match typ {
+            SHT_REL | SHT_RELA if info == 0 && is_dynamic => {}
+            SHT_REL | SHT_RELA if !relocatable(info) => {
+                eprintln!(
+                    "readelf: Warning: [{:2}]: Info field ({}) should index a relocatable section.",
+                    index, info
+                );
+            }
+            SHT_DYNAMIC | SHT_HASH | SHT_SYMTAB_SHNDX | SHT_INIT_ARRAY | SHT_FINI_ARRAY
+            | SHT_PREINIT_ARRAY if info != 0 => {
+                eprintln!("readelf: Warning: [{:2}]: Unexpected value ({}) in info field.", index, info);
+            }
+            // Info field holds symbol index or is checked above.
+            SHT_REL | SHT_RELA | SHT_DYNAMIC | SHT_HASH | SHT_SYMTAB_SHNDX | SHT_INIT_ARRAY
+            | SHT_FINI_ARRAY | SHT_PREINIT_ARRAY | SHT_GROUP | SHT_SYMTAB | SHT_DYNSYM => {}
+            // Headers of sections removed by strip keep their info fields.
+            SHT_NOBITS => {}
+            _ if flags & SHF_INFO_LINK as u64 != 0 && (info < 1 || info as usize >= shdrs.len()) => {
+                // No new line, as GNU readelf does.
+                eprint!("readelf: Warning: [{:2}]: Expected link to another section in info field", index);
+            }
+            _ if flags & SHF_INFO_LINK as u64 != 0 => {}
+            _ if typ < SHT_LOOS && flags & SHF_GNU_MBIND as u64 == 0 && info != 0 => {
+                eprintln!("readelf: Warning: [{:2}]: Unexpected value ({}) in info field.", index, info);
+            }
+            _ => {}
+        }
+
+        if shdr.sh_size > self.file.size()
+            && !matches!(typ, SHT_NOBITS | SHT_NULL)
+            && typ < SHT_LOOS
+        {
+            eprintln!("readelf: Warning: Size of section {} is larger than the entire file!", index);
+        }
+    }
+
+    /// Get section flags string representation.
+    ///
+    /// # Parameters
+    /// - `flags`   - given section flags.
+    /// - `details` - given flag to spell flags out instead of using letters.
+    pub(crate) fn get_section_flags(&self, flags: u64, details: bool) -> String {
+        let hdr     = self.file.header();
+        let machine = hdr.e_machine;
+        let osabi   = hdr.e_ident[EI_OSABI];
+        let width   = if self.file.is_64() { 16 } else { 8 };
+
+        let mut names   = Vec::new();
+        let mut letters = String::new();
+        let mut os      = 0u64;
+        let mut proc    = 0u64;
+        let mut unknown = 0u64;
+        let mut rest    = flags;
+
+        while rest != 0 {
+            let flag = rest & rest.wrapping_neg();
+            rest &= !flag;
+
+            let name = match flag as u32 {
+                SHF_WRITE            => Some(("WRITE", 'W')),
+                SHF_ALLOC            => Some(("ALLOC", 'A')),
+                SHF_EXECINSTR        => Some(("EXEC", 'X')),
+                SHF_MERGE            => Some(("MERGE", 'M')),
+                SHF_STRINGS          => Some(("STRINGS", 'S')),
+                SHF_INFO_LINK        => Some(("INFO LINK", 'I')),
+                SHF_LINK_ORDER       => Some(("LINK ORDER", 'L')),
+                SHF_OS_NONCONFORMING => Some(("OS NONCONF", 'O')),
+                SHF_GROUP            => Some(("GROUP", 'G')),
+                SHF_TLS              => Some(("TLS", 'T')),
+                SHF_EXCLUDE          => Some(("EXCLUDE", 'E')),
+                SHF_COMPRESSED       => Some(("COMPRESSED", 'C')),
+                _                    => None,
+            };
+
+            if let Some((name, letter)) = name {
+                names.push(name);
+                letters.push(letter);
+                continue;
+            }
+
+            let is_gnu = matches!(osabi, ELFOSABI_GNU | ELFOSABI_FREEBSD);
+            let mbind  = is_gnu || osabi == ELFOSABI_NONE;
+
+            if details {
+                let name = match (machine, flag as u32) {
+                    (_, SHF_GNU_RETAIN) if is_gnu => Some("GNU_RETAIN"),
+                    (_, SHF_GNU_MBIND) if mbind   => Some("GNU_MBIND"),
+                    (EM_386 | EM_IAMCU | EM_X86_64 | EM_L1OM | EM_K1OM | EM_OLD_SPARCV9
+                    | EM_SPARC32PLUS | EM_SPARCV9 | EM_SPARC, SHF_ORDERED) => Some("ORDERED"),
+                    (EM_ARM, SHF_ENTRYSECT)       => Some("ENTRYSECT"),
+                    (EM_ARM, SHF_ARM_PURECODE)    => Some("ARM_PURECODE"),
+                    (EM_ARM, SHF_COMDEF)          => Some("COMDEF"),
+                    (EM_PPC, SHF_PPC_VLE)         => Some("VLE"),
+                    _                             => None,
+                };
+
+                match name {
+                    Some(name)                              => names.push(name),
+                    None if flag & SHF_MASKOS as u64 != 0   => os |= flag,
+                    None if flag & SHF_MASKPROC as u64 != 0 => proc |= flag,
+                    None                                    => unknown |= flag,
+                }
+                continue;
+            }
+
+            let letter = match (machine, flag as u32) {
+                (EM_X86_64 | EM_L1OM | EM_K1OM, SHF_X86_64_LARGE) => 'l',
+                (EM_ARM, SHF_ARM_PURECODE)                        => 'y',
+                (EM_PPC, SHF_PPC_VLE)                             => 'v',
+                (_, SHF_GNU_RETAIN) if is_gnu                     => 'R',
+                (_, SHF_GNU_MBIND) if mbind                       => 'D',
+                _ if flag & SHF_MASKOS as u64 != 0 => {
+                    rest &= !(SHF_MASKOS as u64);
+                    'o'
+                }
+                _ if flag & SHF_MASKPROC as u64 != 0 => {
+                    rest &= !(SHF_MASKPROC as u64);
+                    'p'
+                }
+                _ => 'x',
+            };
+
+            letters.push(letter);
+        }
+
+        if !details {
+            return letters;
+        }
+
+        let mut names: Vec<String> = names.iter().map(|name| name.to_string()).collect();
+
+        if os != 0 {
+            names.push(format!("OS ({:01$x})", os, width));
+        }
+
+        if proc != 0 {
+            names.push(format!("PROC ({:01$x})", proc, width));
+        }
+
+        if unknown != 0 {
+            names.push(format!("UNKNOWN ({:01$x})", unknown, width));
+        }
+
+        format!("[{:02$x}]: {}", flags, names.join(", "), width)
+    }
+
+    /// Print legend of section flags letters.
+    fn print_flags_key(&self) {
+        let hdr   = self.file.header();
+        let osabi = hdr.e_ident[EI_OSABI];
+
+        outln!("Key to Flags:");
+        outln!("  W (write), A (alloc), X (execute), M (merge), S (strings), I (info),");
+        outln!("  L (link order), O (extra OS processing required), G (group), T (TLS),");
+        outln!("  C (compressed), x (unknown), o (OS specific), E (exclude),");
+
+        let mut line = String::from("  ");
+
+        if matches!(osabi, ELFOSABI_GNU | ELFOSABI_FREEBSD) {
+            line.push_str("R (retain), ");
+        }
+
+        if matches!(osabi, ELFOSABI_GNU | ELFOSABI_FREEBSD | ELFOSABI_NONE) {
+            line.push_str("D (mbind), ");
+        }
+
+        match hdr.e_machine {
+            EM_X86_64 | EM_L1OM | EM_K1OM => line.push_str("l (large), "),
+            EM_ARM                        => line.push_str("y (purecode), "),
+            EM_PPC                        => line.push_str("v (VLE), "),
+            _                             => {}
+        }
+
+        line.push_str("p (processor specific)");
+        outln!("{}", line);
+    }
+}
+
+/// Get section type string representation.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+/// - `typ`     - given section type.
+///
+/// # Returns
+/// Section type string representation.
+pub fn get_section_type_name(machine: Machine, typ: SectionType) -> String {
+    let name = match (machine, u32::from(typ)) {
+        (_, 0x6ffffff0)                                                     => "VERSYM",
+        (_, 0x6ffffffc)                                                     => "VERDEF",
+        (_, 0x7ffffffd)                                                     => "AUXILIARY",
+        (_, 0x7fffffff)                                                     => "FILTER",
+        (Machine::X86_64 | Machine::L1om | Machine::K1om, SHT_X86_64_UNWIND) => "X86_64_UNWIND",
+        (Machine::Arm, SHT_ARM_EXIDX)                                       => "ARM_EXIDX",
+        (Machine::Arm, SHT_ARM_PREEMPTMAP)                                  => "ARM_PREEMPTMAP",
+        (Machine::Arm, SHT_ARM_ATTRIBUTES)                                  => "ARM_ATTRIBUTES",
+        (Machine::Arm, SHT_ARM_DEBUGOVERLAY)                                => "ARM_DEBUGOVERLAY",
+        (Machine::Arm, SHT_ARM_OVERLAYSECTION)                              => "ARM_OVERLAYSECTION",
+        (Machine::Aarch64, SHT_AARCH64_ATTRIBUTES)                          => "AARCH64_ATTRIBUTES",
+        (Machine::RiscV, SHT_RISCV_ATTRIBUTES)                              => "RISCV_ATTRIBUTES",
+        _                                                                   => return typ.to_string(),
+    };
+
+    name.to_string()
+}
@@ -0,0 +1,252 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Symbol table display module.
+
+use crate::elf::elfhdr::*;
+use crate::elf::sechdr::*;
+use crate::elf::symtbl::*;
+use crate::elf::version::SymbolVersion;
+use crate::parser::{fit_symbol, ElfParser};
+
+impl ElfParser<'_> {
+    /// Print dynamic symbol tables.
+    pub fn print_dyn_syms(&self) {
+        let dynsyms = self.file.section_headers().iter().filter(|s| s.sh_type == SHT_DYNSYM);
+
+        for shdr in dynsyms {
+            self.print_symbol_table(shdr);
+        }
+    }
+
+    /// Print symbol table.
+    ///
+    /// # Parameters
+    /// - `shdr` - given symbol table section header.
+    fn print_symbol_table(&self, shdr: &Elf64_Shdr) {
+        let name  = self.file.section_name(shdr).unwrap_or("<corrupt>");
+        let count = self.file.symbol_count(shdr);
+
+        outln!();
+        outln!(
+            "Symbol table '{}' contains {} {}:",
+            name, count, if count == 1 { "entry" } else { "entries" }
+        );
+
+        match self.file.is_64() {
+            true  => outln!("   Num:    Value          Size Type    Bind   Vis      Ndx Name"),
+            false => outln!("   Num:    Value  Size Type    Bind   Vis      Ndx Name"),
+        }
+
+        let hdr     = self.file.header();
+        let machine = hdr.e_machine;
+        let osabi   = hdr.e_ident[EI_OSABI];
+        let shnum   = self.file.section_headers().len();
+        let entries = match shdr.sh_type {
+            SHT_DYNSYM => self.file.dynamic().unwrap_or_default(),
+            _          => Vec::new(),
+        };
+
+        for index in 0..count {
+            let sym = match self.file.symbol(shdr, index) {
+                Ok(sym)  => sym,
+                Err(err) => {
+                    eprintln!("readelf: Error: {}", err);
+                    break;
+                }
+            };
+
+            let value = sym.st_value;
+            let size  = sym.st_size;
+            let bind  = elf32_st_bind(sym.st_info);
+            let vis   = elf64_st_visibility(sym.st_other);
+
+            let mut line = match self.file.is_64() {
+                true  => format!("{:6}: {:016x} {}", index, value, dec5(size)),
+                false => format!("{:6}: {:08x} {}", index, value, dec5(size)),
+            };
+
+            line.push_str(&format!(" {:<7}", get_symbol_type(osabi, sym.symbol_type())));
+            line.push_str(&format!(" {:<6}", get_symbol_binding(osabi, bind)));
+            line.push_str(&format!(" {:<7}", get_symbol_visibility(vis)));
+
+            if sym.st_other ^ vis != 0 {
+                line.push_str(&format!(" [{}] ", get_symbol_other(machine, sym.st_other ^ vis)));
+            }
+
+            line.push_str(&format!(" {:>4} ", get_symbol_index_type(shnum, sym.st_shndx as u32)));
+
+            let sym_name = self.symbol_display_name(shdr, &sym);
+            let shown    = self.display_name(&sym_name);
+            let version  = self.file.symbol_version(&entries, index, &sym).ok().flatten();
+
+            line.push_str(&versioned_symbol(&sym_name, &shown, version));
+            outln!("{}", line);
+
+            if bind == STB_LOCAL
+                && index as u64 >= shdr.sh_info as u64
+                && machine != EM_MIPS
+                && osabi != ELFOSABI_SOLARIS
+            {
+                eprintln!(
+                    "readelf: Warning: local symbol {} found at index >= {}'s sh_info value of {}",
+                    index, name, { shdr.sh_info }
+                );
+            }
+        }
+    }
+
+    /// Get name of symbol to display.
+    ///
+    /// Section symbols without names are displayed with name of the section.
+    ///
+    /// # Parameters
+    /// - `symtab` - given symbol table section header.
+    /// - `sym`    - given symbol.
+    pub(crate) fn symbol_display_name(&self, symtab: &Elf64_Shdr, sym: &Elf64_Sym) -> String {
+        if sym.st_name == 0
+            && elf32_st_type(sym.st_info) == STT_SECTION
+            && let Ok(shdr) = self.file.section_header(sym.st_shndx as usize)
+        {
+            return self.file.section_name(shdr).unwrap_or("<corrupt>").to_string();
+        }
+
+        self.file.symbol_name(symtab, sym).unwrap_or("<corrupt>").to_string()
+    }
+}
+
+/// Format symbol name followed by its version.
+///
+/// Name and version fit into 21 characters together, name is truncated if
+/// necessary.
+///
+/// # Parameters
+/// - `name`    - given raw symbol name.
+/// - `shown`   - given displayed (e.g. demangled) symbol name.
+/// - `version` - given symbol version.
+fn versioned_symbol(name: &str, shown: &str, version: Option<SymbolVersion>) -> String {
+    let Some(version) = version else {
+        return fit_symbol(21, name, shown);
+    };
+
+    let suffix = version.to_string();
+    let width  = 21 - suffix.len() as isize;
+
+    // Negative width means the name is padded, as `printf("%*s")` does.
+    let name = match width {
+        0          => String::new(),
+        w if w < 0 => format!("{:<1$}", fit_symbol(w.unsigned_abs(), name, shown), w.unsigned_abs()),
+        w          => fit_symbol(w as usize, name, shown),
+    };
+
+    format!("{}{}", name, suffix)
+}
+
+/// Format symbol size in 5 decimal digits, large sizes are hexadecimal.
+fn dec5(size: u64) -> String {
+    match size {
+        0..=99999 => format!("{:5}", size),
+        _         => format!("{:#x}", size),
+    }
+}
+
+/// Get symbol type string representation.
+///
+/// # Parameters
+/// - `osabi` - given ELF header OS ABI.
+/// - `typ`   - given symbol type.
+///
+/// # Returns
+/// Symbol type string representation.
+pub fn get_symbol_type(osabi: u8, typ: SymbolType) -> String {
+    match typ {
+        SymbolType::GnuIfunc if !is_gnu_osabi(osabi) => format!("<OS specific>: {}", u8::from(typ)),
+        typ => typ.to_string(),
+    }
+}
+
+/// Get symbol binding string representation.
+///
+/// # Parameters
+/// - `osabi` - given ELF header OS ABI.
+/// - `bind`  - given symbol binding.
+///
+/// # Returns
+/// Symbol binding string representation.
+pub fn get_symbol_binding(osabi: u8, bind: u8) -> String {
+    match SymbolBinding::from(bind) {
+        SymbolBinding::GnuUnique if !is_gnu_osabi(osabi) => format!("<OS specific>: {}", bind),
+        bind => bind.to_string(),
+    }
+}
+
+/// Get symbol visibility string representation.
+///
+/// # Parameters
+/// - `vis` - given symbol visibility.
+pub fn get_symbol_visibility(vis: u8) -> &'static str {
+    match vis {
+        STV_DEFAULT   => "DEFAULT",
+        STV_INTERNAL  => "INTERNAL",
+        STV_HIDDEN    => "HIDDEN",
+        STV_PROTECTED => "PROTECTED",
+        _             => "<unknown>",
+    }
+}
+
+/// Get string representation of `st_other` bits other than visibility.
+///
+/// # Parameters
+/// - `machine` - given ELF header machine type.
+/// - `other`   - given `st_other` bits without visibility.
+fn get_symbol_other(machine: u16, other: u8) -> String {
+    match machine {
+        EM_AARCH64 if other == STO_AARCH64_VARIANT_PCS => "VARIANT_PCS".to_string(),
+        EM_RISCV if other == STO_RISCV_VARIANT_CC      => "VARIANT_CC".to_string(),
+        _ => format!("<other>: {:x}", other),
+    }
+}
+
+/// Get symbol section index string representation.
+///
+/// # Parameters
+/// - `shnum` - given number of section headers.
+/// - `shndx` - given symbol section index.
+pub fn get_symbol_index_type(shnum: usize, shndx: u32) -> String {
+    match shndx {
+        SHN_UNDEF  => return "UND".to_string(),
+        SHN_ABS    => return "ABS".to_string(),
+        SHN_COMMON => return "COM".to_string(),
+        _          => {}
+    }
+
+    if (SHN_LOPROC..=SHN_HIPROC).contains(&shndx) {
+        format!("PRC[0x{:04x}]", shndx)
+    } else if (SHN_LOOS..=SHN_HIOS).contains(&shndx) {
+        format!("OS [0x{:04x}]", shndx)
+    } else if shndx >= SHN_LORESERVE {
+        format!("RSV[0x{:04x}]", shndx)
+    } else if shnum != 0 && shndx as usize >= shnum {
+        format!("bad section index[{:3}]", shndx)
+    } else {
+        format!("{:3}", shndx)
+    }
+}
+
+/// Check whether GNU extensions are used by objects with OS ABI.
+fn is_gnu_osabi(osabi: u8) -> bool {
+    matches!(osabi, ELFOSABI_NONE | ELFOSABI_GNU | ELFOSABI_FREEBSD)
+}
@@ -0,0 +1,588 @@
+// Readelf - tool for displaying information about ELF files.
+// Copyright (C) 2025 Alexander (@alkuzin).
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <https://www.gnu.org/licenses/>.
+
+//! Symbol versioning sections display module.
+
+use std::cmp::Ordering;
+use std::mem::size_of;
+
+use crate::elf::dynsec::{DynamicTag, Elf64_Dyn};
+use crate::elf::file::{cstr, read, ElfFile};
+use crate::elf::sechdr::*;
+use crate::elf::version::*;
+use crate::parser::ElfParser;
+
+impl ElfParser<'_> {
+    /// Print contents of symbol versioning sections.
+    pub fn print_version_info(&self) {
+        let entries = self.file.dynamic().unwrap_or_default();
+        let mut found = false;
+
+        for shdr in self.file.section_headers() {
+            match shdr.sh_type {
+                SHT_GNU_VERDEF  => {
+                    found = true;
+                    self.print_verdef(shdr, &entries);
+                }
+                SHT_GNU_VERNEED => {
+                    found = true;
+                    self.print_verneed(shdr, &entries);
+                }
+                SHT_GNU_VERSYM  => found |= self.print_versym(shdr, &entries),
+                _               => {}
+            }
+        }
+
+        if !found {
+            outln!();
+            outln!("No version information found in this file.");
+        }
+    }
+
+    /// Print title of symbol versioning section.
+    ///
+    /// # Parameters
+    /// - `kind`  - given kind of section.
+    /// - `shdr`  - given section header.
+    /// - `count` - given number of section entries.
+    fn print_version_title(&self, kind: &str, shdr: &Elf64_Shdr, count: u64) {
+        let name      = self.file.section_name(shdr).unwrap_or("<corrupt>");
+        let link      = shdr.sh_link;
+        let link_name = match self.file.section_header(link as usize) {
+            Ok(link) => self.file.section_name(link).unwrap_or("<corrupt>"),
+            Err(_)   => "<corrupt>",
+        };
+
+        outln!();
+        outln!(
+            "{} section '{}' contains {} {}:",
+            kind, name, count, if count == 1 { "entry" } else { "entries" }
+        );
+        outln!(
+            " Addr: 0x{:016x}  Offset: 0x{:08x}  Link: {} ({})",
+            { shdr.sh_addr }, { shdr.sh_offset }, link, link_name
+        );
+    }
+
+    /// Print version definition section.
+    ///
+    /// # Parameters
+    /// - `shdr`    - given section header.
+    /// - `entries` - given dynamic entries.
+    fn print_verdef(&self, shdr: &Elf64_Shdr, entries: &[Elf64_Dyn]) {
+        let info = shdr.sh_info;
+        self.print_version_title("Version definition", shdr, info as u64);
+
+        let Ok(data) = self.file.section_data(shdr) else {
+            return;
+        };
+
+        let strtab = self.file.dynamic_strtab(entries);
+        let name   = |offset: u32| strtab.and_then(|t| cstr(t, offset as usize).ok());
+        let end    = data.len();
+
+        let mut idx = 0;
+        let mut cnt = 0;
+
+        while cnt < info {
+            let Ok(def) = read::<Elf64_Verdef>(&data, idx) else {
+                break;
+            };
+
+            out!("  {}: Rev: {}  Flags: {}", alt_hex6(idx), { def.vd_version }, get_ver_flags(def.vd_flags));
+            out!("  Index: {}  Cnt: {}  ", { def.vd_ndx }, { def.vd_cnt });
+
+            if def.vd_aux as usize > end - idx {
+                break;
+            }
+
+            let mut vstart = idx + def.vd_aux as usize;
+            let Ok(mut aux) = read::<Elf64_Verdaux>(&data, vstart) else {
+                break;
+            };
+
+            match name(aux.vda_name) {
+                Some(name) => outln!("Name: {}", name),
+                None       => outln!("Name index: {}", { aux.vda_name }),
+            }
+
+            let mut isum = vstart;
+            let mut j    = 1;
+
+            while j < def.vd_cnt {
+                if (aux.vda_next as usize) < size_of::<Elf64_Verdaux>()
+                    && !(j == def.vd_cnt - 1 && aux.vda_next == 0)
+                {
+                    eprintln!("readelf: Warning: Invalid vda_next field of {:x}", { aux.vda_next });
+                    j = def.vd_cnt;
+                    break;
+                }
+
+                if aux.vda_next as usize > end - vstart {
+                    break;
+                }
+
+                isum   += aux.vda_next as usize;
+                vstart += aux.vda_next as usize;
+
+                let Ok(next) = read::<Elf64_Verdaux>(&data, vstart) else {
+                    break;
+                };
+                aux = next;
+
+                match name(aux.vda_name) {
+                    Some(name) => outln!("  {}: Parent {}: {}", alt_hex6(isum), j, name),
+                    None       => outln!("  {}: Parent {}, name index: {}", alt_hex6(isum), j, { aux.vda_name }),
+                }
+
+                j += 1;
+            }
+
+            if j < def.vd_cnt {
+                outln!("  Version def aux past end of section");
+            }
+
+            if (def.vd_next as usize) < size_of::<Elf64_Verdef>() && !(cnt == info - 1 && def.vd_next == 0) {
+                eprintln!("readelf: Warning: Invalid vd_next field of {:x}", { def.vd_next });
+                cnt = info;
+                break;
+            }
+
+            if def.vd_next as usize > end - idx {
+                break;
+            }
+
+            idx += def.vd_next as usize;
+            cnt += 1;
+        }
+
+        if cnt < info {
+            outln!("  Version definition past end of section");
+        }
+    }
+
+    /// Print needed versions section.
+    ///
+    /// # Parameters
+    /// - `shdr`    - given section header.
+    /// - `entries` - given dynamic entries.
+    fn print_verneed(&self, shdr: &Elf64_Shdr, entries: &[Elf64_Dyn]) {
+        let info = shdr.sh_info;
+        self.print_version_title("Version needs", shdr, info as u64);
+
+        let Ok(data) = self.file.section_data(shdr) else {
+            return;
+        };
+
+        let strtab = self.file.dynamic_strtab(entries);
+        let name   = |offset: u32| strtab.and_then(|t| cstr(t, offset as usize).ok());
+        let end    = data.len();
+
+        let mut idx = 0;
+        let mut cnt = 0;
+
+        while cnt < info {
+            let Ok(need) = read::<Elf64_Verneed>(&data, idx) else {
+                break;
+            };
+
+            out!("  {}: Version: {}", alt_hex6(idx), { need.vn_version });
+
+            match name(need.vn_file) {
+                Some(name) => out!("  File: {}", name),
+                None       => out!("  File: {:x}", { need.vn_file }),
+            }
+
+            outln!("  Cnt: {}", { need.vn_cnt });
+
+            if need.vn_aux as usize > end - idx {
+                break;
+            }
+
+            let mut vstart = idx + need.vn_aux as usize;
+            let mut j      = 0;
+
+            while j < need.vn_cnt {
+                let Ok(aux) = read::<Elf64_Vernaux>(&data, vstart) else {
+                    break;
+                };
+
+                match name(aux.vna_name) {
+                    Some(name) => out!("  {}:   Name: {}", alt_hex6(vstart), name),
+                    None       => out!("  {}:   Name index: {:x}", alt_hex6(vstart), { aux.vna_name }),
+                }
+
+                outln!("  Flags: {}  Version: {}", get_ver_flags(aux.vna_flags), { aux.vna_other });
+
+                if (aux.vna_next as usize) < size_of::<Elf64_Vernaux>()
+                    && !(j == need.vn_cnt - 1 && aux.vna_next == 0)
+                {
+                    eprintln!("readelf: Warning: Invalid vna_next field of {:x}", { aux.vna_next });
+                    j = need.vn_cnt;
+                    break;
+                }
+
+                if aux.vna_next as usize > end - vstart {
+                    break;
+                }
+
+                vstart += aux.vna_next as usize;
+                j      += 1;
+            }
+
+            if j < need.vn_cnt {
+                eprintln!("readelf: Warning: Missing Version Needs auxiliary information");
+            }
+
+            if (need.vn_next as usize) < size_of::<Elf64_Verneed>() && !(cnt == info - 1 && need.vn_next == 0) {
+                eprintln!(
+                    "readelf: Warning: Corrupt Version Needs structure - offset to next structure \
+                     is zero with entries still left to be processed"
+                );
+                cnt = info;
+                break;
+            }
+
+            if need.vn_next as usize > end - idx {
+                break;
+            }
+
+            idx += need.vn_next as usize;
+            cnt += 1;
+        }
+
+        if cnt < info {
+            eprintln!("readelf: Warning: Missing Version Needs information");
+        }
+    }
+
+    /// Print version symbols section.
+    ///
+    /// The section is printed only if dynamic symbols are available.
+    ///
+    /// # Parameters
+    /// - `shdr`    - given section header.
+    /// - `entries` - given dynamic entries.
+    ///
+    /// # Returns
+    /// - `true` - if section links to valid symbol table section.
+    /// - `false` - otherwise.
+    fn print_versym(&self, shdr: &Elf64_Shdr, entries: &[Elf64_Dyn]) -> bool {
+        let Ok(symtab) = self.file.section_header(shdr.sh_link as usize) else {
+            return false;
+        };
+
+        let Some(addr) = ElfFile::dynamic_value(entries, DynamicTag::Versym) else {
+            return true;
+        };
+
+        if self.file.dynamic_symbol(entries, 0).is_err() {
+            return true;
+        }
+
+        let strtab = self
+            .file
+            .section_header(symtab.sh_link as usize)
+            .and_then(|strtab| self.file.section_data(strtab));
+
+        let Ok(strtab) = strtab else {
+            return true;
+        };
+
+        let total = shdr.sh_size / 2;
+        self.print_version_title("Version symbols", shdr, total);
+
+        let data = self
+            .file
+            .vaddr_to_offset(addr)
+            .and_then(|offset| self.file.bytes(offset, total * 2).ok());
+
+        let Some(data) = data else {
+            return true;
+        };
+
+        let num_syms = self.file.dynamic_symbol_count(entries) as u64;
+
+        for cnt in (0..total).step_by(4) {
+            let mut line = format!("  {:03x}:", cnt);
+
+            for index in cnt..total.min(cnt + 4) {
+                let value: u16 = read(data, index as usize * 2).unwrap_or(0);
+
+                match value {
+                    VER_NDX_LOCAL  => line.push_str("   0 (*local*)    "),
+                    VER_NDX_GLOBAL => line.push_str("   1 (*global*)   "),
+                    _              => {
+                        let hidden   = if value & VERSYM_HIDDEN != 0 { 'h' } else { ' ' };
+                        let mut cell = format!("{:4x}{}", value & VERSYM_VERSION, hidden);
+
+                        if index >= num_syms {
+                            eprintln!("readelf: Warning: invalid index into symbol array");
+                            line.push_str(&cell);
+                            continue;
+                        }
+
+                        if let Some(name) = self.versym_name(entries, value, &strtab) {
+                            let width = (12 - name.len() as isize).unsigned_abs();
+                            cell.push_str(&format!("({}{:<width$}", name, ")"));
+                        }
+
+                        line.push_str(&format!("{:<18}", cell));
+                    }
+                }
+            }
+
+            outln!("{}", line);
+        }
+
+        true
+    }
+
+    /// Get name of version referenced by version symbols section entry.
+    ///
+    /// # Parameters
+    /// - `entries` - given dynamic entries.
+    /// - `value`   - given version symbols section entry.
+    /// - `strtab`  - given string table of dynamic symbols.
+    ///
+    /// # Returns
+    /// - Version name, `*both*` if both needed and defined versions have the
+    ///   index - if version is found.
+    /// - `None` - otherwise.
+    pub(crate) fn versym_name<'s>(&self, entries: &[Elf64_Dyn], value: u16, strtab: &'s [u8]) -> Option<&'s str> {
+        const INVALID: &str = "*invalid*";
+
+        let name_at = |offset: u32| match (offset as usize) < strtab.len() {
+            true  => cstr(strtab, offset as usize).unwrap_or(INVALID),
+            false => INVALID,
+        };
+
+        let mut name = None;
+
+        let verneed = self.file.dynamic_table_data(entries, DynamicTag::Verneed, SectionType::GnuVerneed);
+
+        if let Ok(Some(verneed)) = verneed {
+            for (offset, need) in VerneedIter::new(verneed, 0) {
+                let mut auxs = VernauxIter::new(verneed, offset + need.vn_aux as usize);
+
+                if let Some((_, aux)) = auxs.find(|(_, aux)| aux.vna_other == value) {
+                    name = Some(name_at(aux.vna_name));
+                    break;
+                }
+            }
+        }
+
+        if value == (VERSYM_HIDDEN | VER_NDX_GLOBAL) {
+            return name;
+        }
+
+        let verdef = self.file.dynamic_table_data(entries, DynamicTag::Verdef, SectionType::GnuVerdef);
+
+        if let Ok(Some(verdef)) = verdef {
+            let def = VerdefIter::new(verdef, 0).find(|(_, def)| def.vd_ndx == value & VERSYM_VERSION);
+
+            if let Some((offset, def)) = def {
+                let aux = read::<Elf64_Verdaux>(verdef, offset + def.vd_aux as usize).ok()?;
+
+                name = match (aux.vda_name as usize) < strtab.len() {
+                    false                                      => Some(INVALID),
+                    true if name.is_some_and(|n| n != INVALID) => Some("*both*"),
+                    true                                       => Some(name_at(aux.vda_name)),
+                };
+            }
+        }
+
+        name
+    }
+
+    /// Print the highest version needed from each needed library together with
+    /// symbols requiring it.
+    pub fn print_version_requirements(&self) {
+        let entries = self.file.dynamic().unwrap_or_default();
+        let needs   = match self.file.version_needs(&entries) {
+            Ok(needs) => needs,
+            Err(err)  => {
+                eprintln!("readelf: Error: Unable to read needed versions: {}", err);
+                return;
+            }
+        };
+
+        let mut files = self.file.needed_libraries(&entries);
+
+        for need in &needs {
+            if !files.contains(&need.file) {
+                files.push(need.file);
+            }
+        }
+
+        outln!();
+
+        if files.is_empty() {
+            outln!("No version requirements found in this file.");
+            return;
+        }
+
+        outln!("Version requirements:");
+
+        for file in files {
+            let versions = needs
+                .iter()
+                .find(|need| need.file == file)
+                .map(|need| highest_versions(&need.versions))
+                .unwrap_or_default();
+
+            if versions.is_empty() {
+                outln!("  {}: no versioned symbols", file);
+                continue;
+            }
+
+            outln!("  {}:", file);
+
+            for version in versions {
+                match version.symbols.is_empty() {
+                    true  => outln!("    {}", version.name),
+                    false => outln!("    {} required by: {}", version.name, version.symbols.join(", ")),
+                }
+            }
+        }
+    }
+
+    /// Check that needed versions are not newer than given ceilings.
+    ///
+    /// Each ceiling limits versions with the same prefix, e.g. `GLIBC_2.28`
+    /// limits `GLIBC_*` versions only. A ceiling limiting no needed version
+    /// is an error as well, since it is most likely misspelled.
+    ///
+    /// # Parameters
+    /// - `ceilings` - given newest allowed versions.
+    ///
+    /// # Returns
+    /// - `true` - if no needed version exceeds ceilings.
+    /// - `false` - otherwise, errors are printed for each exceeding version
+    ///   and each unmatched ceiling.
+    pub fn check_version_ceilings(&self, ceilings: &[String]) -> bool {
+        let mut ok = true;
+
+        for ceiling in ceilings {
+            if split_version_name(ceiling).is_none() {
+                eprintln!("readelf: Error: invalid version ceiling '{}'", ceiling);
+                ok = false;
+            }
+        }
+
+        let entries = self.file.dynamic().unwrap_or_default();
+        let needs   = match self.file.version_needs(&entries) {
+            Ok(needs) => needs,
+            Err(err)  => {
+                eprintln!("readelf: Error: Unable to read needed versions: {}", err);
+                return false;
+            }
+        };
+
+        for need in &needs {
+            for version in &need.versions {
+                let exceeded = ceilings
+                    .iter()
+                    .filter(|c| compare_version_names(version.name, c) == Some(Ordering::Greater));
+
+                for ceiling in exceeded {
+                    eprintln!(
+                        "readelf: Error: {} requires {} which is newer than {}, required by: {}",
+                        need.file, version.name, ceiling, version.symbols.join(", ")
+                    );
+                    ok = false;
+                }
+            }
+        }
+
+        let versions = needs.iter().flat_map(|need| &need.versions);
+
+        for ceiling in ceilings.iter().filter(|c| split_version_name(c).is_some()) {
+            if !versions.clone().any(|version| compare_version_names(version.name, ceiling).is_some()) {
+                eprintln!("readelf: Error: version ceiling '{}' matches no needed version", ceiling);
+                ok = false;
+            }
+        }
+
+        ok
+    }
+}
+
+/// Get the highest versions of each version prefix.
+///
+/// Versions without numeric components, like `GLIBC_PRIVATE`, can not be
+/// compared and are all kept.
+///
+/// # Parameters
+/// - `versions` - given needed versions.
+///
+/// # Returns
+/// Highest versions in order of appearance.
+pub(crate) fn highest_versions<'v, 'a>(versions: &'v [NeededVersion<'a>]) -> Vec<&'v NeededVersion<'a>> {
+    let mut highest: Vec<&NeededVersion> = Vec::new();
+
+    for version in versions {
+        let same_prefix = highest
+            .iter_mut()
+            .find(|h| compare_version_names(h.name, version.name).is_some());
+
+        match same_prefix {
+            Some(h) if compare_version_names(h.name, version.name) == Some(Ordering::Less) => *h = version,
+            Some(_) => {}
+            None    => highest.push(version),
+        }
+    }
+
+    highest
+}
+
+/// Get version information flags string representation.
+///
+/// # Parameters
+/// - `flags` - given version information flags.
+pub fn get_ver_flags(flags: u16) -> String {
+    if flags == 0 {
+        return "none".to_string();
+    }
+
+    let mut names = Vec::new();
+
+    if flags & VER_FLG_BASE != 0 {
+        names.push("BASE");
+    }
+
+    if flags & VER_FLG_WEAK != 0 {
+        names.push("WEAK");
+    }
+
+    if flags & VER_FLG_INFO != 0 {
+        names.push("INFO");
+    }
+
+    if flags & !(VER_FLG_BASE | VER_FLG_WEAK | VER_FLG_INFO) != 0 {
+        names.push("<unknown>");
+    }
+
+    names.join(" | ")
+}
+
+/// Format offset like `printf("%#06x")` does, which prints zero without prefix.
+fn alt_hex6(value: usize) -> String {
+    match value {
+        0 => "000000".to_string(),
+        _ => format!("{:#06x}", value),
+    }
+}